@@ -0,0 +1,29 @@
+//! Git merge driver for entity RON files
+//!
+//! Registered by `init_repository` as `tessera-merge %O %A %B %P`. Writes the
+//! field-level merge into the `%A` file and exits 0 when clean, 1 when
+//! conflicts remain for resolution in the app, 2 on errors.
+
+use std::path::Path;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() < 3 {
+        eprintln!("usage: tessera-merge <base> <ours> <theirs> [path]");
+        return ExitCode::from(2);
+    }
+    let display_path = args.get(3).unwrap_or(&args[1]);
+
+    match tessera_lib::run_merge_driver(Path::new(&args[0]), Path::new(&args[1]), Path::new(&args[2])) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => {
+            eprintln!("tessera-merge: conflicting field changes in {}", display_path);
+            ExitCode::from(1)
+        }
+        Err(e) => {
+            eprintln!("tessera-merge: failed to merge {}: {}", display_path, e);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use tauri::State;
+use crate::core::{AuditQuery, AuditRecord, AuditVerification, Workspace};
+use serde::Serialize;
+
+/// Response with the audit records matching a query
+#[derive(Debug, Serialize)]
+pub struct AuditRecordsResponse {
+    pub records: Vec<AuditRecord>,
+}
+
+/// Response with the result of checking the audit log's hash chain
+#[derive(Debug, Serialize)]
+pub struct AuditVerificationResponse {
+    pub verification: AuditVerification,
+}
+
+/// List audit records, optionally filtered by entity, user and date range
+#[tauri::command]
+pub async fn query_audit_log(
+    workspace: State<'_, Workspace>,
+    query: AuditQuery,
+) -> Result<AuditRecordsResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let records = state.audit.query(&query).map_err(|e| e.to_string())?;
+
+    Ok(AuditRecordsResponse { records })
+}
+
+/// List the audit history of one entity, including links to or from it
+#[tauri::command]
+pub async fn get_entity_audit_history(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+) -> Result<AuditRecordsResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let entity = state
+        .id_registry
+        .lookup(&entity_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Entity not found: {}", entity_id))?;
+
+    let query = AuditQuery {
+        entity_id: Some(entity.entity_id),
+        ..AuditQuery::default()
+    };
+    let records = state.audit.query(&query).map_err(|e| e.to_string())?;
+
+    Ok(AuditRecordsResponse { records })
+}
+
+/// Check that no audit record has been altered or removed
+#[tauri::command]
+pub async fn verify_audit_log(
+    workspace: State<'_, Workspace>,
+) -> Result<AuditVerificationResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let verification = state.audit.verify().map_err(|e| e.to_string())?;
+
+    Ok(AuditVerificationResponse { verification })
+}
+
+/// Get the user name recorded with changes
+#[tauri::command]
+pub async fn get_audit_user(
+    workspace: State<'_, Workspace>,
+) -> Result<String, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    state.audit.user().map_err(|e| e.to_string())
+}
+
+/// Set the user name recorded with changes from now on
+#[tauri::command]
+pub async fn set_audit_user(
+    workspace: State<'_, Workspace>,
+    user: String,
+) -> Result<(), String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    state.audit.set_user(&user).map_err(|e| e.to_string())
+}
+
+// Note: Command tests are covered by AuditLog tests
+// Integration tests with Tauri State will be added later
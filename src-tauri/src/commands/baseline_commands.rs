@@ -1,6 +1,7 @@
 use tauri::State;
 use uuid::Uuid;
-use crate::core::AppState;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::{Workspace, FieldChange};
 use crate::models::Baseline;
 use serde::{Deserialize, Serialize};
 
@@ -9,7 +10,8 @@ use serde::{Deserialize, Serialize};
 pub struct CreateBaselineRequest {
     pub name: String,
     pub description: String,
-    pub task_ids: Vec<Uuid>,
+    /// UUIDs or human-readable IDs such as `TSK-0001`
+    pub task_ids: Vec<String>,
 }
 
 /// Response with baseline data
@@ -18,6 +20,12 @@ pub struct BaselineResponse {
     pub baseline: Baseline,
 }
 
+/// Field changes between a task's baseline snapshot and its current plan
+#[derive(Debug, Serialize)]
+pub struct BaselineDiffResponse {
+    pub changes: Vec<FieldChange>,
+}
+
 /// List of baseline IDs
 #[derive(Debug, Serialize)]
 pub struct BaselineListResponse {
@@ -27,15 +35,22 @@ pub struct BaselineListResponse {
 /// Create a new baseline
 #[tauri::command]
 pub async fn create_baseline(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateBaselineRequest,
 ) -> Result<BaselineResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let task_ids = request
+        .task_ids
+        .iter()
+        .map(|id| state.id_registry.resolve(id))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
     let baseline = state
         .entity_manager
         .create_baseline(
             request.name,
             request.description,
-            request.task_ids,
+            task_ids,
         )
         .map_err(|e| e.to_string())?;
 
@@ -45,10 +60,11 @@ pub async fn create_baseline(
 /// Get a baseline by ID
 #[tauri::command]
 pub async fn get_baseline(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     baseline_id: String,
 ) -> Result<BaselineResponse, String> {
-    let id = Uuid::parse_str(&baseline_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&baseline_id).map_err(|e| e.to_string())?;
 
     let baseline = state
         .entity_manager
@@ -61,9 +77,10 @@ pub async fn get_baseline(
 /// Update a baseline
 #[tauri::command]
 pub async fn update_baseline(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     baseline: Baseline,
 ) -> Result<BaselineResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_baseline = state
         .entity_manager
         .update_baseline(baseline)
@@ -75,24 +92,43 @@ pub async fn update_baseline(
 /// Delete a baseline
 #[tauri::command]
 pub async fn delete_baseline(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     baseline_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&baseline_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&baseline_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_baseline(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
+}
+
+/// Compare a task's current plan with its baseline snapshot
+#[tauri::command]
+pub async fn diff_task_baseline(
+    workspace: State<'_, Workspace>,
+    task_id: String,
+) -> Result<BaselineDiffResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&task_id).map_err(|e| e.to_string())?;
+
+    let changes = state
+        .entity_manager
+        .diff_task_baseline(&id)
+        .map_err(|e| e.to_string())?;
+
+    Ok(BaselineDiffResponse { changes })
 }
 
 /// List all baseline IDs
 #[tauri::command]
 pub async fn list_baselines(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<BaselineListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let baseline_ids = state
         .storage
         .list_ids(&crate::models::EntityType::Baseline)
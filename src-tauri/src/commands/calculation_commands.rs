@@ -1,6 +1,5 @@
 use tauri::State;
-use uuid::Uuid;
-use crate::core::{AppState, CriticalPathResult, EvmMetrics};
+use crate::core::{Workspace, CriticalPathResult, EvmMetrics};
 use crate::models::{StackupResult, MonteCarloResult, BomResult};
 use serde::{Deserialize, Serialize};
 
@@ -19,8 +18,9 @@ pub struct EvmResponse {
 /// Calculate critical path using CPM algorithm
 #[tauri::command]
 pub async fn calculate_critical_path(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<CriticalPathResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let result = state
         .calculation_engine
         .calculate_critical_path()
@@ -32,8 +32,9 @@ pub async fn calculate_critical_path(
 /// Calculate Earned Value Management metrics
 #[tauri::command]
 pub async fn calculate_evm(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<EvmResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let metrics = state
         .calculation_engine
         .calculate_evm()
@@ -74,10 +75,11 @@ pub struct MonteCarloRequest {
 /// Calculate worst case tolerance for a stackup
 #[tauri::command]
 pub async fn calculate_worst_case(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     stackup_id: String,
 ) -> Result<WorstCaseResponse, String> {
-    let id = Uuid::parse_str(&stackup_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&stackup_id).map_err(|e| e.to_string())?;
 
     let result = state
         .calculation_engine
@@ -90,10 +92,11 @@ pub async fn calculate_worst_case(
 /// Calculate RSS (Root Sum Squared) tolerance for a stackup
 #[tauri::command]
 pub async fn calculate_rss(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     stackup_id: String,
 ) -> Result<RssResponse, String> {
-    let id = Uuid::parse_str(&stackup_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&stackup_id).map_err(|e| e.to_string())?;
 
     let result = state
         .calculation_engine
@@ -106,10 +109,11 @@ pub async fn calculate_rss(
 /// Calculate Monte Carlo tolerance simulation for a stackup
 #[tauri::command]
 pub async fn calculate_monte_carlo(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: MonteCarloRequest,
 ) -> Result<MonteCarloResponse, String> {
-    let id = Uuid::parse_str(&request.stackup_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&request.stackup_id).map_err(|e| e.to_string())?;
 
     let result = state
         .calculation_engine
@@ -139,10 +143,11 @@ pub struct GenerateBomRequest {
 /// Generate a Bill of Materials (BOM) for an assembly
 #[tauri::command]
 pub async fn generate_bom(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: GenerateBomRequest,
 ) -> Result<BomResponse, String> {
-    let id = Uuid::parse_str(&request.assembly_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&request.assembly_id).map_err(|e| e.to_string())?;
 
     let result = state
         .calculation_engine
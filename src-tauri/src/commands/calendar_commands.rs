@@ -1,7 +1,8 @@
 use tauri::State;
 use uuid::Uuid;
 use chrono::Weekday;
-use crate::core::AppState;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::Workspace;
 use crate::models::Calendar;
 use serde::{Deserialize, Serialize};
 
@@ -28,9 +29,10 @@ pub struct CalendarListResponse {
 /// Create a new calendar
 #[tauri::command]
 pub async fn create_calendar(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateCalendarRequest,
 ) -> Result<CalendarResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let calendar = state
         .entity_manager
         .create_calendar(
@@ -46,10 +48,11 @@ pub async fn create_calendar(
 /// Get a calendar by ID
 #[tauri::command]
 pub async fn get_calendar(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     calendar_id: String,
 ) -> Result<CalendarResponse, String> {
-    let id = Uuid::parse_str(&calendar_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&calendar_id).map_err(|e| e.to_string())?;
 
     let calendar = state
         .entity_manager
@@ -62,9 +65,10 @@ pub async fn get_calendar(
 /// Update a calendar
 #[tauri::command]
 pub async fn update_calendar(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     calendar: Calendar,
 ) -> Result<CalendarResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_calendar = state
         .entity_manager
         .update_calendar(calendar)
@@ -76,24 +80,26 @@ pub async fn update_calendar(
 /// Delete a calendar
 #[tauri::command]
 pub async fn delete_calendar(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     calendar_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&calendar_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&calendar_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_calendar(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 /// List all calendar IDs
 #[tauri::command]
 pub async fn list_calendars(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<CalendarListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let calendar_ids = state
         .storage
         .list_ids(&crate::models::EntityType::Calendar)
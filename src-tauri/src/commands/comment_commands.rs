@@ -0,0 +1,224 @@
+use tauri::State;
+use uuid::Uuid;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::{CommentThread, Workspace};
+use crate::models::Comment;
+use serde::Serialize;
+
+/// Response with comment data
+#[derive(Debug, Serialize)]
+pub struct CommentResponse {
+    pub comment: Comment,
+}
+
+/// Response with the discussions on an entity
+#[derive(Debug, Serialize)]
+pub struct CommentThreadsResponse {
+    pub threads: Vec<CommentThread>,
+}
+
+/// Response with the number of unresolved comments on an entity
+#[derive(Debug, Serialize)]
+pub struct UnresolvedCommentCountResponse {
+    pub count: usize,
+}
+
+/// Response with comments the user has not read yet, newest first
+#[derive(Debug, Serialize)]
+pub struct UnseenCommentsResponse {
+    pub comments: Vec<Comment>,
+}
+
+/// Start a discussion on any entity
+#[tauri::command]
+pub async fn create_comment(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+    author: String,
+    body: String,
+) -> Result<CommentResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let entity = state
+        .id_registry
+        .lookup(&entity_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Entity not found: {}", entity_id))?;
+
+    let comment = state
+        .entity_manager
+        .create_comment(entity.entity_type, &entity.entity_id, author, body)
+        .map_err(|e| e.to_string())?;
+
+    Ok(CommentResponse { comment })
+}
+
+/// Reply to a comment
+#[tauri::command]
+pub async fn reply_to_comment(
+    workspace: State<'_, Workspace>,
+    comment_id: String,
+    author: String,
+    body: String,
+) -> Result<CommentResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&comment_id).map_err(|e| e.to_string())?;
+
+    let comment = state
+        .entity_manager
+        .reply_to_comment(&id, author, body)
+        .map_err(|e| e.to_string())?;
+
+    Ok(CommentResponse { comment })
+}
+
+/// Get a comment by ID
+#[tauri::command]
+pub async fn get_comment(
+    workspace: State<'_, Workspace>,
+    comment_id: String,
+) -> Result<CommentResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&comment_id).map_err(|e| e.to_string())?;
+
+    let comment = state
+        .entity_manager
+        .get_comment(&id)
+        .map_err(|e| e.to_string())?;
+
+    Ok(CommentResponse { comment })
+}
+
+/// Replace a comment's body
+#[tauri::command]
+pub async fn edit_comment(
+    workspace: State<'_, Workspace>,
+    comment_id: String,
+    body: String,
+) -> Result<CommentResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&comment_id).map_err(|e| e.to_string())?;
+
+    let comment = state
+        .entity_manager
+        .edit_comment(&id, body)
+        .map_err(|e| e.to_string())?;
+
+    Ok(CommentResponse { comment })
+}
+
+/// Resolve a comment, or reopen it
+#[tauri::command]
+pub async fn resolve_comment(
+    workspace: State<'_, Workspace>,
+    comment_id: String,
+    resolved: bool,
+) -> Result<CommentResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&comment_id).map_err(|e| e.to_string())?;
+
+    let comment = state
+        .entity_manager
+        .set_comment_resolved(&id, resolved)
+        .map_err(|e| e.to_string())?;
+
+    Ok(CommentResponse { comment })
+}
+
+/// Delete a comment
+#[tauri::command]
+pub async fn delete_comment(
+    workspace: State<'_, Workspace>,
+    comment_id: String,
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&comment_id).map_err(|e| e.to_string())?;
+
+    let impact = state
+        .entity_manager
+        .delete_comment(&id)
+        .map_err(|e| e.to_string())?;
+
+    Ok(DeleteResponse { impact })
+}
+
+/// List the discussions on an entity with their replies
+#[tauri::command]
+pub async fn get_comment_threads(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+) -> Result<CommentThreadsResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&entity_id).map_err(|e| e.to_string())?;
+
+    let threads = state
+        .entity_manager
+        .get_comment_threads(&id)
+        .map_err(|e| e.to_string())?;
+
+    Ok(CommentThreadsResponse { threads })
+}
+
+/// Count the unresolved comments on an entity, replies included
+#[tauri::command]
+pub async fn get_unresolved_comment_count(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+) -> Result<UnresolvedCommentCountResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&entity_id).map_err(|e| e.to_string())?;
+
+    let count = state
+        .entity_manager
+        .unresolved_comment_count(&id)
+        .map_err(|e| e.to_string())?;
+
+    Ok(UnresolvedCommentCountResponse { count })
+}
+
+/// Mark comments as read by a user
+#[tauri::command]
+pub async fn mark_comments_seen(
+    workspace: State<'_, Workspace>,
+    user: String,
+    comment_ids: Vec<String>,
+) -> Result<(), String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let ids = comment_ids
+        .iter()
+        .map(|id| state.id_registry.resolve(id))
+        .collect::<Result<Vec<Uuid>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    state.seen_comments.mark_seen(&user, &ids).map_err(|e| e.to_string())
+}
+
+/// List comments by others that a user has not read, or that were edited since
+#[tauri::command]
+pub async fn get_unseen_comments(
+    workspace: State<'_, Workspace>,
+    user: String,
+) -> Result<UnseenCommentsResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let seen = state.seen_comments.seen_by(&user).map_err(|e| e.to_string())?;
+
+    let mut comments = Vec::new();
+    for id in state.entity_manager.list_comment_ids().map_err(|e| e.to_string())? {
+        let comment = state.entity_manager.get_comment(&id).map_err(|e| e.to_string())?;
+        if comment.author.eq_ignore_ascii_case(&user) {
+            continue;
+        }
+        let unseen = match seen.get(&id) {
+            Some(seen_at) => comment.edited_at.is_some_and(|edited_at| edited_at > *seen_at),
+            None => true,
+        };
+        if unseen {
+            comments.push(comment);
+        }
+    }
+    comments.sort_by_key(|comment| std::cmp::Reverse(comment.metadata.created_at));
+
+    Ok(UnseenCommentsResponse { comments })
+}
+
+// Note: Command tests are covered by EntityManager and SqliteCache tests
+// Integration tests with Tauri State will be added later
@@ -0,0 +1,56 @@
+use tauri::State;
+use crate::core::{Workspace, TypeValidationIssue};
+use crate::models::ProjectConfig;
+use serde::Serialize;
+
+/// Response with the project configuration
+#[derive(Debug, Serialize)]
+pub struct ConfigResponse {
+    pub config: ProjectConfig,
+}
+
+/// Entities whose configured type no longer exists
+#[derive(Debug, Serialize)]
+pub struct RevalidateResponse {
+    pub issues: Vec<TypeValidationIssue>,
+}
+
+/// Get the project configuration
+#[tauri::command]
+pub async fn get_config(
+    workspace: State<'_, Workspace>,
+) -> Result<ConfigResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let config = state.get_config().map_err(|e| e.to_string())?;
+
+    Ok(ConfigResponse { config })
+}
+
+/// Replace the project configuration and save it to project.toml
+#[tauri::command]
+pub async fn update_config(
+    workspace: State<'_, Workspace>,
+    config: ProjectConfig,
+) -> Result<ConfigResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let config = state.update_config(config).map_err(|e| e.to_string())?;
+
+    Ok(ConfigResponse { config })
+}
+
+/// Re-check every entity against the current config and report the ones whose type was removed
+#[tauri::command]
+pub async fn revalidate_entities(
+    workspace: State<'_, Workspace>,
+) -> Result<RevalidateResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let issues = state
+        .entity_manager
+        .revalidate_entity_types()
+        .map_err(|e| e.to_string())?;
+
+    Ok(RevalidateResponse { issues })
+}
+
+// Note: Command tests are covered by AppState, ConfigStorage and EntityManager tests
+// Integration tests with Tauri State will be added later
@@ -0,0 +1,31 @@
+use tauri::State;
+use crate::core::{Workspace, DeleteImpact};
+use crate::models::EntityType;
+use serde::Serialize;
+
+/// Response with the links and children removed by a delete
+#[derive(Debug, Serialize)]
+pub struct DeleteResponse {
+    pub impact: DeleteImpact,
+}
+
+/// Show what deleting an entity would remove, so the UI can ask for confirmation
+#[tauri::command]
+pub async fn preview_delete(
+    workspace: State<'_, Workspace>,
+    entity_type: EntityType,
+    entity_id: String,
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&entity_id).map_err(|e| e.to_string())?;
+
+    let impact = state
+        .entity_manager
+        .preview_delete(entity_type, &id)
+        .map_err(|e| e.to_string())?;
+
+    Ok(DeleteResponse { impact })
+}
+
+// Note: Command tests are covered by EntityManager tests
+// Integration tests with Tauri State will be added later
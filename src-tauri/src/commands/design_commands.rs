@@ -1,7 +1,8 @@
 use tauri::State;
 use uuid::Uuid;
 use chrono::NaiveDate;
-use crate::core::{AppState, EdtError};
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::{Workspace, EdtError};
 use crate::models::{
     Assembly, Component, Feature, FeatureType, DistributionType,
     Mate, MateType, Stackup, AnalysisType, Supplier, Quote, CostDistribution,
@@ -31,9 +32,10 @@ pub struct AssemblyListResponse {
 
 #[tauri::command]
 pub async fn create_assembly(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateAssemblyRequest,
 ) -> Result<AssemblyResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let assembly = state
         .entity_manager
         .create_assembly(
@@ -48,10 +50,11 @@ pub async fn create_assembly(
 
 #[tauri::command]
 pub async fn get_assembly(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     assembly_id: String,
 ) -> Result<AssemblyResponse, String> {
-    let id = Uuid::parse_str(&assembly_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&assembly_id).map_err(|e| e.to_string())?;
 
     let assembly = state
         .entity_manager
@@ -63,9 +66,10 @@ pub async fn get_assembly(
 
 #[tauri::command]
 pub async fn update_assembly(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     assembly: Assembly,
 ) -> Result<AssemblyResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_assembly = state
         .entity_manager
         .update_assembly(assembly)
@@ -76,23 +80,25 @@ pub async fn update_assembly(
 
 #[tauri::command]
 pub async fn delete_assembly(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     assembly_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&assembly_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&assembly_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_assembly(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 #[tauri::command]
 pub async fn list_assemblies(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<AssemblyListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let assembly_ids = state
         .entity_manager
         .list_assembly_ids()
@@ -124,9 +130,10 @@ pub struct ComponentListResponse {
 
 #[tauri::command]
 pub async fn create_component(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateComponentRequest,
 ) -> Result<ComponentResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let component = state
         .entity_manager
         .create_component(
@@ -141,10 +148,11 @@ pub async fn create_component(
 
 #[tauri::command]
 pub async fn get_component(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     component_id: String,
 ) -> Result<ComponentResponse, String> {
-    let id = Uuid::parse_str(&component_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&component_id).map_err(|e| e.to_string())?;
 
     let component = state
         .entity_manager
@@ -156,9 +164,10 @@ pub async fn get_component(
 
 #[tauri::command]
 pub async fn update_component(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     component: Component,
 ) -> Result<ComponentResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_component = state
         .entity_manager
         .update_component(component)
@@ -169,23 +178,25 @@ pub async fn update_component(
 
 #[tauri::command]
 pub async fn delete_component(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     component_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&component_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&component_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_component(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 #[tauri::command]
 pub async fn list_components(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<ComponentListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let component_ids = state
         .entity_manager
         .list_component_ids()
@@ -221,9 +232,10 @@ pub struct FeatureListResponse {
 
 #[tauri::command]
 pub async fn create_feature(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateFeatureRequest,
 ) -> Result<FeatureResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let feature = state
         .entity_manager
         .create_feature(
@@ -242,10 +254,11 @@ pub async fn create_feature(
 
 #[tauri::command]
 pub async fn get_feature(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     feature_id: String,
 ) -> Result<FeatureResponse, String> {
-    let id = Uuid::parse_str(&feature_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&feature_id).map_err(|e| e.to_string())?;
 
     let feature = state
         .entity_manager
@@ -257,9 +270,10 @@ pub async fn get_feature(
 
 #[tauri::command]
 pub async fn update_feature(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     feature: Feature,
 ) -> Result<FeatureResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_feature = state
         .entity_manager
         .update_feature(feature)
@@ -270,23 +284,25 @@ pub async fn update_feature(
 
 #[tauri::command]
 pub async fn delete_feature(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     feature_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&feature_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&feature_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_feature(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 #[tauri::command]
 pub async fn list_features(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<FeatureListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let feature_ids = state
         .entity_manager
         .list_feature_ids()
@@ -318,9 +334,10 @@ pub struct MateListResponse {
 
 #[tauri::command]
 pub async fn create_mate(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateMateRequest,
 ) -> Result<MateResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let mate = state
         .entity_manager
         .create_mate(
@@ -335,10 +352,11 @@ pub async fn create_mate(
 
 #[tauri::command]
 pub async fn get_mate(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     mate_id: String,
 ) -> Result<MateResponse, String> {
-    let id = Uuid::parse_str(&mate_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&mate_id).map_err(|e| e.to_string())?;
 
     let mate = state
         .entity_manager
@@ -350,9 +368,10 @@ pub async fn get_mate(
 
 #[tauri::command]
 pub async fn update_mate(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     mate: Mate,
 ) -> Result<MateResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_mate = state
         .entity_manager
         .update_mate(mate)
@@ -363,23 +382,25 @@ pub async fn update_mate(
 
 #[tauri::command]
 pub async fn delete_mate(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     mate_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&mate_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&mate_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_mate(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 #[tauri::command]
 pub async fn list_mates(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<MateListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let mate_ids = state
         .entity_manager
         .list_mate_ids()
@@ -411,9 +432,10 @@ pub struct StackupListResponse {
 
 #[tauri::command]
 pub async fn create_stackup(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateStackupRequest,
 ) -> Result<StackupResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let stackup = state
         .entity_manager
         .create_stackup(
@@ -428,10 +450,11 @@ pub async fn create_stackup(
 
 #[tauri::command]
 pub async fn get_stackup(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     stackup_id: String,
 ) -> Result<StackupResponse, String> {
-    let id = Uuid::parse_str(&stackup_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&stackup_id).map_err(|e| e.to_string())?;
 
     let stackup = state
         .entity_manager
@@ -443,9 +466,10 @@ pub async fn get_stackup(
 
 #[tauri::command]
 pub async fn update_stackup(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     stackup: Stackup,
 ) -> Result<StackupResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_stackup = state
         .entity_manager
         .update_stackup(stackup)
@@ -456,23 +480,25 @@ pub async fn update_stackup(
 
 #[tauri::command]
 pub async fn delete_stackup(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     stackup_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&stackup_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&stackup_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_stackup(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 #[tauri::command]
 pub async fn list_stackups(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<StackupListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let stackup_ids = state
         .entity_manager
         .list_stackup_ids()
@@ -503,9 +529,10 @@ pub struct SupplierListResponse {
 
 #[tauri::command]
 pub async fn create_supplier(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateSupplierRequest,
 ) -> Result<SupplierResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let supplier = state
         .entity_manager
         .create_supplier(
@@ -519,10 +546,11 @@ pub async fn create_supplier(
 
 #[tauri::command]
 pub async fn get_supplier(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     supplier_id: String,
 ) -> Result<SupplierResponse, String> {
-    let id = Uuid::parse_str(&supplier_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&supplier_id).map_err(|e| e.to_string())?;
 
     let supplier = state
         .entity_manager
@@ -534,9 +562,10 @@ pub async fn get_supplier(
 
 #[tauri::command]
 pub async fn update_supplier(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     supplier: Supplier,
 ) -> Result<SupplierResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_supplier = state
         .entity_manager
         .update_supplier(supplier)
@@ -547,23 +576,25 @@ pub async fn update_supplier(
 
 #[tauri::command]
 pub async fn delete_supplier(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     supplier_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&supplier_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&supplier_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_supplier(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 #[tauri::command]
 pub async fn list_suppliers(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<SupplierListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let supplier_ids = state
         .entity_manager
         .list_supplier_ids()
@@ -596,9 +627,10 @@ pub struct QuoteListResponse {
 
 #[tauri::command]
 pub async fn create_quote(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateQuoteRequest,
 ) -> Result<QuoteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let quote = state
         .entity_manager
         .create_quote(
@@ -614,10 +646,11 @@ pub async fn create_quote(
 
 #[tauri::command]
 pub async fn get_quote(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     quote_id: String,
 ) -> Result<QuoteResponse, String> {
-    let id = Uuid::parse_str(&quote_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&quote_id).map_err(|e| e.to_string())?;
 
     let quote = state
         .entity_manager
@@ -629,9 +662,10 @@ pub async fn get_quote(
 
 #[tauri::command]
 pub async fn update_quote(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     quote: Quote,
 ) -> Result<QuoteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_quote = state
         .entity_manager
         .update_quote(quote)
@@ -642,23 +676,25 @@ pub async fn update_quote(
 
 #[tauri::command]
 pub async fn delete_quote(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     quote_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&quote_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&quote_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_quote(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 #[tauri::command]
 pub async fn list_quotes(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<QuoteListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let quote_ids = state
         .entity_manager
         .list_quote_ids()
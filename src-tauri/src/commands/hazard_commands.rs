@@ -1,6 +1,7 @@
 use tauri::State;
 use uuid::Uuid;
-use crate::core::AppState;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::Workspace;
 use crate::models::Hazard;
 use serde::{Deserialize, Serialize};
 
@@ -28,9 +29,10 @@ pub struct HazardListResponse {
 /// Create a new hazard
 #[tauri::command]
 pub async fn create_hazard(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateHazardRequest,
 ) -> Result<HazardResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let hazard = state
         .entity_manager
         .create_hazard(
@@ -47,10 +49,11 @@ pub async fn create_hazard(
 /// Get a hazard by ID
 #[tauri::command]
 pub async fn get_hazard(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     hazard_id: String,
 ) -> Result<HazardResponse, String> {
-    let id = Uuid::parse_str(&hazard_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&hazard_id).map_err(|e| e.to_string())?;
 
     let hazard = state
         .entity_manager
@@ -63,9 +66,10 @@ pub async fn get_hazard(
 /// Update a hazard
 #[tauri::command]
 pub async fn update_hazard(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     hazard: Hazard,
 ) -> Result<HazardResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_hazard = state
         .entity_manager
         .update_hazard(hazard)
@@ -77,24 +81,26 @@ pub async fn update_hazard(
 /// Delete a hazard
 #[tauri::command]
 pub async fn delete_hazard(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     hazard_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&hazard_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&hazard_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_hazard(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 /// List all hazard IDs
 #[tauri::command]
 pub async fn list_hazards(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<HazardListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let hazard_ids = state
         .entity_manager
         .list_hazard_ids()
@@ -0,0 +1,26 @@
+use tauri::State;
+use crate::core::{Workspace, HealthReport};
+use serde::Serialize;
+
+/// Response with project health check findings
+#[derive(Debug, Serialize)]
+pub struct HealthReportResponse {
+    pub report: HealthReport,
+}
+
+/// Check the project for traceability gaps enabled by the config warn_* flags
+#[tauri::command]
+pub async fn run_health_check(
+    workspace: State<'_, Workspace>,
+) -> Result<HealthReportResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let report = state
+        .health_checker
+        .check()
+        .map_err(|e| e.to_string())?;
+
+    Ok(HealthReportResponse { report })
+}
+
+// Note: Command tests are covered by HealthChecker tests
+// Integration tests with Tauri State will be added later
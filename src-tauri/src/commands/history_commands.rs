@@ -0,0 +1,102 @@
+use tauri::State;
+use crate::core::Workspace;
+use crate::storage::{HistoryEntry, UndoHistory};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Response with the step that was undone or redone; `None` if there was nothing to do
+#[derive(Debug, Serialize)]
+pub struct HistoryStepResponse {
+    pub step: Option<HistoryEntry>,
+}
+
+/// Response with the available undo and redo steps
+#[derive(Debug, Serialize)]
+pub struct UndoHistoryResponse {
+    pub history: UndoHistory,
+}
+
+/// Undo the most recent change
+#[tauri::command]
+pub async fn undo(
+    workspace: State<'_, Workspace>,
+) -> Result<HistoryStepResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let step = state.undo().map_err(|e| e.to_string())?;
+
+    Ok(HistoryStepResponse { step })
+}
+
+/// Redo the most recently undone change
+#[tauri::command]
+pub async fn redo(
+    workspace: State<'_, Workspace>,
+) -> Result<HistoryStepResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let step = state.redo().map_err(|e| e.to_string())?;
+
+    Ok(HistoryStepResponse { step })
+}
+
+/// List the steps available to undo and redo
+#[tauri::command]
+pub async fn get_undo_history(
+    workspace: State<'_, Workspace>,
+) -> Result<UndoHistoryResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let history = state
+        .storage
+        .undo_history()
+        .map_err(|e| e.to_string())?;
+
+    Ok(UndoHistoryResponse { history })
+}
+
+/// Response with the token that closes an undo group
+#[derive(Debug, Serialize)]
+pub struct UndoGroupResponse {
+    pub token: Uuid,
+}
+
+/// Start grouping the following commands into one undo step
+#[tauri::command]
+pub async fn begin_undo_group(
+    workspace: State<'_, Workspace>,
+    label: String,
+) -> Result<UndoGroupResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let token = state
+        .storage
+        .begin_undo_group(&label)
+        .map_err(|e| e.to_string())?;
+
+    Ok(UndoGroupResponse { token })
+}
+
+/// Finish the undo step started by `begin_undo_group`
+#[tauri::command]
+pub async fn end_undo_group(
+    workspace: State<'_, Workspace>,
+    token: Uuid,
+) -> Result<(), String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    state
+        .storage
+        .end_undo_group(token)
+        .map_err(|e| e.to_string())
+}
+
+/// Close an undo group left open, keeping the changes it collected as one step
+#[tauri::command]
+pub async fn reset_undo_group(
+    workspace: State<'_, Workspace>,
+) -> Result<(), String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    state
+        .storage
+        .reset_undo_group()
+        .map_err(|e| e.to_string())
+}
+
+// Note: Command tests are covered by RonStorage and OperationLog tests
+// Integration tests with Tauri State will be added later
@@ -0,0 +1,139 @@
+use tauri::State;
+use uuid::Uuid;
+use crate::core::Workspace;
+use crate::models::{EntityType, Link, LinkMetadata, LinkType};
+use serde::{Deserialize, Serialize};
+
+/// Request to create a new link between two entities
+#[derive(Debug, Deserialize)]
+pub struct CreateLinkRequest {
+    pub from_entity_id: String,
+    pub from_entity_type: EntityType,
+    pub to_entity_id: String,
+    pub to_entity_type: EntityType,
+    pub link_type: LinkType,
+    pub metadata: Option<LinkMetadata>,
+}
+
+/// Response with link data
+#[derive(Debug, Serialize)]
+pub struct LinkResponse {
+    pub link: Link,
+}
+
+/// List of links
+#[derive(Debug, Serialize)]
+pub struct LinkListResponse {
+    pub links: Vec<Link>,
+}
+
+/// IDs of all entities reachable from an entity
+#[derive(Debug, Serialize)]
+pub struct ImpactedEntitiesResponse {
+    pub entity_ids: Vec<Uuid>,
+}
+
+/// Create a new link
+#[tauri::command]
+pub async fn create_link(
+    workspace: State<'_, Workspace>,
+    request: CreateLinkRequest,
+) -> Result<LinkResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let from_id = state.id_registry.resolve(&request.from_entity_id).map_err(|e| e.to_string())?;
+    let to_id = state.id_registry.resolve(&request.to_entity_id).map_err(|e| e.to_string())?;
+
+    let mut link_manager = state.link_manager.lock().map_err(|e| e.to_string())?;
+    let link = link_manager
+        .create_link(
+            from_id,
+            request.from_entity_type,
+            to_id,
+            request.to_entity_type,
+            request.link_type,
+            request.metadata,
+        )
+        .map_err(|e| e.to_string())?;
+
+    Ok(LinkResponse { link })
+}
+
+/// Delete a link
+#[tauri::command]
+pub async fn delete_link(
+    workspace: State<'_, Workspace>,
+    link_id: String,
+) -> Result<(), String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = Uuid::parse_str(&link_id).map_err(|e| e.to_string())?;
+
+    let mut link_manager = state.link_manager.lock().map_err(|e| e.to_string())?;
+    link_manager
+        .delete_link(&id)
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Get all links originating from an entity
+#[tauri::command]
+pub async fn get_links_from(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+) -> Result<LinkListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&entity_id).map_err(|e| e.to_string())?;
+
+    let link_manager = state.link_manager.lock().map_err(|e| e.to_string())?;
+    let links = link_manager.get_links_from(&id).into_iter().cloned().collect();
+
+    Ok(LinkListResponse { links })
+}
+
+/// Get all links pointing to an entity
+#[tauri::command]
+pub async fn get_links_to(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+) -> Result<LinkListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&entity_id).map_err(|e| e.to_string())?;
+
+    let link_manager = state.link_manager.lock().map_err(|e| e.to_string())?;
+    let links = link_manager.get_links_to(&id).into_iter().cloned().collect();
+
+    Ok(LinkListResponse { links })
+}
+
+/// Get all links from or to an entity
+#[tauri::command]
+pub async fn get_all_links(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+) -> Result<LinkListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&entity_id).map_err(|e| e.to_string())?;
+
+    let link_manager = state.link_manager.lock().map_err(|e| e.to_string())?;
+    let links = link_manager.get_all_links(&id).into_iter().cloned().collect();
+
+    Ok(LinkListResponse { links })
+}
+
+/// Get all entities impacted by a change to an entity (downstream traversal)
+#[tauri::command]
+pub async fn get_impacted_entities(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+) -> Result<ImpactedEntitiesResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&entity_id).map_err(|e| e.to_string())?;
+
+    let link_manager = state.link_manager.lock().map_err(|e| e.to_string())?;
+    let entity_ids = link_manager.get_impacted_entities(&id);
+
+    Ok(ImpactedEntitiesResponse { entity_ids })
+}
+
+// Note: Command tests are covered by LinkManager tests
+// Integration tests with Tauri State will be added later
@@ -1,6 +1,7 @@
 use tauri::State;
 use uuid::Uuid;
-use crate::core::AppState;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::Workspace;
 use crate::models::{Manufacturing, WorkInstructionStep};
 use serde::{Deserialize, Serialize};
 
@@ -29,9 +30,10 @@ pub struct ManufacturingListResponse {
 /// Create a new manufacturing process
 #[tauri::command]
 pub async fn create_manufacturing(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateManufacturingRequest,
 ) -> Result<ManufacturingResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let manufacturing = state
         .entity_manager
         .create_manufacturing(
@@ -49,10 +51,11 @@ pub async fn create_manufacturing(
 /// Get a manufacturing process by ID
 #[tauri::command]
 pub async fn get_manufacturing(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     manufacturing_id: String,
 ) -> Result<ManufacturingResponse, String> {
-    let id = Uuid::parse_str(&manufacturing_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&manufacturing_id).map_err(|e| e.to_string())?;
 
     let manufacturing = state
         .entity_manager
@@ -65,9 +68,10 @@ pub async fn get_manufacturing(
 /// Update a manufacturing process
 #[tauri::command]
 pub async fn update_manufacturing(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     manufacturing: Manufacturing,
 ) -> Result<ManufacturingResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_manufacturing = state
         .entity_manager
         .update_manufacturing(manufacturing)
@@ -79,24 +83,26 @@ pub async fn update_manufacturing(
 /// Delete a manufacturing process
 #[tauri::command]
 pub async fn delete_manufacturing(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     manufacturing_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&manufacturing_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&manufacturing_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_manufacturing(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 /// List all manufacturing process IDs
 #[tauri::command]
 pub async fn list_manufacturing(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<ManufacturingListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let manufacturing_ids = state
         .entity_manager
         .list_manufacturing_ids()
@@ -0,0 +1,26 @@
+use tauri::State;
+use crate::core::Workspace;
+use crate::storage::{MigrationRegistry, MigrationReport};
+use serde::Serialize;
+
+/// Response with the outcome of a project schema migration
+#[derive(Debug, Serialize)]
+pub struct MigrationReportResponse {
+    pub report: MigrationReport,
+}
+
+/// Rewrite all entity files that use an older schema version
+#[tauri::command]
+pub async fn migrate_project(
+    workspace: State<'_, Workspace>,
+) -> Result<MigrationReportResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let report = MigrationRegistry::builtin()
+        .migrate_project(&state.storage, &state.audit)
+        .map_err(|e| e.to_string())?;
+
+    Ok(MigrationReportResponse { report })
+}
+
+// Note: Command tests are covered by MigrationRegistry tests
+// Integration tests with Tauri State will be added later
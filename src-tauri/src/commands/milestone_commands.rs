@@ -1,7 +1,8 @@
 use tauri::State;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
-use crate::core::AppState;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::Workspace;
 use crate::models::Milestone;
 use serde::{Deserialize, Serialize};
 
@@ -28,9 +29,10 @@ pub struct MilestoneListResponse {
 /// Create a new milestone
 #[tauri::command]
 pub async fn create_milestone(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateMilestoneRequest,
 ) -> Result<MilestoneResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let milestone = state
         .entity_manager
         .create_milestone(
@@ -46,10 +48,11 @@ pub async fn create_milestone(
 /// Get a milestone by ID
 #[tauri::command]
 pub async fn get_milestone(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     milestone_id: String,
 ) -> Result<MilestoneResponse, String> {
-    let id = Uuid::parse_str(&milestone_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&milestone_id).map_err(|e| e.to_string())?;
 
     let milestone = state
         .entity_manager
@@ -62,9 +65,10 @@ pub async fn get_milestone(
 /// Update a milestone
 #[tauri::command]
 pub async fn update_milestone(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     milestone: Milestone,
 ) -> Result<MilestoneResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_milestone = state
         .entity_manager
         .update_milestone(milestone)
@@ -76,24 +80,26 @@ pub async fn update_milestone(
 /// Delete a milestone
 #[tauri::command]
 pub async fn delete_milestone(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     milestone_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&milestone_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&milestone_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_milestone(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 /// List all milestone IDs
 #[tauri::command]
 pub async fn list_milestones(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<MilestoneListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let milestone_ids = state
         .storage
         .list_ids(&crate::models::EntityType::Milestone)
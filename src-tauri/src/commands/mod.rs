@@ -13,12 +13,27 @@ pub mod requirement_commands;
 pub mod risk_commands;
 pub mod hazard_commands;
 pub mod risk_control_commands;
+pub mod link_commands;
+pub mod delete_commands;
+pub mod config_commands;
+pub mod health_commands;
+pub mod query_commands;
+pub mod migration_commands;
+pub mod version_control_commands;
+pub mod history_commands;
+pub mod workspace_commands;
+pub mod workflow_commands;
+pub mod signature_commands;
+pub mod audit_commands;
+pub mod comment_commands;
 
 pub use task_commands::{create_task, get_task, update_task, delete_task, list_tasks};
 pub use milestone_commands::{create_milestone, get_milestone, update_milestone, delete_milestone, list_milestones};
 pub use resource_commands::{create_resource, get_resource, update_resource, delete_resource, list_resources};
 pub use calendar_commands::{create_calendar, get_calendar, update_calendar, delete_calendar, list_calendars};
-pub use baseline_commands::{create_baseline, get_baseline, update_baseline, delete_baseline, list_baselines};
+pub use baseline_commands::{
+    create_baseline, get_baseline, update_baseline, delete_baseline, list_baselines, diff_task_baseline,
+};
 pub use calculation_commands::{
     calculate_critical_path, calculate_evm,
     calculate_worst_case, calculate_rss, calculate_monte_carlo,
@@ -54,3 +69,28 @@ pub use hazard_commands::{
 pub use risk_control_commands::{
     create_risk_control, get_risk_control, update_risk_control, delete_risk_control, list_risk_controls,
 };
+pub use link_commands::{
+    create_link, delete_link, get_links_from, get_links_to, get_all_links, get_impacted_entities,
+};
+pub use delete_commands::preview_delete;
+pub use config_commands::{get_config, update_config, revalidate_entities};
+pub use health_commands::run_health_check;
+pub use query_commands::{query_entities, search_entities, refresh_cache, resolve_entity_id};
+pub use migration_commands::migrate_project;
+pub use version_control_commands::{
+    init_repository, commit_changes, get_entity_history, diff_entity_revisions, restore_entity_revision,
+    get_merge_conflicts, resolve_merge_conflict,
+};
+pub use history_commands::{undo, redo, get_undo_history, begin_undo_group, end_undo_group, reset_undo_group};
+pub use workspace_commands::{
+    create_project, open_project, close_project, get_current_project, get_recent_projects,
+};
+pub use workflow_commands::{transition_entity_status, get_available_transitions};
+pub use signature_commands::{sign_entity, get_entity_signatures};
+pub use audit_commands::{
+    query_audit_log, get_entity_audit_history, verify_audit_log, get_audit_user, set_audit_user,
+};
+pub use comment_commands::{
+    create_comment, reply_to_comment, get_comment, edit_comment, resolve_comment, delete_comment,
+    get_comment_threads, get_unresolved_comment_count, mark_comments_seen, get_unseen_comments,
+};
@@ -0,0 +1,94 @@
+use tauri::State;
+use crate::core::Workspace;
+use crate::core::entity_manager::EntityRef;
+use crate::storage::sqlite_cache::{EntityQuery, EntityPage, RefreshStats, SearchQuery, SearchHit};
+use serde::Serialize;
+
+/// Response with one page of entities
+#[derive(Debug, Serialize)]
+pub struct EntityPageResponse {
+    pub page: EntityPage,
+}
+
+/// Response with ranked search hits
+#[derive(Debug, Serialize)]
+pub struct SearchResponse {
+    pub hits: Vec<SearchHit>,
+}
+
+/// Response with the outcome of a cache refresh
+#[derive(Debug, Serialize)]
+pub struct RefreshCacheResponse {
+    pub stats: RefreshStats,
+}
+
+/// Response with the entity an ID refers to
+#[derive(Debug, Serialize)]
+pub struct ResolveIdResponse {
+    pub entity: EntityRef,
+}
+
+/// List entities with filtering, sorting and pagination from the query cache
+#[tauri::command]
+pub async fn query_entities(
+    workspace: State<'_, Workspace>,
+    query: EntityQuery,
+) -> Result<EntityPageResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    // Pick up any files changed since the last query
+    state.cache.refresh().map_err(|e| e.to_string())?;
+
+    let page = state
+        .cache
+        .query_entities(&query)
+        .map_err(|e| e.to_string())?;
+
+    Ok(EntityPageResponse { page })
+}
+
+/// Full-text search across all entity types
+#[tauri::command]
+pub async fn search_entities(
+    workspace: State<'_, Workspace>,
+    query: SearchQuery,
+) -> Result<SearchResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    state.cache.refresh().map_err(|e| e.to_string())?;
+
+    let hits = state
+        .cache
+        .search(&query)
+        .map_err(|e| e.to_string())?;
+
+    Ok(SearchResponse { hits })
+}
+
+/// Re-index changed entity and link files into the query cache
+#[tauri::command]
+pub async fn refresh_cache(
+    workspace: State<'_, Workspace>,
+) -> Result<RefreshCacheResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let stats = state.cache.refresh().map_err(|e| e.to_string())?;
+
+    Ok(RefreshCacheResponse { stats })
+}
+
+/// Look up an entity by UUID or human-readable ID (e.g. REQ-0001)
+#[tauri::command]
+pub async fn resolve_entity_id(
+    workspace: State<'_, Workspace>,
+    id: String,
+) -> Result<ResolveIdResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let entity = state
+        .id_registry
+        .lookup(&id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Entity not found: {}", id))?;
+
+    Ok(ResolveIdResponse { entity })
+}
+
+// Note: Command tests are covered by SqliteCache tests
+// Integration tests with Tauri State will be added later
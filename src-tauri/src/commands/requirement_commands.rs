@@ -1,6 +1,7 @@
 use tauri::State;
 use uuid::Uuid;
-use crate::core::AppState;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::Workspace;
 use crate::models::Requirement;
 use serde::{Deserialize, Serialize};
 
@@ -27,9 +28,10 @@ pub struct RequirementListResponse {
 /// Create a new requirement
 #[tauri::command]
 pub async fn create_requirement(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateRequirementRequest,
 ) -> Result<RequirementResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let requirement = state
         .entity_manager
         .create_requirement(
@@ -45,10 +47,11 @@ pub async fn create_requirement(
 /// Get a requirement by ID
 #[tauri::command]
 pub async fn get_requirement(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     requirement_id: String,
 ) -> Result<RequirementResponse, String> {
-    let id = Uuid::parse_str(&requirement_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&requirement_id).map_err(|e| e.to_string())?;
 
     let requirement = state
         .entity_manager
@@ -61,9 +64,10 @@ pub async fn get_requirement(
 /// Update a requirement
 #[tauri::command]
 pub async fn update_requirement(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     requirement: Requirement,
 ) -> Result<RequirementResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_requirement = state
         .entity_manager
         .update_requirement(requirement)
@@ -75,24 +79,26 @@ pub async fn update_requirement(
 /// Delete a requirement
 #[tauri::command]
 pub async fn delete_requirement(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     requirement_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&requirement_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&requirement_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_requirement(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 /// List all requirement IDs
 #[tauri::command]
 pub async fn list_requirements(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<RequirementListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let requirement_ids = state
         .entity_manager
         .list_requirement_ids()
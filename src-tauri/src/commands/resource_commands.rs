@@ -1,6 +1,7 @@
 use tauri::State;
 use uuid::Uuid;
-use crate::core::AppState;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::Workspace;
 use crate::models::{Resource, ResourceType};
 use serde::{Deserialize, Serialize};
 
@@ -29,9 +30,10 @@ pub struct ResourceListResponse {
 /// Create a new resource
 #[tauri::command]
 pub async fn create_resource(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateResourceRequest,
 ) -> Result<ResourceResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let mut resource = state
         .entity_manager
         .create_resource(
@@ -57,10 +59,11 @@ pub async fn create_resource(
 /// Get a resource by ID
 #[tauri::command]
 pub async fn get_resource(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     resource_id: String,
 ) -> Result<ResourceResponse, String> {
-    let id = Uuid::parse_str(&resource_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&resource_id).map_err(|e| e.to_string())?;
 
     let resource = state
         .entity_manager
@@ -73,9 +76,10 @@ pub async fn get_resource(
 /// Update a resource
 #[tauri::command]
 pub async fn update_resource(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     resource: Resource,
 ) -> Result<ResourceResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_resource = state
         .entity_manager
         .update_resource(resource)
@@ -87,24 +91,26 @@ pub async fn update_resource(
 /// Delete a resource
 #[tauri::command]
 pub async fn delete_resource(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     resource_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&resource_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&resource_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_resource(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 /// List all resource IDs
 #[tauri::command]
 pub async fn list_resources(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<ResourceListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let resource_ids = state
         .storage
         .list_ids(&crate::models::EntityType::Resource)
@@ -1,6 +1,7 @@
 use tauri::State;
 use uuid::Uuid;
-use crate::core::AppState;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::Workspace;
 use crate::models::Risk;
 use serde::{Deserialize, Serialize};
 
@@ -29,9 +30,10 @@ pub struct RiskListResponse {
 /// Create a new risk
 #[tauri::command]
 pub async fn create_risk(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateRiskRequest,
 ) -> Result<RiskResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let risk = state
         .entity_manager
         .create_risk(
@@ -49,10 +51,11 @@ pub async fn create_risk(
 /// Get a risk by ID
 #[tauri::command]
 pub async fn get_risk(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     risk_id: String,
 ) -> Result<RiskResponse, String> {
-    let id = Uuid::parse_str(&risk_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&risk_id).map_err(|e| e.to_string())?;
 
     let risk = state
         .entity_manager
@@ -65,9 +68,10 @@ pub async fn get_risk(
 /// Update a risk
 #[tauri::command]
 pub async fn update_risk(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     risk: Risk,
 ) -> Result<RiskResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_risk = state
         .entity_manager
         .update_risk(risk)
@@ -79,24 +83,26 @@ pub async fn update_risk(
 /// Delete a risk
 #[tauri::command]
 pub async fn delete_risk(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     risk_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&risk_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&risk_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_risk(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 /// List all risk IDs
 #[tauri::command]
 pub async fn list_risks(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<RiskListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let risk_ids = state
         .entity_manager
         .list_risk_ids()
@@ -1,6 +1,7 @@
 use tauri::State;
 use uuid::Uuid;
-use crate::core::AppState;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::Workspace;
 use crate::models::RiskControl;
 use serde::{Deserialize, Serialize};
 
@@ -27,9 +28,10 @@ pub struct RiskControlListResponse {
 /// Create a new risk control
 #[tauri::command]
 pub async fn create_risk_control(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateRiskControlRequest,
 ) -> Result<RiskControlResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let risk_control = state
         .entity_manager
         .create_risk_control(
@@ -45,10 +47,11 @@ pub async fn create_risk_control(
 /// Get a risk control by ID
 #[tauri::command]
 pub async fn get_risk_control(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     risk_control_id: String,
 ) -> Result<RiskControlResponse, String> {
-    let id = Uuid::parse_str(&risk_control_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&risk_control_id).map_err(|e| e.to_string())?;
 
     let risk_control = state
         .entity_manager
@@ -61,9 +64,10 @@ pub async fn get_risk_control(
 /// Update a risk control
 #[tauri::command]
 pub async fn update_risk_control(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     risk_control: RiskControl,
 ) -> Result<RiskControlResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_risk_control = state
         .entity_manager
         .update_risk_control(risk_control)
@@ -75,24 +79,26 @@ pub async fn update_risk_control(
 /// Delete a risk control
 #[tauri::command]
 pub async fn delete_risk_control(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     risk_control_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&risk_control_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&risk_control_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_risk_control(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 /// List all risk control IDs
 #[tauri::command]
 pub async fn list_risk_controls(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<RiskControlListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let risk_control_ids = state
         .entity_manager
         .list_risk_control_ids()
@@ -0,0 +1,69 @@
+use tauri::State;
+use crate::core::{SignatureRecord, Workspace};
+use crate::models::{Signature, SignatureMeaning};
+use serde::Serialize;
+
+/// Response with the signature that was applied
+#[derive(Debug, Serialize)]
+pub struct SignatureResponse {
+    pub signature: Signature,
+}
+
+/// Response with an entity's signatures and the hash of its current revision
+#[derive(Debug, Serialize)]
+pub struct EntitySignaturesResponse {
+    pub content_hash: String,
+    pub signatures: Vec<SignatureRecord>,
+}
+
+/// Sign the current revision of an entity
+#[tauri::command]
+pub async fn sign_entity(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+    signer: String,
+    role: String,
+    meaning: SignatureMeaning,
+) -> Result<SignatureResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let entity = state
+        .id_registry
+        .lookup(&entity_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Entity not found: {}", entity_id))?;
+
+    let signature = state
+        .signatures
+        .sign(&entity.entity_type, &entity.entity_id, &signer, &role, meaning)
+        .map_err(|e| e.to_string())?;
+
+    Ok(SignatureResponse { signature })
+}
+
+/// List the signatures on an entity, marking those invalidated by later edits
+#[tauri::command]
+pub async fn get_entity_signatures(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+) -> Result<EntitySignaturesResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let entity = state
+        .id_registry
+        .lookup(&entity_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Entity not found: {}", entity_id))?;
+
+    let content_hash = state
+        .signatures
+        .content_hash(&entity.entity_type, &entity.entity_id)
+        .map_err(|e| e.to_string())?;
+    let signatures = state
+        .signatures
+        .signatures(&entity.entity_type, &entity.entity_id)
+        .map_err(|e| e.to_string())?;
+
+    Ok(EntitySignaturesResponse { content_hash, signatures })
+}
+
+// Note: Command tests are covered by SignatureManager tests
+// Integration tests with Tauri State will be added later
@@ -1,7 +1,8 @@
 use tauri::State;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
-use crate::core::{AppState, EdtError};
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::{Workspace, EdtError};
 use crate::models::{Task, TaskType};
 use serde::{Deserialize, Serialize};
 
@@ -30,9 +31,10 @@ pub struct TaskListResponse {
 /// Create a new task
 #[tauri::command]
 pub async fn create_task(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateTaskRequest,
 ) -> Result<TaskResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let task = state
         .entity_manager
         .create_task(
@@ -50,10 +52,11 @@ pub async fn create_task(
 /// Get a task by ID
 #[tauri::command]
 pub async fn get_task(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     task_id: String,
 ) -> Result<TaskResponse, String> {
-    let id = Uuid::parse_str(&task_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&task_id).map_err(|e| e.to_string())?;
 
     let task = state
         .entity_manager
@@ -66,9 +69,10 @@ pub async fn get_task(
 /// Update a task
 #[tauri::command]
 pub async fn update_task(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     task: Task,
 ) -> Result<TaskResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_task = state
         .entity_manager
         .update_task(task)
@@ -80,24 +84,26 @@ pub async fn update_task(
 /// Delete a task
 #[tauri::command]
 pub async fn delete_task(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     task_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&task_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&task_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_task(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 /// List all task IDs
 #[tauri::command]
 pub async fn list_tasks(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<TaskListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let task_ids = state
         .entity_manager
         .list_task_ids()
@@ -1,6 +1,7 @@
 use tauri::State;
 use uuid::Uuid;
-use crate::core::AppState;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::Workspace;
 use crate::models::{Validation, TestPriority};
 use serde::{Deserialize, Serialize};
 
@@ -30,9 +31,10 @@ pub struct ValidationListResponse {
 /// Create a new validation
 #[tauri::command]
 pub async fn create_validation(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateValidationRequest,
 ) -> Result<ValidationResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let validation = state
         .entity_manager
         .create_validation(
@@ -51,10 +53,11 @@ pub async fn create_validation(
 /// Get a validation by ID
 #[tauri::command]
 pub async fn get_validation(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     validation_id: String,
 ) -> Result<ValidationResponse, String> {
-    let id = Uuid::parse_str(&validation_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&validation_id).map_err(|e| e.to_string())?;
 
     let validation = state
         .entity_manager
@@ -67,9 +70,10 @@ pub async fn get_validation(
 /// Update a validation
 #[tauri::command]
 pub async fn update_validation(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     validation: Validation,
 ) -> Result<ValidationResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_validation = state
         .entity_manager
         .update_validation(validation)
@@ -81,24 +85,26 @@ pub async fn update_validation(
 /// Delete a validation
 #[tauri::command]
 pub async fn delete_validation(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     validation_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&validation_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&validation_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_validation(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 /// List all validation IDs
 #[tauri::command]
 pub async fn list_validations(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<ValidationListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let validation_ids = state
         .entity_manager
         .list_validation_ids()
@@ -1,6 +1,7 @@
 use tauri::State;
 use uuid::Uuid;
-use crate::core::AppState;
+use crate::commands::delete_commands::DeleteResponse;
+use crate::core::Workspace;
 use crate::models::{Verification, TestPriority, TestStep};
 use serde::{Deserialize, Serialize};
 
@@ -30,9 +31,10 @@ pub struct VerificationListResponse {
 /// Create a new verification
 #[tauri::command]
 pub async fn create_verification(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     request: CreateVerificationRequest,
 ) -> Result<VerificationResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let verification = state
         .entity_manager
         .create_verification(
@@ -51,10 +53,11 @@ pub async fn create_verification(
 /// Get a verification by ID
 #[tauri::command]
 pub async fn get_verification(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     verification_id: String,
 ) -> Result<VerificationResponse, String> {
-    let id = Uuid::parse_str(&verification_id).map_err(|e| e.to_string())?;
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&verification_id).map_err(|e| e.to_string())?;
 
     let verification = state
         .entity_manager
@@ -67,9 +70,10 @@ pub async fn get_verification(
 /// Update a verification
 #[tauri::command]
 pub async fn update_verification(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     verification: Verification,
 ) -> Result<VerificationResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let updated_verification = state
         .entity_manager
         .update_verification(verification)
@@ -81,24 +85,26 @@ pub async fn update_verification(
 /// Delete a verification
 #[tauri::command]
 pub async fn delete_verification(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
     verification_id: String,
-) -> Result<(), String> {
-    let id = Uuid::parse_str(&verification_id).map_err(|e| e.to_string())?;
+) -> Result<DeleteResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&verification_id).map_err(|e| e.to_string())?;
 
-    state
+    let impact = state
         .entity_manager
         .delete_verification(&id)
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    Ok(DeleteResponse { impact })
 }
 
 /// List all verification IDs
 #[tauri::command]
 pub async fn list_verifications(
-    state: State<'_, AppState>,
+    workspace: State<'_, Workspace>,
 ) -> Result<VerificationListResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
     let verification_ids = state
         .entity_manager
         .list_verification_ids()
@@ -0,0 +1,167 @@
+use tauri::State;
+use crate::core::{Workspace, CommitInfo, ConflictResolution, EntityConflict, EntityRevisionDiff};
+use crate::models::EntityType;
+use serde::Serialize;
+
+/// Response for repository initialization
+#[derive(Debug, Serialize)]
+pub struct InitRepositoryResponse {
+    /// False if the project was already a repository
+    pub created: bool,
+}
+
+/// Response with a single commit
+#[derive(Debug, Serialize)]
+pub struct CommitResponse {
+    pub commit: CommitInfo,
+}
+
+/// Response with the commits that touched an entity
+#[derive(Debug, Serialize)]
+pub struct EntityHistoryResponse {
+    pub commits: Vec<CommitInfo>,
+}
+
+/// Response with field-level changes between two revisions
+#[derive(Debug, Serialize)]
+pub struct EntityDiffResponse {
+    pub diff: EntityRevisionDiff,
+}
+
+/// Response with the restored entity
+#[derive(Debug, Serialize)]
+pub struct RestoreEntityResponse {
+    pub entity: serde_json::Value,
+}
+
+/// Initialize a git repository in the project folder
+#[tauri::command]
+pub async fn init_repository(
+    workspace: State<'_, Workspace>,
+) -> Result<InitRepositoryResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let created = state
+        .version_control
+        .init()
+        .map_err(|e| e.to_string())?;
+
+    Ok(InitRepositoryResponse { created })
+}
+
+/// Commit all changes in the project folder
+#[tauri::command]
+pub async fn commit_changes(
+    workspace: State<'_, Workspace>,
+    message: String,
+) -> Result<CommitResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let commit = state
+        .version_control
+        .commit(&message)
+        .map_err(|e| e.to_string())?;
+
+    Ok(CommitResponse { commit })
+}
+
+/// List the commits that changed an entity
+#[tauri::command]
+pub async fn get_entity_history(
+    workspace: State<'_, Workspace>,
+    entity_type: EntityType,
+    entity_id: String,
+) -> Result<EntityHistoryResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&entity_id).map_err(|e| e.to_string())?;
+
+    let commits = state
+        .version_control
+        .entity_history(&entity_type, &id)
+        .map_err(|e| e.to_string())?;
+
+    Ok(EntityHistoryResponse { commits })
+}
+
+/// Compare an entity between two revisions; omit `to_revision` to compare with the working copy
+#[tauri::command]
+pub async fn diff_entity_revisions(
+    workspace: State<'_, Workspace>,
+    entity_type: EntityType,
+    entity_id: String,
+    from_revision: String,
+    to_revision: Option<String>,
+) -> Result<EntityDiffResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&entity_id).map_err(|e| e.to_string())?;
+
+    let diff = state
+        .version_control
+        .diff_entity(&entity_type, &id, &from_revision, to_revision.as_deref())
+        .map_err(|e| e.to_string())?;
+
+    Ok(EntityDiffResponse { diff })
+}
+
+/// Response with the entities left conflicted by a merge
+#[derive(Debug, Serialize)]
+pub struct MergeConflictsResponse {
+    pub conflicts: Vec<EntityConflict>,
+}
+
+/// Response with the merged entity; `null` if the resolution deleted it
+#[derive(Debug, Serialize)]
+pub struct ResolveConflictResponse {
+    pub entity: serde_json::Value,
+}
+
+/// Restore an entity's file to its content at an earlier revision
+#[tauri::command]
+pub async fn restore_entity_revision(
+    workspace: State<'_, Workspace>,
+    entity_type: EntityType,
+    entity_id: String,
+    revision: String,
+) -> Result<RestoreEntityResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&entity_id).map_err(|e| e.to_string())?;
+
+    let entity = state
+        .restore_entity_revision(&entity_type, &id, &revision)
+        .map_err(|e| e.to_string())?;
+
+    Ok(RestoreEntityResponse { entity })
+}
+
+/// List entities with unresolved merge conflicts, field by field
+#[tauri::command]
+pub async fn get_merge_conflicts(
+    workspace: State<'_, Workspace>,
+) -> Result<MergeConflictsResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let conflicts = state
+        .version_control
+        .merge_conflicts()
+        .map_err(|e| e.to_string())?;
+
+    Ok(MergeConflictsResponse { conflicts })
+}
+
+/// Resolve every conflicting field of an entity and mark the file resolved
+#[tauri::command]
+pub async fn resolve_merge_conflict(
+    workspace: State<'_, Workspace>,
+    entity_type: EntityType,
+    entity_id: String,
+    resolutions: Vec<ConflictResolution>,
+) -> Result<ResolveConflictResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let id = state.id_registry.resolve(&entity_id).map_err(|e| e.to_string())?;
+
+    let entity = state
+        .resolve_merge_conflict(&entity_type, &id, &resolutions)
+        .map_err(|e| e.to_string())?;
+
+    Ok(ResolveConflictResponse { entity })
+}
+
+// Note: Command tests are covered by VersionControl tests
+// Integration tests with Tauri State will be added later
@@ -0,0 +1,69 @@
+use tauri::State;
+use crate::core::{AvailableTransition, Workspace};
+use crate::models::{EntityStatus, StatusChange};
+use serde::Serialize;
+
+/// Response with the recorded status change
+#[derive(Debug, Serialize)]
+pub struct StatusChangeResponse {
+    pub change: StatusChange,
+}
+
+/// Response with the statuses an entity can move to next
+#[derive(Debug, Serialize)]
+pub struct AvailableTransitionsResponse {
+    pub status: EntityStatus,
+    pub transitions: Vec<AvailableTransition>,
+}
+
+/// Move an entity to a new status under the project's workflow rules
+#[tauri::command]
+pub async fn transition_entity_status(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+    status: EntityStatus,
+    changed_by: String,
+    reason: Option<String>,
+) -> Result<StatusChangeResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let entity = state
+        .id_registry
+        .lookup(&entity_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Entity not found: {}", entity_id))?;
+
+    let change = state
+        .workflow
+        .transition(&entity.entity_type, &entity.entity_id, status, &changed_by, reason)
+        .map_err(|e| e.to_string())?;
+
+    Ok(StatusChangeResponse { change })
+}
+
+/// List the transitions available from an entity's current status
+#[tauri::command]
+pub async fn get_available_transitions(
+    workspace: State<'_, Workspace>,
+    entity_id: String,
+) -> Result<AvailableTransitionsResponse, String> {
+    let state = workspace.project().map_err(|e| e.to_string())?;
+    let entity = state
+        .id_registry
+        .lookup(&entity_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Entity not found: {}", entity_id))?;
+
+    let status = state
+        .workflow
+        .status(&entity.entity_type, &entity.entity_id)
+        .map_err(|e| e.to_string())?;
+    let transitions = state
+        .workflow
+        .available_transitions(&entity.entity_type, &entity.entity_id)
+        .map_err(|e| e.to_string())?;
+
+    Ok(AvailableTransitionsResponse { status, transitions })
+}
+
+// Note: Command tests are covered by WorkflowEngine tests
+// Integration tests with Tauri State will be added later
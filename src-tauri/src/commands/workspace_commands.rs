@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+use tauri::State;
+use crate::core::{ProjectInfo, Workspace};
+use serde::Serialize;
+
+/// Response with the project that was opened or created
+#[derive(Debug, Serialize)]
+pub struct ProjectResponse {
+    pub project: ProjectInfo,
+}
+
+/// Response with the open project's folder, if any
+#[derive(Debug, Serialize)]
+pub struct CurrentProjectResponse {
+    pub path: Option<PathBuf>,
+}
+
+/// Response with recently opened projects, most recent first
+#[derive(Debug, Serialize)]
+pub struct RecentProjectsResponse {
+    pub projects: Vec<ProjectInfo>,
+}
+
+/// Create a new project in a folder and open it
+#[tauri::command]
+pub async fn create_project(
+    workspace: State<'_, Workspace>,
+    path: String,
+) -> Result<ProjectResponse, String> {
+    let project = workspace
+        .create_project(&PathBuf::from(path))
+        .map_err(|e| e.to_string())?;
+
+    Ok(ProjectResponse { project })
+}
+
+/// Open an existing project, closing the current one
+#[tauri::command]
+pub async fn open_project(
+    workspace: State<'_, Workspace>,
+    path: String,
+) -> Result<ProjectResponse, String> {
+    let project = workspace
+        .open_project(&PathBuf::from(path))
+        .map_err(|e| e.to_string())?;
+
+    Ok(ProjectResponse { project })
+}
+
+/// Close the open project; returns false if none was open
+#[tauri::command]
+pub async fn close_project(
+    workspace: State<'_, Workspace>,
+) -> Result<bool, String> {
+    workspace.close_project().map_err(|e| e.to_string())
+}
+
+/// Get the folder of the open project
+#[tauri::command]
+pub async fn get_current_project(
+    workspace: State<'_, Workspace>,
+) -> Result<CurrentProjectResponse, String> {
+    let path = workspace
+        .project()
+        .ok()
+        .map(|state| state.project_root().clone());
+
+    Ok(CurrentProjectResponse { path })
+}
+
+/// List recently opened projects
+#[tauri::command]
+pub async fn get_recent_projects(
+    workspace: State<'_, Workspace>,
+) -> Result<RecentProjectsResponse, String> {
+    let projects = workspace.recent_projects().map_err(|e| e.to_string())?;
+
+    Ok(RecentProjectsResponse { projects })
+}
+
+// Note: Command tests are covered by Workspace tests
+// Integration tests with Tauri State will be added later
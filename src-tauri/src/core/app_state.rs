@@ -1,37 +1,200 @@
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, RwLock};
 use std::path::PathBuf;
-use crate::core::{EdtResult, EntityManager, LinkManager, CalculationEngine};
-use crate::storage::RonStorage;
+use serde_json::Value;
+use uuid::Uuid;
+use crate::core::{EdtResult, EdtError, EntityManager, LinkManager, LinkRules, CalculationEngine, HealthChecker, VersionControl};
+use crate::core::{AuditLog, ConflictResolution, EntityChange, FileWatcher, IdRegistry, SignatureManager, WorkflowEngine};
+use crate::models::{EntityType, ProjectConfig};
+use crate::storage::{RonStorage, ConfigStorage, SqliteCache, SeenComments, AppliedStep, HistoryEntry};
 
 /// Application state shared across Tauri commands
 pub struct AppState {
     pub entity_manager: Arc<EntityManager>,
     pub link_manager: Arc<Mutex<LinkManager>>,
     pub calculation_engine: Arc<CalculationEngine>,
+    pub health_checker: Arc<HealthChecker>,
+    pub version_control: Arc<VersionControl>,
+    pub id_registry: Arc<IdRegistry>,
+    pub workflow: Arc<WorkflowEngine>,
+    pub signatures: Arc<SignatureManager>,
+    pub audit: Arc<AuditLog>,
     pub storage: Arc<RonStorage>,
+    pub cache: Arc<SqliteCache>,
+    pub seen_comments: Arc<SeenComments>,
+    pub config: Arc<RwLock<ProjectConfig>>,
     pub project_root: PathBuf,
+    config_storage: ConfigStorage,
+    file_watcher: Mutex<Option<FileWatcher>>,
 }
 
 impl AppState {
     /// Create a new AppState for a project
     pub fn new(project_root: PathBuf) -> EdtResult<Self> {
         let storage = Arc::new(RonStorage::new(&project_root)?);
-        let entity_manager = Arc::new(EntityManager::new(Arc::clone(&storage)));
-        let link_manager = Arc::new(Mutex::new(LinkManager::new()));
+        let cache = Arc::new(SqliteCache::open(&project_root)?);
+        let seen_comments = Arc::new(SeenComments::new(&project_root));
+        let config_storage = ConfigStorage::new(&project_root);
+        let config = config_storage.load_or_create()?;
+
+        let mut link_manager = LinkManager::with_storage(Arc::clone(&storage))?;
+        link_manager.set_link_rules(LinkRules::from_config(&config));
+
+        let config = Arc::new(RwLock::new(config));
+        let link_manager = Arc::new(Mutex::new(link_manager));
+        let entity_manager = Arc::new(EntityManager::new(
+            Arc::clone(&storage),
+            Arc::clone(&link_manager),
+            Arc::clone(&config),
+        )?);
+        let id_registry = entity_manager.id_registry();
+        let workflow = entity_manager.workflow();
+        let signatures = entity_manager.signatures();
+        let audit = entity_manager.audit_log();
         let calculation_engine = Arc::new(CalculationEngine::new(
             Arc::clone(&entity_manager),
             Arc::clone(&link_manager)
         ));
+        let health_checker = Arc::new(HealthChecker::new(
+            Arc::clone(&entity_manager),
+            Arc::clone(&link_manager),
+            Arc::clone(&config),
+        ));
+        let version_control = Arc::new(VersionControl::new(
+            project_root.clone(),
+            Arc::clone(&storage),
+            Arc::clone(&workflow),
+            Arc::clone(&audit),
+        ));
 
         Ok(Self {
             entity_manager,
             link_manager,
             calculation_engine,
+            health_checker,
+            version_control,
+            id_registry,
+            workflow,
+            signatures,
+            audit,
             storage,
+            cache,
+            seen_comments,
+            config,
             project_root,
+            config_storage,
+            file_watcher: Mutex::new(None),
         })
     }
 
+    /// Get a copy of the current project configuration
+    pub fn get_config(&self) -> EdtResult<ProjectConfig> {
+        let config = self.config.read().map_err(|e| {
+            EdtError::ValidationError(format!("Failed to read project config: {}", e))
+        })?;
+        Ok(config.clone())
+    }
+
+    /// Validate and save a new project configuration, then apply it to the managers
+    pub fn update_config(&self, new_config: ProjectConfig) -> EdtResult<ProjectConfig> {
+        self.config_storage.save(&new_config)?;
+
+        let mut link_manager = self.link_manager.lock().map_err(|e| {
+            EdtError::ValidationError(format!("Failed to lock link manager: {}", e))
+        })?;
+        link_manager.set_link_rules(LinkRules::from_config(&new_config));
+
+        let mut config = self.config.write().map_err(|e| {
+            EdtError::ValidationError(format!("Failed to write project config: {}", e))
+        })?;
+        *config = new_config.clone();
+
+        Ok(new_config)
+    }
+
+    /// Undo the most recent change; returns `None` if there is nothing to undo
+    ///
+    /// Steps that would change a locked entity or an entity's status are refused.
+    pub fn undo(&self) -> EdtResult<Option<HistoryEntry>> {
+        let step = self
+            .storage
+            .undo_checked(|entity, current, target| self.workflow.check_file_rewrite(&entity.entity_type, current, target))?;
+        self.after_history(step)
+    }
+
+    /// Redo the most recently undone change; returns `None` if there is nothing to redo
+    pub fn redo(&self) -> EdtResult<Option<HistoryEntry>> {
+        let step = self
+            .storage
+            .redo_checked(|entity, current, target| self.workflow.check_file_rewrite(&entity.entity_type, current, target))?;
+        self.after_history(step)
+    }
+
+    /// Audit the files restored by undo or redo and bring in-memory state back in line with them
+    fn after_history(&self, step: Option<AppliedStep>) -> EdtResult<Option<HistoryEntry>> {
+        let Some(step) = step else { return Ok(None) };
+        self.audit.record_file_edits(&step.edits)?;
+        self.reload_from_files(step.entry.links_changed)?;
+        Ok(Some(step.entry))
+    }
+
+    /// Restore an entity's file to its content at an earlier revision
+    pub fn restore_entity_revision(&self, entity_type: &EntityType, entity_id: &Uuid, revision: &str) -> EdtResult<Value> {
+        let entity = self.version_control.restore_entity(entity_type, entity_id, revision)?;
+        self.reload_from_files(true)?;
+        Ok(entity)
+    }
+
+    /// Resolve an entity's merge conflicts; returns `Null` if the resolution deletes it
+    pub fn resolve_merge_conflict(
+        &self,
+        entity_type: &EntityType,
+        entity_id: &Uuid,
+        resolutions: &[ConflictResolution],
+    ) -> EdtResult<Value> {
+        let entity = self.version_control.resolve_conflict(entity_type, entity_id, resolutions)?;
+        self.reload_from_files(true)?;
+        Ok(entity)
+    }
+
+    /// Bring in-memory state in line with files rewritten behind the managers' backs
+    ///
+    /// Done explicitly rather than left to the file watcher, which may not be running.
+    fn reload_from_files(&self, links_changed: bool) -> EdtResult<()> {
+        if links_changed {
+            let mut link_manager = self.link_manager.lock().map_err(|e| {
+                EdtError::ValidationError(format!("Failed to lock link manager: {}", e))
+            })?;
+            link_manager.reload()?;
+        }
+        self.id_registry.invalidate()
+    }
+
+    /// Start watching the project for external file changes, replacing any running watcher
+    pub fn watch_files<F>(&self, on_change: F) -> EdtResult<()>
+    where
+        F: Fn(&[EntityChange]) + Send + 'static,
+    {
+        // Pulled or hand-edited files may bring new human-readable IDs
+        let id_registry = Arc::clone(&self.id_registry);
+        let watcher = FileWatcher::start(
+            self.project_root.clone(),
+            Arc::clone(&self.cache),
+            Arc::clone(&self.link_manager),
+            move |changes| {
+                if let Err(e) = id_registry.invalidate() {
+                    eprintln!("Failed to reset ID registry after file change: {}", e);
+                }
+                on_change(changes)
+            },
+        )?;
+
+        let mut file_watcher = self.file_watcher.lock().map_err(|e| {
+            EdtError::ValidationError(format!("Failed to lock file watcher: {}", e))
+        })?;
+        *file_watcher = Some(watcher);
+        Ok(())
+    }
+
     /// Get the project root path
     pub fn project_root(&self) -> &PathBuf {
         &self.project_root
@@ -70,4 +233,204 @@ mod tests {
         let link_manager = app_state.link_manager.lock().unwrap();
         assert_eq!(link_manager.link_count(), 0);
     }
+
+    #[test]
+    fn test_app_state_reloads_links() {
+        use crate::models::{EntityType, LinkType};
+        use uuid::Uuid;
+
+        let temp_dir = TempDir::new().unwrap();
+        let from_id = Uuid::new_v4();
+        let to_id = Uuid::new_v4();
+
+        {
+            let app_state = AppState::new(temp_dir.path().to_path_buf()).unwrap();
+            let mut link_manager = app_state.link_manager.lock().unwrap();
+            link_manager
+                .create_link(
+                    from_id,
+                    EntityType::Component,
+                    to_id,
+                    EntityType::Requirement,
+                    LinkType::Satisfies,
+                    None,
+                )
+                .unwrap();
+        }
+
+        // Re-opening the project restores the link
+        let app_state = AppState::new(temp_dir.path().to_path_buf()).unwrap();
+        let link_manager = app_state.link_manager.lock().unwrap();
+        assert_eq!(link_manager.link_count(), 1);
+        assert_eq!(link_manager.get_links_to(&to_id).len(), 1);
+    }
+
+    #[test]
+    fn test_app_state_undo_delete_restores_entity_and_links() {
+        use crate::models::{EntityType, LinkType};
+
+        let temp_dir = TempDir::new().unwrap();
+        let app_state = AppState::new(temp_dir.path().to_path_buf()).unwrap();
+        let manager = &app_state.entity_manager;
+
+        let req = manager
+            .create_requirement("Requirement".to_string(), "Description".to_string(), "System Requirement".to_string())
+            .unwrap();
+        let component = manager
+            .create_component("Component".to_string(), "Description".to_string(), "A".to_string())
+            .unwrap();
+        app_state
+            .link_manager
+            .lock()
+            .unwrap()
+            .create_link(
+                component.metadata.id,
+                EntityType::Component,
+                req.metadata.id,
+                EntityType::Requirement,
+                LinkType::Satisfies,
+                None,
+            )
+            .unwrap();
+
+        manager.delete_component(&component.metadata.id).unwrap();
+        assert!(manager.get_component(&component.metadata.id).is_err());
+        assert_eq!(app_state.link_manager.lock().unwrap().link_count(), 0);
+
+        let undone = app_state.undo().unwrap().unwrap();
+        assert_eq!(undone.label, "Delete Component");
+        assert!(manager.get_component(&component.metadata.id).is_ok());
+        assert_eq!(app_state.link_manager.lock().unwrap().link_count(), 1);
+
+        app_state.redo().unwrap();
+        assert!(manager.get_component(&component.metadata.id).is_err());
+        assert_eq!(app_state.link_manager.lock().unwrap().link_count(), 0);
+    }
+
+    #[test]
+    fn test_app_state_undo_respects_workflow() {
+        use crate::models::{EntityStatus, EntityType, SignatureMeaning};
+
+        let temp_dir = TempDir::new().unwrap();
+        let app_state = AppState::new(temp_dir.path().to_path_buf()).unwrap();
+        let id = app_state
+            .entity_manager
+            .create_risk("Overpressure".to_string(), "Line bursts".to_string(), "Safety Risk".to_string(), 3, 4)
+            .unwrap()
+            .metadata
+            .id;
+
+        // Status only changes through the workflow, so a transition cannot be undone
+        app_state
+            .workflow
+            .transition(&EntityType::Risk, &id, EntityStatus::PendingApproval, "Dana Reyes", None)
+            .unwrap();
+        assert!(matches!(app_state.undo(), Err(EdtError::InvalidTransition(_))));
+
+        app_state
+            .signatures
+            .sign(&EntityType::Risk, &id, "Dana Reyes", "Approver", SignatureMeaning::Approved)
+            .unwrap();
+        app_state
+            .workflow
+            .transition(&EntityType::Risk, &id, EntityStatus::Approved, "Dana Reyes", None)
+            .unwrap();
+        assert!(matches!(app_state.undo(), Err(EdtError::EntityLocked(EntityStatus::Approved))));
+
+        assert_eq!(app_state.workflow.status(&EntityType::Risk, &id).unwrap(), EntityStatus::Approved);
+        assert_eq!(app_state.storage.undo_history().unwrap().redo.len(), 0);
+    }
+
+    #[test]
+    fn test_app_state_undo_and_transition_audited() {
+        use crate::core::{AuditAction, AuditQuery};
+        use crate::models::{EntityStatus, EntityType};
+
+        let temp_dir = TempDir::new().unwrap();
+        let app_state = AppState::new(temp_dir.path().to_path_buf()).unwrap();
+        let mut risk = app_state
+            .entity_manager
+            .create_risk("Overpressure".to_string(), "Line bursts".to_string(), "Safety Risk".to_string(), 3, 4)
+            .unwrap();
+        let id = risk.metadata.id;
+        risk.severity = 5;
+        app_state.entity_manager.update_risk(risk).unwrap();
+
+        app_state.undo().unwrap().unwrap();
+        app_state
+            .workflow
+            .transition(&EntityType::Risk, &id, EntityStatus::PendingApproval, "Dana Reyes", None)
+            .unwrap();
+
+        let records = app_state.audit.query(&AuditQuery { entity_id: Some(id), ..Default::default() }).unwrap();
+        assert_eq!(records.len(), 4);
+        assert!(records[1..].iter().all(|r| r.action == AuditAction::Update));
+
+        let severity = records[2].changes.iter().find(|c| c.path == "severity").unwrap();
+        assert_eq!(severity.old_value, Some(serde_json::json!(5)));
+        assert_eq!(severity.new_value, Some(serde_json::json!(4)));
+        assert!(records[3].changes.iter().any(|c| c.path == "metadata.status"
+            && c.new_value == Some(serde_json::json!("PendingApproval"))));
+    }
+
+    #[test]
+    fn test_app_state_creates_config_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let app_state = AppState::new(temp_dir.path().to_path_buf()).unwrap();
+
+        assert!(temp_dir.path().join("project.toml").exists());
+        assert_eq!(app_state.get_config().unwrap().schema_version, "1.0.0");
+    }
+
+    #[test]
+    fn test_app_state_update_config_persists_and_applies() {
+        use crate::models::config::LinkRuleConfig;
+        use crate::models::{EntityType, LinkType};
+        use uuid::Uuid;
+
+        let temp_dir = TempDir::new().unwrap();
+        let app_state = AppState::new(temp_dir.path().to_path_buf()).unwrap();
+
+        let mut config = app_state.get_config().unwrap();
+        config.acceptable_risk_threshold = 4;
+        config.link_rules.push(LinkRuleConfig {
+            from_entity_type: EntityType::Task,
+            link_type: LinkType::Satisfies,
+            to_entity_type: EntityType::Requirement,
+        });
+        app_state.update_config(config).unwrap();
+
+        // Link rules take effect immediately
+        app_state
+            .link_manager
+            .lock()
+            .unwrap()
+            .create_link(
+                Uuid::new_v4(),
+                EntityType::Task,
+                Uuid::new_v4(),
+                EntityType::Requirement,
+                LinkType::Satisfies,
+                None,
+            )
+            .unwrap();
+
+        // And the config is reloaded on the next open
+        let reopened = AppState::new(temp_dir.path().to_path_buf()).unwrap();
+        let config = reopened.get_config().unwrap();
+        assert_eq!(config.acceptable_risk_threshold, 4);
+        assert_eq!(config.link_rules.len(), 1);
+    }
+
+    #[test]
+    fn test_app_state_update_config_rejects_invalid() {
+        let temp_dir = TempDir::new().unwrap();
+        let app_state = AppState::new(temp_dir.path().to_path_buf()).unwrap();
+
+        let mut config = app_state.get_config().unwrap();
+        config.probability_levels.clear();
+
+        assert!(app_state.update_config(config).is_err());
+        assert_eq!(app_state.get_config().unwrap().probability_levels.len(), 5);
+    }
 }
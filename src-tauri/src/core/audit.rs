@@ -0,0 +1,444 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, RwLock};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+use crate::core::{EdtResult, EdtError};
+use crate::core::diff::{diff_json, FieldChange};
+use crate::models::{EntityType, Link, StoredEntity};
+use crate::storage::{FileEdit, RonStorage};
+
+/// Audit log location, relative to the project root
+pub const AUDIT_LOG_FILE: &str = "audit/audit_log.jsonl";
+
+/// `previous_hash` of the first record
+const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+/// One change to an entity or link
+///
+/// Each record carries the blake3 hash of the previous one, so editing or removing
+/// a record breaks the chain from that point on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuditRecord {
+    pub sequence: u64,
+    pub timestamp: DateTime<Utc>,
+    pub user: String,
+    pub action: AuditAction,
+    /// The changed entity, or the source entity of a changed link
+    pub entity_id: Uuid,
+    pub entity_type: EntityType,
+    /// Set when the record is for a link
+    pub link_id: Option<Uuid>,
+    pub changes: Vec<FieldChange>,
+    pub previous_hash: String,
+    pub hash: String,
+}
+
+/// Filter for audit records; unset fields match everything
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuditQuery {
+    /// Records for this entity, including links to or from it
+    pub entity_id: Option<Uuid>,
+    pub user: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Result of checking the hash chain
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditVerification {
+    pub record_count: usize,
+    /// Sequence number of the first record that fails the check, if any
+    pub broken_at: Option<u64>,
+}
+
+/// Append-only, hash-chained log of entity and link changes
+pub struct AuditLog {
+    path: PathBuf,
+    user: RwLock<String>,
+    /// File length, sequence and hash of the last record, as last read or written
+    tail: Mutex<Option<(u64, u64, String)>>,
+}
+
+impl AuditRecord {
+    fn compute_hash(&self) -> EdtResult<String> {
+        let mut unhashed = self.clone();
+        unhashed.hash = String::new();
+        Ok(blake3::hash(serde_json::to_string(&unhashed)?.as_bytes()).to_hex().to_string())
+    }
+
+    fn involves(&self, entity_id: &Uuid) -> bool {
+        if self.entity_id == *entity_id {
+            return true;
+        }
+        // A link record also concerns the entity it points to
+        let target = Value::String(entity_id.to_string());
+        self.link_id.is_some()
+            && self.changes.iter().any(|change| {
+                change.path == "to_entity_id"
+                    && (change.old_value.as_ref() == Some(&target) || change.new_value.as_ref() == Some(&target))
+            })
+    }
+}
+
+impl AuditLog {
+    /// Open the project's audit log, recording changes under the configured git user name
+    pub fn new(project_root: &Path) -> Self {
+        Self {
+            path: project_root.join(AUDIT_LOG_FILE),
+            user: RwLock::new(Self::default_user(project_root)),
+            tail: Mutex::new(None),
+        }
+    }
+
+    /// Name recorded for changes made from now on
+    pub fn user(&self) -> EdtResult<String> {
+        self.user
+            .read()
+            .map(|user| user.clone())
+            .map_err(|e| EdtError::ValidationError(format!("Failed to read audit user: {}", e)))
+    }
+
+    pub fn set_user(&self, user: &str) -> EdtResult<()> {
+        let user = user.trim();
+        if user.is_empty() {
+            return Err(EdtError::ValidationError("Audit user name cannot be empty".to_string()));
+        }
+        let mut current = self
+            .user
+            .write()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to write audit user: {}", e)))?;
+        *current = user.to_string();
+        Ok(())
+    }
+
+    pub fn record_create<T: StoredEntity>(&self, entity: &T) -> EdtResult<()> {
+        let after = serde_json::to_value(entity)?;
+        self.append(AuditAction::Create, entity.id(), T::ENTITY_TYPE, None, field_values(None, Some(&after)))
+    }
+
+    /// Record an update; without a previous version it is recorded as a create
+    pub fn record_update<T: StoredEntity>(&self, before: Option<&T>, after: &T) -> EdtResult<()> {
+        let Some(before) = before else {
+            return self.record_create(after);
+        };
+        let changes = diff_json(Some(&serde_json::to_value(before)?), Some(&serde_json::to_value(after)?));
+        self.append(AuditAction::Update, after.id(), T::ENTITY_TYPE, None, changes)
+    }
+
+    /// Record a deleted entity from its last content
+    pub fn record_delete(&self, entity_type: EntityType, id: Uuid, before: &Value) -> EdtResult<()> {
+        self.record_change(entity_type, id, Some(before), None)
+    }
+
+    /// Record an entity file rewritten as JSON, e.g. by a restore or merge resolution
+    ///
+    /// `None` means the entity did not exist before, or no longer exists after.
+    pub fn record_change(
+        &self,
+        entity_type: EntityType,
+        id: Uuid,
+        before: Option<&Value>,
+        after: Option<&Value>,
+    ) -> EdtResult<()> {
+        let (action, changes) = match (before, after) {
+            (Some(before), Some(after)) => (AuditAction::Update, diff_json(Some(before), Some(after))),
+            (None, Some(after)) => (AuditAction::Create, field_values(None, Some(after))),
+            (Some(before), None) => (AuditAction::Delete, field_values(Some(before), None)),
+            (None, None) => return Ok(()),
+        };
+        if action == AuditAction::Update && changes.is_empty() {
+            return Ok(());
+        }
+        self.append(action, id, entity_type, None, changes)
+    }
+
+    /// Record an entity file upgraded to a newer schema version
+    pub fn record_migration(&self, entity_type: EntityType, id: Uuid, from_version: &str, to_version: &str) -> EdtResult<()> {
+        let change = FieldChange {
+            path: "metadata.schema_version".to_string(),
+            old_value: Some(Value::String(from_version.to_string())),
+            new_value: Some(Value::String(to_version.to_string())),
+        };
+        self.append(AuditAction::Update, id, entity_type, None, vec![change])
+    }
+
+    /// Record entity and link files restored by undo or redo
+    pub fn record_file_edits(&self, edits: &[FileEdit]) -> EdtResult<()> {
+        for edit in edits {
+            if let Some(entity) = edit.entity() {
+                let parse = |content: &Option<String>| {
+                    content
+                        .as_deref()
+                        .map(|content| RonStorage::entity_ron_to_json(&entity.entity_type, content))
+                        .transpose()
+                };
+                let (before, after) = (parse(&edit.before)?, parse(&edit.after)?);
+                self.record_change(entity.entity_type, entity.entity_id, before.as_ref(), after.as_ref())?;
+            } else if edit.is_link() {
+                // Links are never edited in place, only created and deleted
+                if let Some(before) = &edit.before {
+                    self.record_link(AuditAction::Delete, &ron::from_str(before)?)?;
+                }
+                if let Some(after) = &edit.after {
+                    self.record_link(AuditAction::Create, &ron::from_str(after)?)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Record a created or deleted link
+    pub fn record_link(&self, action: AuditAction, link: &Link) -> EdtResult<()> {
+        let value = serde_json::to_value(link)?;
+        let changes = match action {
+            AuditAction::Delete => field_values(Some(&value), None),
+            _ => field_values(None, Some(&value)),
+        };
+        self.append(action, link.from_entity_id, link.from_entity_type.clone(), Some(link.id), changes)
+    }
+
+    /// Records matching the query, oldest first
+    pub fn query(&self, query: &AuditQuery) -> EdtResult<Vec<AuditRecord>> {
+        Ok(self
+            .read_all()?
+            .into_iter()
+            .filter(|record| query.entity_id.is_none_or(|id| record.involves(&id)))
+            .filter(|record| query.user.as_ref().is_none_or(|user| record.user.eq_ignore_ascii_case(user)))
+            .filter(|record| query.from.is_none_or(|from| record.timestamp >= from))
+            .filter(|record| query.to.is_none_or(|to| record.timestamp <= to))
+            .collect())
+    }
+
+    /// Check every record's hash and its link to the record before it
+    pub fn verify(&self) -> EdtResult<AuditVerification> {
+        let records = self.read_all()?;
+        let mut previous = GENESIS_HASH.to_string();
+        let mut broken_at = None;
+
+        for (index, record) in records.iter().enumerate() {
+            let intact = record.sequence == index as u64 + 1
+                && record.previous_hash == previous
+                && record.hash == record.compute_hash()?;
+            if !intact {
+                broken_at = Some(index as u64 + 1);
+                break;
+            }
+            previous = record.hash.clone();
+        }
+
+        Ok(AuditVerification {
+            record_count: records.len(),
+            broken_at,
+        })
+    }
+
+    fn append(
+        &self,
+        action: AuditAction,
+        entity_id: Uuid,
+        entity_type: EntityType,
+        link_id: Option<Uuid>,
+        changes: Vec<FieldChange>,
+    ) -> EdtResult<()> {
+        let mut tail = self.lock_tail()?;
+        let (sequence, previous_hash) = self.last_record(&mut tail)?;
+
+        let mut record = AuditRecord {
+            sequence: sequence + 1,
+            timestamp: Utc::now(),
+            user: self.user()?,
+            action,
+            entity_id,
+            entity_type,
+            link_id,
+            changes,
+            previous_hash,
+            hash: String::new(),
+        };
+        record.hash = record.compute_hash()?;
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(&record)?)?;
+        file.sync_data()?;
+
+        *tail = Some((file.metadata()?.len(), record.sequence, record.hash));
+        Ok(())
+    }
+
+    /// Sequence and hash of the last record, re-read if the file changed on disk (e.g. a git pull)
+    fn last_record(&self, tail: &mut Option<(u64, u64, String)>) -> EdtResult<(u64, String)> {
+        let len = fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        if let Some((cached_len, sequence, hash)) = tail.as_ref() {
+            if *cached_len == len {
+                return Ok((*sequence, hash.clone()));
+            }
+        }
+
+        let last = self
+            .read_all()?
+            .pop()
+            .map(|record| (record.sequence, record.hash))
+            .unwrap_or_else(|| (0, GENESIS_HASH.to_string()));
+        *tail = Some((len, last.0, last.1.clone()));
+        Ok(last)
+    }
+
+    fn read_all(&self) -> EdtResult<Vec<AuditRecord>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        fs::read_to_string(&self.path)?
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Ok(serde_json::from_str(line)?))
+            .collect()
+    }
+
+    fn lock_tail(&self) -> EdtResult<MutexGuard<'_, Option<(u64, u64, String)>>> {
+        self.tail
+            .lock()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to lock audit log: {}", e)))
+    }
+
+    /// The git user name for the project, falling back to the OS user
+    fn default_user(project_root: &Path) -> String {
+        let git_user = git2::Repository::open(project_root)
+            .and_then(|repo| repo.config())
+            .or_else(|_| git2::Config::open_default())
+            .and_then(|config| config.get_string("user.name"))
+            .ok();
+
+        git_user
+            .or_else(|| std::env::var("USER").ok())
+            .or_else(|| std::env::var("USERNAME").ok())
+            .filter(|user| !user.trim().is_empty())
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+}
+
+/// Field-by-field changes for a created (`old` is `None`) or deleted (`new` is `None`) record
+fn field_values(old: Option<&Value>, new: Option<&Value>) -> Vec<FieldChange> {
+    let empty = Value::Object(Default::default());
+    diff_json(Some(old.unwrap_or(&empty)), Some(new.unwrap_or(&empty)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{EntityManager, LinkManager};
+    use crate::models::{LinkType, ProjectConfig};
+    use crate::storage::RonStorage;
+    use std::sync::{Arc, RwLock};
+    use tempfile::TempDir;
+
+    fn create_test_manager() -> (TempDir, EntityManager, Arc<Mutex<LinkManager>>, Arc<AuditLog>) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(RonStorage::new(temp_dir.path()).unwrap());
+        let link_manager = Arc::new(Mutex::new(LinkManager::with_storage(Arc::clone(&storage)).unwrap()));
+        let config = Arc::new(RwLock::new(ProjectConfig::default()));
+        let manager = EntityManager::new(storage, Arc::clone(&link_manager), config).unwrap();
+        let audit = manager.audit_log();
+        audit.set_user("alice").unwrap();
+        (temp_dir, manager, link_manager, audit)
+    }
+
+    #[test]
+    fn test_entity_changes_recorded_with_field_diffs() {
+        let (_temp, manager, _links, audit) = create_test_manager();
+        let mut risk = manager
+            .create_risk("Overpressure".to_string(), "Line bursts".to_string(), "Safety Risk".to_string(), 3, 4)
+            .unwrap();
+        let id = risk.metadata.id;
+
+        audit.set_user("bob").unwrap();
+        risk.severity = 5;
+        manager.update_risk(risk).unwrap();
+        manager.delete_risk(&id).unwrap();
+
+        let records = audit.query(&AuditQuery { entity_id: Some(id), ..Default::default() }).unwrap();
+        let actions: Vec<AuditAction> = records.iter().map(|r| r.action).collect();
+        assert_eq!(actions, vec![AuditAction::Create, AuditAction::Update, AuditAction::Delete]);
+        assert_eq!(records[0].user, "alice");
+        assert_eq!(records[1].user, "bob");
+
+        let severity = records[1].changes.iter().find(|c| c.path == "severity").unwrap();
+        assert_eq!(severity.old_value, Some(serde_json::json!(4)));
+        assert_eq!(severity.new_value, Some(serde_json::json!(5)));
+
+        let by_user = audit.query(&AuditQuery { user: Some("Bob".to_string()), ..Default::default() }).unwrap();
+        assert_eq!(by_user.len(), 2);
+    }
+
+    #[test]
+    fn test_link_changes_recorded_for_both_ends() {
+        let (_temp, manager, links, audit) = create_test_manager();
+        let control = manager
+            .create_risk_control("Relief valve".to_string(), "Vents".to_string(), "Design Control".to_string())
+            .unwrap();
+        let risk = manager
+            .create_risk("Overpressure".to_string(), "Line bursts".to_string(), "Safety Risk".to_string(), 3, 4)
+            .unwrap();
+
+        let link = links
+            .lock()
+            .unwrap()
+            .create_link(
+                control.metadata.id,
+                EntityType::RiskControl,
+                risk.metadata.id,
+                EntityType::Risk,
+                LinkType::Mitigates,
+                None,
+            )
+            .unwrap();
+        links.lock().unwrap().delete_link(&link.id).unwrap();
+
+        let for_risk = audit
+            .query(&AuditQuery { entity_id: Some(risk.metadata.id), ..Default::default() })
+            .unwrap();
+        assert_eq!(for_risk.len(), 3);
+        assert!(for_risk[1..].iter().all(|r| r.link_id == Some(link.id)));
+    }
+
+    #[test]
+    fn test_date_range_query() {
+        let (_temp, manager, _links, audit) = create_test_manager();
+        let start = Utc::now();
+        manager.create_supplier("Acme".to_string(), "Valves".to_string()).unwrap();
+
+        let since = audit.query(&AuditQuery { from: Some(start), ..Default::default() }).unwrap();
+        assert_eq!(since.len(), 1);
+        let before = audit.query(&AuditQuery { to: Some(start), ..Default::default() }).unwrap();
+        assert!(before.is_empty());
+    }
+
+    #[test]
+    fn test_tampering_breaks_hash_chain() {
+        let (temp, manager, _links, audit) = create_test_manager();
+        for name in ["Acme", "Globex", "Initech"] {
+            manager.create_supplier(name.to_string(), "Parts".to_string()).unwrap();
+        }
+        let verification = audit.verify().unwrap();
+        assert_eq!(verification.record_count, 3);
+        assert_eq!(verification.broken_at, None);
+
+        let path = temp.path().join(AUDIT_LOG_FILE);
+        let content = fs::read_to_string(&path).unwrap().replace("Globex", "Umbrella");
+        fs::write(&path, content).unwrap();
+        assert_eq!(audit.verify().unwrap().broken_at, Some(2));
+    }
+}
@@ -806,15 +806,17 @@ impl CalculationEngine {
 mod tests {
     use super::*;
     use crate::storage::RonStorage;
-    use crate::models::TaskType;
+    use crate::models::{ProjectConfig, TaskType};
+    use std::sync::RwLock;
     use tempfile::TempDir;
     use chrono::{Duration, Utc};
 
     fn create_test_engine() -> (TempDir, CalculationEngine) {
         let temp_dir = TempDir::new().unwrap();
         let storage = Arc::new(RonStorage::new(temp_dir.path()).unwrap());
-        let entity_manager = Arc::new(EntityManager::new(storage));
         let link_manager = Arc::new(Mutex::new(LinkManager::new()));
+        let config = Arc::new(RwLock::new(ProjectConfig::default()));
+        let entity_manager = Arc::new(EntityManager::new(storage, Arc::clone(&link_manager), config).unwrap());
         let engine = CalculationEngine::new(entity_manager, link_manager);
         (temp_dir, engine)
     }
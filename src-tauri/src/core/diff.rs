@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::core::EdtResult;
+
+/// A single field that differs between two versions of an entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldChange {
+    /// Path such as `metadata.status`, `batches[0].quantity_completed` or
+    /// `test_steps[step_number=2].expected_result`
+    pub path: String,
+    /// `None` if the field was added
+    pub old_value: Option<Value>,
+    /// `None` if the field was removed
+    pub new_value: Option<Value>,
+}
+
+/// How the elements of a nested vector are matched between versions
+pub(crate) enum ArrayKey {
+    /// Struct elements matched by the value of a field
+    Field(&'static str),
+    /// Tuple elements matched by the value at an index, labelled with a name
+    TupleIndex(usize, &'static str),
+}
+
+/// Vectors whose elements have a natural identity, by field name
+///
+/// Matching by key means inserting a step reports one added element instead of a
+/// change to every step after it.
+const KEYED_ARRAYS: &[(&str, ArrayKey)] = &[
+    ("test_steps", ArrayKey::Field("step_number")),
+    ("work_instructions", ArrayKey::Field("step_number")),
+    ("quality_checkpoints", ArrayKey::Field("checkpoint_id")),
+    ("batches", ArrayKey::Field("batch_number")),
+    ("quantity_price_pairs", ArrayKey::TupleIndex(0, "quantity")),
+    ("assigned_resources", ArrayKey::Field("resource_id")),
+    ("dependencies", ArrayKey::Field("predecessor_id")),
+    ("feature_contributions", ArrayKey::Field("feature_id")),
+];
+
+/// Compare two versions of any model type field by field
+pub fn diff_entities<T: Serialize>(old: &T, new: &T) -> EdtResult<Vec<FieldChange>> {
+    let old = serde_json::to_value(old)?;
+    let new = serde_json::to_value(new)?;
+    Ok(diff_json(Some(&old), Some(&new)))
+}
+
+/// Compare two JSON representations of an entity; `None` stands for a missing entity
+pub fn diff_json(old: Option<&Value>, new: Option<&Value>) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_values("", None, old, new, &mut changes);
+    changes
+}
+
+fn diff_values(
+    path: &str,
+    field: Option<&str>,
+    old: Option<&Value>,
+    new: Option<&Value>,
+    changes: &mut Vec<FieldChange>,
+) {
+    match (old, new) {
+        (Some(Value::Object(old_map)), Some(Value::Object(new_map))) => {
+            for (key, old_value) in old_map {
+                diff_values(&join(path, key), Some(key), Some(old_value), new_map.get(key), changes);
+            }
+            for (key, new_value) in new_map {
+                if !old_map.contains_key(key) {
+                    diff_values(&join(path, key), Some(key), None, Some(new_value), changes);
+                }
+            }
+        }
+        (Some(Value::Array(old_items)), Some(Value::Array(new_items))) => {
+            match field.and_then(array_key) {
+                Some(key) if all_keyed(old_items, key) && all_keyed(new_items, key) => {
+                    diff_keyed(path, key, old_items, new_items, changes)
+                }
+                _ => {
+                    for i in 0..old_items.len().max(new_items.len()) {
+                        let item_path = format!("{}[{}]", path, i);
+                        diff_values(&item_path, None, old_items.get(i), new_items.get(i), changes);
+                    }
+                }
+            }
+        }
+        (old, new) if old != new => changes.push(FieldChange {
+            path: path.to_string(),
+            old_value: old.cloned(),
+            new_value: new.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+/// Match elements by key, reporting changes in old order followed by additions
+fn diff_keyed(
+    path: &str,
+    key: &ArrayKey,
+    old_items: &[Value],
+    new_items: &[Value],
+    changes: &mut Vec<FieldChange>,
+) {
+    let find = |items: &'_ [Value], wanted: &Value| -> Option<usize> {
+        items.iter().position(|item| key_value(item, key) == Some(wanted))
+    };
+
+    for old_item in old_items {
+        let Some(id) = key_value(old_item, key) else { continue };
+        let new_item = find(new_items, id).map(|i| &new_items[i]);
+        diff_values(&keyed_path(path, key, id), None, Some(old_item), new_item, changes);
+    }
+    for new_item in new_items {
+        let Some(id) = key_value(new_item, key) else { continue };
+        if find(old_items, id).is_none() {
+            diff_values(&keyed_path(path, key, id), None, None, Some(new_item), changes);
+        }
+    }
+}
+
+/// Key used to match elements of the vector stored under `field`, if it has one
+pub(crate) fn array_key(field: &str) -> Option<&'static ArrayKey> {
+    KEYED_ARRAYS
+        .iter()
+        .find(|(name, _)| *name == field)
+        .map(|(_, key)| key)
+}
+
+pub(crate) fn key_value<'a>(item: &'a Value, key: &ArrayKey) -> Option<&'a Value> {
+    match key {
+        ArrayKey::Field(field) => item.get(*field),
+        ArrayKey::TupleIndex(index, _) => item.get(*index),
+    }
+}
+
+/// Keys must be present and unique, otherwise positional matching is used
+pub(crate) fn all_keyed(items: &[Value], key: &ArrayKey) -> bool {
+    let keys: Vec<&Value> = items.iter().filter_map(|item| key_value(item, key)).collect();
+    keys.len() == items.len()
+        && keys.iter().enumerate().all(|(i, k)| !keys[..i].contains(k))
+}
+
+pub(crate) fn keyed_path(path: &str, key: &ArrayKey, id: &Value) -> String {
+    let name = match key {
+        ArrayKey::Field(field) => field,
+        ArrayKey::TupleIndex(_, label) => label,
+    };
+    format!("{}[{}={}]", path, name, key_label(id))
+}
+
+/// Key as it appears in a path
+pub(crate) fn key_label(id: &Value) -> String {
+    match id {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+pub(crate) fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use chrono::NaiveDate;
+    use crate::models::{CostDistribution, EntityMetadata, EntityType, Quote};
+
+    fn paths(changes: &[FieldChange]) -> Vec<&str> {
+        changes.iter().map(|c| c.path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_identical_entities_have_no_changes() {
+        let value = json!({ "name": "A", "nested": { "x": 1 }, "list": [1, 2] });
+        assert!(diff_json(Some(&value), Some(&value)).is_empty());
+    }
+
+    #[test]
+    fn test_nested_fields_added_removed_and_modified() {
+        let old = json!({ "name": "A", "metadata": { "status": "Draft" }, "notes": "gone" });
+        let new = json!({ "name": "A", "metadata": { "status": "Approved" }, "rationale": "new" });
+
+        let changes = diff_json(Some(&old), Some(&new));
+        assert_eq!(paths(&changes), vec!["metadata.status", "notes", "rationale"]);
+        assert_eq!(changes[0].old_value, Some(json!("Draft")));
+        assert_eq!(changes[0].new_value, Some(json!("Approved")));
+        assert_eq!(changes[1].new_value, None);
+        assert_eq!(changes[2].old_value, None);
+    }
+
+    #[test]
+    fn test_test_steps_matched_by_step_number() {
+        let old = json!({ "test_steps": [
+            { "step_number": 1, "description": "Power on" },
+            { "step_number": 2, "description": "Measure" },
+        ]});
+        let new = json!({ "test_steps": [
+            { "step_number": 1, "description": "Power on" },
+            { "step_number": 3, "description": "Inserted" },
+            { "step_number": 2, "description": "Measure voltage" },
+        ]});
+
+        let changes = diff_json(Some(&old), Some(&new));
+        assert_eq!(
+            paths(&changes),
+            vec!["test_steps[step_number=2].description", "test_steps[step_number=3]"]
+        );
+    }
+
+    #[test]
+    fn test_unkeyed_arrays_compared_by_position() {
+        let old = json!({ "holidays": ["2025-01-01", "2025-12-25"] });
+        let new = json!({ "holidays": ["2025-01-01"] });
+
+        let changes = diff_json(Some(&old), Some(&new));
+        assert_eq!(paths(&changes), vec!["holidays[1]"]);
+        assert_eq!(changes[0].new_value, None);
+    }
+
+    #[test]
+    fn test_quote_price_breaks_matched_by_quantity() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let old = Quote {
+            metadata: EntityMetadata::new(EntityType::Quote),
+            quote_number: "Q-001".to_string(),
+            quote_date: date,
+            expiration_date: None,
+            quantity_price_pairs: vec![(1, 10.0), (100, 8.0)],
+            distribution_type: CostDistribution::Linear,
+            notes: None,
+        };
+        let mut new = old.clone();
+        new.quantity_price_pairs = vec![(1, 10.0), (50, 9.0), (100, 7.5)];
+
+        let changes = diff_entities(&old, &new).unwrap();
+        assert_eq!(
+            paths(&changes),
+            vec!["quantity_price_pairs[quantity=100][1]", "quantity_price_pairs[quantity=50]"]
+        );
+        assert_eq!(changes[0].old_value, Some(json!(8.0)));
+        assert_eq!(changes[0].new_value, Some(json!(7.5)));
+    }
+}
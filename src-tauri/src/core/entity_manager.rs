@@ -1,23 +1,59 @@
-use std::sync::Arc;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, RwLock};
 use uuid::Uuid;
-use crate::core::{EdtResult, EdtError};
+use serde::Serialize;
+use crate::core::{AuditAction, AuditLog, EdtResult, EdtError, IdRegistry, LinkManager, SignatureManager, WorkflowEngine};
+use crate::core::diff::{diff_entities, FieldChange};
 use crate::storage::RonStorage;
+pub use crate::models::EntityRef;
 use crate::models::{
-    EntityType, EntityMetadata, Task, Requirement, Risk, Hazard, RiskControl,
+    EntityType, EntityMetadata, DeletePolicy, Link, ProjectConfig, Task, Requirement, Risk, Hazard, RiskControl,
     Milestone, Resource, TaskType, ResourceType, Calendar, Baseline,
     Assembly, Component, Feature, FeatureType, DistributionType,
     Mate, MateType, Stackup, AnalysisType, Supplier, Quote, CostDistribution,
     Verification, Validation, TestStatus, TestPriority, TestStep,
-    Manufacturing, ProcessStatus, WorkInstructionStep,
+    Manufacturing, ProcessStatus, WorkInstructionStep, Comment, LinkType,
 };
 use chrono::{Utc, NaiveDate};
 
 // Import specialized managers
+use crate::core::managers::config_checks::{read_config, validate_config_type};
 use crate::core::managers::{
     TaskManager, RequirementManager, RiskManager, DesignManager,
-    TestingManager, ManufacturingManager,
+    TestingManager, ManufacturingManager, CommentManager,
 };
 
+/// What deleting an entity does (or would do) under its type's delete policy
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteImpact {
+    pub entity_id: Uuid,
+    pub entity_type: EntityType,
+    pub policy: DeletePolicy,
+    /// Owned children, and theirs in turn, deleted along with the entity
+    pub children: Vec<EntityRef>,
+    /// Links referencing the entity or its children; removed unless the policy is `Orphan`
+    pub links: Vec<Link>,
+    /// True when the `Block` policy prevents the delete
+    pub blocked: bool,
+}
+
+/// An entity whose configurable type is not listed in the project config
+#[derive(Debug, Clone, Serialize)]
+pub struct TypeValidationIssue {
+    pub entity_id: Uuid,
+    pub entity_type: EntityType,
+    pub name: String,
+    pub field: String,
+    pub message: String,
+}
+
+/// A comment with its replies, each level oldest first
+#[derive(Debug, Clone, Serialize)]
+pub struct CommentThread {
+    pub comment: Comment,
+    pub replies: Vec<CommentThread>,
+}
+
 /// Facade for entity lifecycle (CRUD operations)
 /// Delegates to specialized managers for better modularity
 pub struct EntityManager {
@@ -27,18 +63,277 @@ pub struct EntityManager {
     design_manager: DesignManager,
     testing_manager: TestingManager,
     manufacturing_manager: ManufacturingManager,
+    comment_manager: CommentManager,
+    ids: Arc<IdRegistry>,
+    workflow: Arc<WorkflowEngine>,
+    signatures: Arc<SignatureManager>,
+    audit: Arc<AuditLog>,
+    storage: Arc<RonStorage>,
+    link_manager: Arc<Mutex<LinkManager>>,
+    config: Arc<RwLock<ProjectConfig>>,
 }
 
 impl EntityManager {
-    pub fn new(storage: Arc<RonStorage>) -> Self {
-        Self {
-            task_manager: TaskManager::new(Arc::clone(&storage)),
-            requirement_manager: RequirementManager::new(Arc::clone(&storage)),
-            risk_manager: RiskManager::new(Arc::clone(&storage)),
-            design_manager: DesignManager::new(Arc::clone(&storage)),
-            testing_manager: TestingManager::new(Arc::clone(&storage)),
-            manufacturing_manager: ManufacturingManager::new(storage),
+    pub fn new(
+        storage: Arc<RonStorage>,
+        link_manager: Arc<Mutex<LinkManager>>,
+        config: Arc<RwLock<ProjectConfig>>,
+    ) -> EdtResult<Self> {
+        let ids = Arc::new(IdRegistry::new(Arc::clone(&storage), Arc::clone(&config)));
+        let signatures = Arc::new(SignatureManager::new(Arc::clone(&storage), Arc::clone(&config)));
+        let audit = Arc::new(AuditLog::new(storage.project_root()));
+        link_manager
+            .lock()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to lock link manager: {}", e)))?
+            .set_audit_log(Arc::clone(&audit));
+        let workflow = Arc::new(WorkflowEngine::new(
+            Arc::clone(&storage),
+            Arc::clone(&link_manager),
+            Arc::clone(&signatures),
+            Arc::clone(&audit),
+            Arc::clone(&config),
+        ));
+        Ok(Self {
+            task_manager: TaskManager::new(Arc::clone(&storage), Arc::clone(&ids)),
+            requirement_manager: RequirementManager::new(Arc::clone(&storage), Arc::clone(&ids), Arc::clone(&config)),
+            risk_manager: RiskManager::new(Arc::clone(&storage), Arc::clone(&ids), Arc::clone(&config)),
+            design_manager: DesignManager::new(Arc::clone(&storage), Arc::clone(&ids)),
+            testing_manager: TestingManager::new(Arc::clone(&storage), Arc::clone(&ids)),
+            manufacturing_manager: ManufacturingManager::new(Arc::clone(&storage), Arc::clone(&ids)),
+            comment_manager: CommentManager::new(Arc::clone(&storage), Arc::clone(&ids)),
+            ids,
+            workflow,
+            signatures,
+            audit,
+            storage,
+            link_manager,
+            config,
+        })
+    }
+
+    /// Registry of human-readable IDs, shared with the specialized managers
+    pub fn id_registry(&self) -> Arc<IdRegistry> {
+        Arc::clone(&self.ids)
+    }
+
+    /// Status workflow, which also guards updates and deletes of locked entities
+    pub fn workflow(&self) -> Arc<WorkflowEngine> {
+        Arc::clone(&self.workflow)
+    }
+
+    /// Electronic signatures, which the workflow checks when guarding approvals
+    pub fn signatures(&self) -> Arc<SignatureManager> {
+        Arc::clone(&self.signatures)
+    }
+
+    /// Audit trail of entity and link changes, shared with the link manager
+    pub fn audit_log(&self) -> Arc<AuditLog> {
+        Arc::clone(&self.audit)
+    }
+
+    // ============================================================================
+    // Delete (applies the configured DeletePolicy)
+    // ============================================================================
+
+    /// Work out what deleting an entity would do, without changing anything
+    pub fn preview_delete(&self, entity_type: EntityType, id: &Uuid) -> EdtResult<DeleteImpact> {
+        let link_manager = self.lock_link_manager()?;
+        self.plan_delete(&link_manager, entity_type, id)
+    }
+
+    /// Delete an entity, its links and owned children according to its delete policy
+    ///
+    /// Returns `HasDependencies` when the policy is `Block` and links reference the entity, and
+    /// `EntityLocked` when the entity or any owned child is Approved or Released.
+    pub fn delete_entity(&self, entity_type: EntityType, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.workflow.ensure_editable(&entity_type, id)?;
+        let mut link_manager = self.lock_link_manager()?;
+        let impact = self.plan_delete(&link_manager, entity_type.clone(), id)?;
+
+        if impact.blocked {
+            return Err(EdtError::HasDependencies(impact.links.len()));
+        }
+
+        // Approved and Released children are as read-only as the entity itself
+        for child in &impact.children {
+            self.workflow.ensure_editable(&child.entity_type, &child.entity_id)?;
+        }
+
+        // Last content of everything deleted, for the audit trail
+        let mut deleted = Vec::new();
+        for child in &impact.children {
+            // An owning link can outlive its child when the child's file was removed outside the app
+            if self.storage.exists(&child.entity_type, &child.entity_id) {
+                let before = self.storage.read_entity_json(&child.entity_type, &child.entity_id)?;
+                deleted.push((child.clone(), before));
+            }
+        }
+        let root = EntityRef {
+            entity_id: *id,
+            entity_type: entity_type.clone(),
+        };
+        deleted.push((root, self.storage.read_entity_json(&entity_type, id)?));
+
+        // Children, entity and links go together as one undo step; a failure part-way
+        // restores all files
+        let result = self.storage.with_undo_group(&format!("Delete {:?}", entity_type), || {
+            for (entity, _) in &deleted {
+                self.delete_entity_file(&entity.entity_type, &entity.entity_id)?;
+            }
+
+            if impact.policy != DeletePolicy::Orphan {
+                for link in &impact.links {
+                    link_manager.remove_link(&link.id)?;
+                }
+            }
+            Ok(())
+        });
+
+        if let Err(e) = result {
+            // Bring the in-memory graph back in line with the restored link files
+            link_manager.reload()?;
+            return Err(e);
         }
+
+        // Audited only once committed, so a rolled-back delete leaves no record
+        for (entity, before) in deleted {
+            self.audit.record_delete(entity.entity_type, entity.entity_id, &before)?;
+        }
+        if impact.policy != DeletePolicy::Orphan {
+            for link in &impact.links {
+                self.audit.record_link(AuditAction::Delete, link)?;
+            }
+        }
+
+        Ok(impact)
+    }
+
+    fn plan_delete(
+        &self,
+        link_manager: &LinkManager,
+        entity_type: EntityType,
+        id: &Uuid,
+    ) -> EdtResult<DeleteImpact> {
+        self.ensure_exists(&entity_type, id)?;
+
+        let policy = self
+            .config
+            .read()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to read project config: {}", e)))?
+            .delete_policy(&entity_type);
+
+        // Owned children, their own children and so on, each once even if ownership loops
+        let mut children: Vec<EntityRef> = Vec::new();
+        if policy == DeletePolicy::CascadeChildren {
+            let mut visited = HashSet::from([*id]);
+            let mut pending = vec![*id];
+            while let Some(owner) = pending.pop() {
+                for link in link_manager.get_links_from(&owner) {
+                    if link.link_type.is_owning() && visited.insert(link.to_entity_id) {
+                        pending.push(link.to_entity_id);
+                        children.push(EntityRef {
+                            entity_id: link.to_entity_id,
+                            entity_type: link.to_entity_type.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        // Collect each link once, even when it joins the entity to one of its children
+        let mut links: Vec<Link> = Vec::new();
+        let affected = std::iter::once(*id).chain(children.iter().map(|child| child.entity_id));
+        for entity_id in affected {
+            for link in link_manager.get_all_links(&entity_id) {
+                if !links.iter().any(|existing| existing.id == link.id) {
+                    links.push(link.clone());
+                }
+            }
+        }
+
+        Ok(DeleteImpact {
+            entity_id: *id,
+            entity_type,
+            blocked: policy == DeletePolicy::Block && !links.is_empty(),
+            policy,
+            children,
+            links,
+        })
+    }
+
+    fn ensure_exists(&self, entity_type: &EntityType, id: &Uuid) -> EdtResult<()> {
+        if self.storage.exists(entity_type, id) {
+            Ok(())
+        } else {
+            Err(EdtError::EntityNotFound(id.to_string()))
+        }
+    }
+
+    fn delete_entity_file(&self, entity_type: &EntityType, id: &Uuid) -> EdtResult<()> {
+        if !RonStorage::has_model(entity_type) {
+            return Err(EdtError::ValidationError(format!(
+                "Deleting {:?} entities is not supported",
+                entity_type
+            )));
+        }
+        if !self.storage.exists(entity_type, id) {
+            return Err(EdtError::EntityNotFound(id.to_string()));
+        }
+        self.storage.delete(entity_type, id)
+    }
+
+    fn lock_link_manager(&self) -> EdtResult<std::sync::MutexGuard<'_, LinkManager>> {
+        self.link_manager
+            .lock()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to lock link manager: {}", e)))
+    }
+
+    // ============================================================================
+    // Config Re-validation
+    // ============================================================================
+
+    /// Report requirements, risks and risk controls whose type is no longer in the config
+    pub fn revalidate_entity_types(&self) -> EdtResult<Vec<TypeValidationIssue>> {
+        let config = read_config(&self.config)?.clone();
+        let mut issues = Vec::new();
+
+        let mut check = |metadata: &EntityMetadata, name: &str, field: &str, value: &str, allowed: &[String]| {
+            if let Err(e) = validate_config_type(field, value, allowed) {
+                issues.push(TypeValidationIssue {
+                    entity_id: metadata.id,
+                    entity_type: metadata.entity_type.clone(),
+                    name: name.to_string(),
+                    field: field.to_string(),
+                    message: e.to_string(),
+                });
+            }
+        };
+
+        for requirement in self.storage.read_all::<Requirement>()? {
+            check(
+                &requirement.metadata,
+                &requirement.name,
+                "requirement_type",
+                &requirement.requirement_type,
+                &config.requirement_types,
+            );
+        }
+
+        for risk in self.storage.read_all::<Risk>()? {
+            check(&risk.metadata, &risk.name, "risk_type", &risk.risk_type, &config.risk_types);
+        }
+
+        for control in self.storage.read_all::<RiskControl>()? {
+            check(
+                &control.metadata,
+                &control.name,
+                "control_type",
+                &control.control_type,
+                &config.risk_control_types,
+            );
+        }
+
+        Ok(issues)
     }
 
     // ============================================================================
@@ -53,19 +348,24 @@ impl EntityManager {
         deadline: chrono::DateTime<Utc>,
         task_type: TaskType,
     ) -> EdtResult<Task> {
-        self.task_manager.create_task(name, description, scheduled_start, deadline, task_type)
+        let created = self.task_manager.create_task(name, description, scheduled_start, deadline, task_type)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_task(&self, id: &Uuid) -> EdtResult<Task> {
         self.task_manager.get_task(id)
     }
 
-    pub fn update_task(&self, task: Task) -> EdtResult<Task> {
-        self.task_manager.update_task(task)
+    pub fn update_task(&self, mut task: Task) -> EdtResult<Task> {
+        let before = self.workflow.guard_update(&mut task)?;
+        let updated = self.task_manager.update_task(task)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_task(&self, id: &Uuid) -> EdtResult<()> {
-        self.task_manager.delete_task(id)
+    pub fn delete_task(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Task, id)
     }
 
     pub fn list_task_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -82,19 +382,24 @@ impl EntityManager {
         description: String,
         date: chrono::DateTime<Utc>,
     ) -> EdtResult<Milestone> {
-        self.task_manager.create_milestone(name, description, date)
+        let created = self.task_manager.create_milestone(name, description, date)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_milestone(&self, id: &Uuid) -> EdtResult<Milestone> {
         self.task_manager.get_milestone(id)
     }
 
-    pub fn update_milestone(&self, milestone: Milestone) -> EdtResult<Milestone> {
-        self.task_manager.update_milestone(milestone)
+    pub fn update_milestone(&self, mut milestone: Milestone) -> EdtResult<Milestone> {
+        let before = self.workflow.guard_update(&mut milestone)?;
+        let updated = self.task_manager.update_milestone(milestone)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_milestone(&self, id: &Uuid) -> EdtResult<()> {
-        self.task_manager.delete_milestone(id)
+    pub fn delete_milestone(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Milestone, id)
     }
 
     // ============================================================================
@@ -107,19 +412,24 @@ impl EntityManager {
         description: String,
         resource_type: ResourceType,
     ) -> EdtResult<Resource> {
-        self.task_manager.create_resource(name, description, resource_type)
+        let created = self.task_manager.create_resource(name, description, resource_type)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_resource(&self, id: &Uuid) -> EdtResult<Resource> {
         self.task_manager.get_resource(id)
     }
 
-    pub fn update_resource(&self, resource: Resource) -> EdtResult<Resource> {
-        self.task_manager.update_resource(resource)
+    pub fn update_resource(&self, mut resource: Resource) -> EdtResult<Resource> {
+        let before = self.workflow.guard_update(&mut resource)?;
+        let updated = self.task_manager.update_resource(resource)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_resource(&self, id: &Uuid) -> EdtResult<()> {
-        self.task_manager.delete_resource(id)
+    pub fn delete_resource(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Resource, id)
     }
 
     // ============================================================================
@@ -132,19 +442,24 @@ impl EntityManager {
         work_hours_per_day: f64,
         work_days: Vec<chrono::Weekday>,
     ) -> EdtResult<Calendar> {
-        self.task_manager.create_calendar(name, work_hours_per_day, work_days)
+        let created = self.task_manager.create_calendar(name, work_hours_per_day, work_days)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_calendar(&self, id: &Uuid) -> EdtResult<Calendar> {
         self.task_manager.get_calendar(id)
     }
 
-    pub fn update_calendar(&self, calendar: Calendar) -> EdtResult<Calendar> {
-        self.task_manager.update_calendar(calendar)
+    pub fn update_calendar(&self, mut calendar: Calendar) -> EdtResult<Calendar> {
+        let before = self.workflow.guard_update(&mut calendar)?;
+        let updated = self.task_manager.update_calendar(calendar)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_calendar(&self, id: &Uuid) -> EdtResult<()> {
-        self.task_manager.delete_calendar(id)
+    pub fn delete_calendar(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Calendar, id)
     }
 
     // ============================================================================
@@ -157,19 +472,42 @@ impl EntityManager {
         description: String,
         task_ids: Vec<Uuid>,
     ) -> EdtResult<Baseline> {
-        self.task_manager.create_baseline(name, description, task_ids)
+        // Baselining rewrites each task's snapshot, which a locked task does not allow
+        for task_id in &task_ids {
+            self.workflow.ensure_editable(&EntityType::Task, task_id)?;
+        }
+        let before: Vec<Task> = task_ids.iter().map(|id| self.storage.read(id)).collect::<EdtResult<_>>()?;
+        let created = self.task_manager.create_baseline(name, description, task_ids)?;
+        for task in &before {
+            let after: Task = self.storage.read(&task.metadata.id)?;
+            self.audit.record_update(Some(task), &after)?;
+        }
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_baseline(&self, id: &Uuid) -> EdtResult<Baseline> {
         self.task_manager.get_baseline(id)
     }
 
-    pub fn update_baseline(&self, baseline: Baseline) -> EdtResult<Baseline> {
-        self.task_manager.update_baseline(baseline)
+    pub fn update_baseline(&self, mut baseline: Baseline) -> EdtResult<Baseline> {
+        let before = self.workflow.guard_update(&mut baseline)?;
+        let updated = self.task_manager.update_baseline(baseline)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_baseline(&self, id: &Uuid) -> EdtResult<()> {
-        self.task_manager.delete_baseline(id)
+    pub fn delete_baseline(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Baseline, id)
+    }
+
+    /// Changes to a task's plan since its last baseline snapshot
+    pub fn diff_task_baseline(&self, task_id: &Uuid) -> EdtResult<Vec<FieldChange>> {
+        let task = self.get_task(task_id)?;
+        let snapshot = task.baseline_data.as_ref().ok_or_else(|| {
+            EdtError::ValidationError(format!("Task '{}' has not been baselined", task.name))
+        })?;
+        diff_entities(snapshot, &task.baseline_snapshot(snapshot.baseline_id))
     }
 
     // ============================================================================
@@ -182,19 +520,24 @@ impl EntityManager {
         description: String,
         requirement_type: String,
     ) -> EdtResult<Requirement> {
-        self.requirement_manager.create_requirement(name, description, requirement_type)
+        let created = self.requirement_manager.create_requirement(name, description, requirement_type)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_requirement(&self, id: &Uuid) -> EdtResult<Requirement> {
         self.requirement_manager.get_requirement(id)
     }
 
-    pub fn update_requirement(&self, requirement: Requirement) -> EdtResult<Requirement> {
-        self.requirement_manager.update_requirement(requirement)
+    pub fn update_requirement(&self, mut requirement: Requirement) -> EdtResult<Requirement> {
+        let before = self.workflow.guard_update(&mut requirement)?;
+        let updated = self.requirement_manager.update_requirement(requirement)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_requirement(&self, id: &Uuid) -> EdtResult<()> {
-        self.requirement_manager.delete_requirement(id)
+    pub fn delete_requirement(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Requirement, id)
     }
 
     pub fn list_requirement_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -213,19 +556,24 @@ impl EntityManager {
         probability: u32,
         severity: u32,
     ) -> EdtResult<Risk> {
-        self.risk_manager.create_risk(name, description, risk_type, probability, severity)
+        let created = self.risk_manager.create_risk(name, description, risk_type, probability, severity)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_risk(&self, id: &Uuid) -> EdtResult<Risk> {
         self.risk_manager.get_risk(id)
     }
 
-    pub fn update_risk(&self, risk: Risk) -> EdtResult<Risk> {
-        self.risk_manager.update_risk(risk)
+    pub fn update_risk(&self, mut risk: Risk) -> EdtResult<Risk> {
+        let before = self.workflow.guard_update(&mut risk)?;
+        let updated = self.risk_manager.update_risk(risk)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_risk(&self, id: &Uuid) -> EdtResult<()> {
-        self.risk_manager.delete_risk(id)
+    pub fn delete_risk(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Risk, id)
     }
 
     pub fn list_risk_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -243,19 +591,24 @@ impl EntityManager {
         causes: Vec<String>,
         harms: Vec<String>,
     ) -> EdtResult<Hazard> {
-        self.risk_manager.create_hazard(name, description, causes, harms)
+        let created = self.risk_manager.create_hazard(name, description, causes, harms)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_hazard(&self, id: &Uuid) -> EdtResult<Hazard> {
         self.risk_manager.get_hazard(id)
     }
 
-    pub fn update_hazard(&self, hazard: Hazard) -> EdtResult<Hazard> {
-        self.risk_manager.update_hazard(hazard)
+    pub fn update_hazard(&self, mut hazard: Hazard) -> EdtResult<Hazard> {
+        let before = self.workflow.guard_update(&mut hazard)?;
+        let updated = self.risk_manager.update_hazard(hazard)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_hazard(&self, id: &Uuid) -> EdtResult<()> {
-        self.risk_manager.delete_hazard(id)
+    pub fn delete_hazard(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Hazard, id)
     }
 
     pub fn list_hazard_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -272,19 +625,24 @@ impl EntityManager {
         description: String,
         control_type: String,
     ) -> EdtResult<RiskControl> {
-        self.risk_manager.create_risk_control(name, description, control_type)
+        let created = self.risk_manager.create_risk_control(name, description, control_type)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_risk_control(&self, id: &Uuid) -> EdtResult<RiskControl> {
         self.risk_manager.get_risk_control(id)
     }
 
-    pub fn update_risk_control(&self, control: RiskControl) -> EdtResult<RiskControl> {
-        self.risk_manager.update_risk_control(control)
+    pub fn update_risk_control(&self, mut control: RiskControl) -> EdtResult<RiskControl> {
+        let before = self.workflow.guard_update(&mut control)?;
+        let updated = self.risk_manager.update_risk_control(control)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_risk_control(&self, id: &Uuid) -> EdtResult<()> {
-        self.risk_manager.delete_risk_control(id)
+    pub fn delete_risk_control(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::RiskControl, id)
     }
 
     pub fn list_risk_control_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -302,19 +660,24 @@ impl EntityManager {
         description: String,
         revision: String,
     ) -> EdtResult<Assembly> {
-        self.design_manager.create_assembly(name, description, revision)
+        let created = self.design_manager.create_assembly(name, description, revision)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_assembly(&self, id: &Uuid) -> EdtResult<Assembly> {
         self.design_manager.get_assembly(id)
     }
 
-    pub fn update_assembly(&self, assembly: Assembly) -> EdtResult<Assembly> {
-        self.design_manager.update_assembly(assembly)
+    pub fn update_assembly(&self, mut assembly: Assembly) -> EdtResult<Assembly> {
+        let before = self.workflow.guard_update(&mut assembly)?;
+        let updated = self.design_manager.update_assembly(assembly)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_assembly(&self, id: &Uuid) -> EdtResult<()> {
-        self.design_manager.delete_assembly(id)
+    pub fn delete_assembly(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Assembly, id)
     }
 
     pub fn list_assembly_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -328,19 +691,24 @@ impl EntityManager {
         description: String,
         revision: String,
     ) -> EdtResult<Component> {
-        self.design_manager.create_component(name, description, revision)
+        let created = self.design_manager.create_component(name, description, revision)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_component(&self, id: &Uuid) -> EdtResult<Component> {
         self.design_manager.get_component(id)
     }
 
-    pub fn update_component(&self, component: Component) -> EdtResult<Component> {
-        self.design_manager.update_component(component)
+    pub fn update_component(&self, mut component: Component) -> EdtResult<Component> {
+        let before = self.workflow.guard_update(&mut component)?;
+        let updated = self.design_manager.update_component(component)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_component(&self, id: &Uuid) -> EdtResult<()> {
-        self.design_manager.delete_component(id)
+    pub fn delete_component(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Component, id)
     }
 
     pub fn list_component_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -358,7 +726,7 @@ impl EntityManager {
         lower_tolerance: f64,
         distribution_type: DistributionType,
     ) -> EdtResult<Feature> {
-        self.design_manager.create_feature(
+        let created = self.design_manager.create_feature(
             name,
             description,
             feature_type,
@@ -366,19 +734,24 @@ impl EntityManager {
             upper_tolerance,
             lower_tolerance,
             distribution_type,
-        )
+        )?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_feature(&self, id: &Uuid) -> EdtResult<Feature> {
         self.design_manager.get_feature(id)
     }
 
-    pub fn update_feature(&self, feature: Feature) -> EdtResult<Feature> {
-        self.design_manager.update_feature(feature)
+    pub fn update_feature(&self, mut feature: Feature) -> EdtResult<Feature> {
+        let before = self.workflow.guard_update(&mut feature)?;
+        let updated = self.design_manager.update_feature(feature)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_feature(&self, id: &Uuid) -> EdtResult<()> {
-        self.design_manager.delete_feature(id)
+    pub fn delete_feature(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Feature, id)
     }
 
     pub fn list_feature_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -392,19 +765,24 @@ impl EntityManager {
         description: String,
         mate_type: MateType,
     ) -> EdtResult<Mate> {
-        self.design_manager.create_mate(name, description, mate_type)
+        let created = self.design_manager.create_mate(name, description, mate_type)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_mate(&self, id: &Uuid) -> EdtResult<Mate> {
         self.design_manager.get_mate(id)
     }
 
-    pub fn update_mate(&self, mate: Mate) -> EdtResult<Mate> {
-        self.design_manager.update_mate(mate)
+    pub fn update_mate(&self, mut mate: Mate) -> EdtResult<Mate> {
+        let before = self.workflow.guard_update(&mut mate)?;
+        let updated = self.design_manager.update_mate(mate)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_mate(&self, id: &Uuid) -> EdtResult<()> {
-        self.design_manager.delete_mate(id)
+    pub fn delete_mate(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Mate, id)
     }
 
     pub fn list_mate_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -418,19 +796,24 @@ impl EntityManager {
         description: String,
         analysis_types: Vec<AnalysisType>,
     ) -> EdtResult<Stackup> {
-        self.design_manager.create_stackup(name, description, analysis_types)
+        let created = self.design_manager.create_stackup(name, description, analysis_types)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_stackup(&self, id: &Uuid) -> EdtResult<Stackup> {
         self.design_manager.get_stackup(id)
     }
 
-    pub fn update_stackup(&self, stackup: Stackup) -> EdtResult<Stackup> {
-        self.design_manager.update_stackup(stackup)
+    pub fn update_stackup(&self, mut stackup: Stackup) -> EdtResult<Stackup> {
+        let before = self.workflow.guard_update(&mut stackup)?;
+        let updated = self.design_manager.update_stackup(stackup)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_stackup(&self, id: &Uuid) -> EdtResult<()> {
-        self.design_manager.delete_stackup(id)
+    pub fn delete_stackup(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Stackup, id)
     }
 
     pub fn list_stackup_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -443,19 +826,24 @@ impl EntityManager {
         name: String,
         description: String,
     ) -> EdtResult<Supplier> {
-        self.design_manager.create_supplier(name, description)
+        let created = self.design_manager.create_supplier(name, description)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_supplier(&self, id: &Uuid) -> EdtResult<Supplier> {
         self.design_manager.get_supplier(id)
     }
 
-    pub fn update_supplier(&self, supplier: Supplier) -> EdtResult<Supplier> {
-        self.design_manager.update_supplier(supplier)
+    pub fn update_supplier(&self, mut supplier: Supplier) -> EdtResult<Supplier> {
+        let before = self.workflow.guard_update(&mut supplier)?;
+        let updated = self.design_manager.update_supplier(supplier)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_supplier(&self, id: &Uuid) -> EdtResult<()> {
-        self.design_manager.delete_supplier(id)
+    pub fn delete_supplier(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Supplier, id)
     }
 
     pub fn list_supplier_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -470,19 +858,24 @@ impl EntityManager {
         quantity_price_pairs: Vec<(u32, f64)>,
         distribution_type: CostDistribution,
     ) -> EdtResult<Quote> {
-        self.design_manager.create_quote(quote_number, quote_date, quantity_price_pairs, distribution_type)
+        let created = self.design_manager.create_quote(quote_number, quote_date, quantity_price_pairs, distribution_type)?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_quote(&self, id: &Uuid) -> EdtResult<Quote> {
         self.design_manager.get_quote(id)
     }
 
-    pub fn update_quote(&self, quote: Quote) -> EdtResult<Quote> {
-        self.design_manager.update_quote(quote)
+    pub fn update_quote(&self, mut quote: Quote) -> EdtResult<Quote> {
+        let before = self.workflow.guard_update(&mut quote)?;
+        let updated = self.design_manager.update_quote(quote)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_quote(&self, id: &Uuid) -> EdtResult<()> {
-        self.design_manager.delete_quote(id)
+    pub fn delete_quote(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Quote, id)
     }
 
     pub fn list_quote_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -502,26 +895,31 @@ impl EntityManager {
         acceptance_criteria: Vec<String>,
         priority: TestPriority,
     ) -> EdtResult<Verification> {
-        self.testing_manager.create_verification(
+        let created = self.testing_manager.create_verification(
             name,
             description,
             test_type,
             test_steps,
             acceptance_criteria,
             priority,
-        )
+        )?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_verification(&self, id: &Uuid) -> EdtResult<Verification> {
         self.testing_manager.get_verification(id)
     }
 
-    pub fn update_verification(&self, verification: Verification) -> EdtResult<Verification> {
-        self.testing_manager.update_verification(verification)
+    pub fn update_verification(&self, mut verification: Verification) -> EdtResult<Verification> {
+        let before = self.workflow.guard_update(&mut verification)?;
+        let updated = self.testing_manager.update_verification(verification)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_verification(&self, id: &Uuid) -> EdtResult<()> {
-        self.testing_manager.delete_verification(id)
+    pub fn delete_verification(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Verification, id)
     }
 
     pub fn list_verification_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -541,26 +939,31 @@ impl EntityManager {
         success_criteria: Vec<String>,
         priority: TestPriority,
     ) -> EdtResult<Validation> {
-        self.testing_manager.create_validation(
+        let created = self.testing_manager.create_validation(
             name,
             description,
             validation_type,
             participants,
             success_criteria,
             priority,
-        )
+        )?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_validation(&self, id: &Uuid) -> EdtResult<Validation> {
         self.testing_manager.get_validation(id)
     }
 
-    pub fn update_validation(&self, validation: Validation) -> EdtResult<Validation> {
-        self.testing_manager.update_validation(validation)
+    pub fn update_validation(&self, mut validation: Validation) -> EdtResult<Validation> {
+        let before = self.workflow.guard_update(&mut validation)?;
+        let updated = self.testing_manager.update_validation(validation)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_validation(&self, id: &Uuid) -> EdtResult<()> {
-        self.testing_manager.delete_validation(id)
+    pub fn delete_validation(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Validation, id)
     }
 
     pub fn list_validation_ids(&self) -> EdtResult<Vec<Uuid>> {
@@ -579,30 +982,166 @@ impl EntityManager {
         work_instructions: Vec<WorkInstructionStep>,
         priority: u32,
     ) -> EdtResult<Manufacturing> {
-        self.manufacturing_manager.create_manufacturing(
+        let created = self.manufacturing_manager.create_manufacturing(
             name,
             description,
             process_type,
             work_instructions,
             priority,
-        )
+        )?;
+        self.audit.record_create(&created)?;
+        Ok(created)
     }
 
     pub fn get_manufacturing(&self, id: &Uuid) -> EdtResult<Manufacturing> {
         self.manufacturing_manager.get_manufacturing(id)
     }
 
-    pub fn update_manufacturing(&self, manufacturing: Manufacturing) -> EdtResult<Manufacturing> {
-        self.manufacturing_manager.update_manufacturing(manufacturing)
+    pub fn update_manufacturing(&self, mut manufacturing: Manufacturing) -> EdtResult<Manufacturing> {
+        let before = self.workflow.guard_update(&mut manufacturing)?;
+        let updated = self.manufacturing_manager.update_manufacturing(manufacturing)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
     }
 
-    pub fn delete_manufacturing(&self, id: &Uuid) -> EdtResult<()> {
-        self.manufacturing_manager.delete_manufacturing(id)
+    pub fn delete_manufacturing(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Manufacturing, id)
     }
 
     pub fn list_manufacturing_ids(&self) -> EdtResult<Vec<Uuid>> {
         self.manufacturing_manager.list_manufacturing_ids()
     }
+
+    // ============================================================================
+    // Comment Methods (delegate to CommentManager; threads are kept as links)
+    // ============================================================================
+
+    /// Start a discussion on any entity
+    pub fn create_comment(
+        &self,
+        entity_type: EntityType,
+        entity_id: &Uuid,
+        author: String,
+        body: String,
+    ) -> EdtResult<Comment> {
+        self.ensure_exists(&entity_type, entity_id)?;
+        self.add_comment(entity_type, entity_id, LinkType::Comments, author, body)
+    }
+
+    /// Reply to a comment, continuing its thread
+    pub fn reply_to_comment(&self, parent_id: &Uuid, author: String, body: String) -> EdtResult<Comment> {
+        self.ensure_exists(&EntityType::Comment, parent_id)?;
+        self.add_comment(EntityType::Comment, parent_id, LinkType::Replies, author, body)
+    }
+
+    pub fn get_comment(&self, id: &Uuid) -> EdtResult<Comment> {
+        self.comment_manager.get_comment(id)
+    }
+
+    pub fn update_comment(&self, mut comment: Comment) -> EdtResult<Comment> {
+        let before = self.workflow.guard_update(&mut comment)?;
+        let updated = self.comment_manager.update_comment(comment)?;
+        self.audit.record_update(before.as_ref(), &updated)?;
+        Ok(updated)
+    }
+
+    /// Replace a comment's body and mark it as edited
+    pub fn edit_comment(&self, id: &Uuid, body: String) -> EdtResult<Comment> {
+        let mut comment = self.comment_manager.get_comment(id)?;
+        comment.body = body;
+        comment.edited_at = Some(Utc::now());
+        self.update_comment(comment)
+    }
+
+    /// Resolve a comment, or reopen it
+    pub fn set_comment_resolved(&self, id: &Uuid, resolved: bool) -> EdtResult<Comment> {
+        let mut comment = self.comment_manager.get_comment(id)?;
+        comment.resolved = resolved;
+        self.update_comment(comment)
+    }
+
+    pub fn delete_comment(&self, id: &Uuid) -> EdtResult<DeleteImpact> {
+        self.delete_entity(EntityType::Comment, id)
+    }
+
+    pub fn list_comment_ids(&self) -> EdtResult<Vec<Uuid>> {
+        self.comment_manager.list_comment_ids()
+    }
+
+    /// Discussions on an entity, oldest first
+    pub fn get_comment_threads(&self, entity_id: &Uuid) -> EdtResult<Vec<CommentThread>> {
+        let link_manager = self.lock_link_manager()?;
+        self.comment_threads(&link_manager, entity_id, &LinkType::Comments)
+    }
+
+    /// Number of unresolved comments on an entity, replies included
+    ///
+    /// Resolving a comment closes its replies too, so they no longer count.
+    pub fn unresolved_comment_count(&self, entity_id: &Uuid) -> EdtResult<usize> {
+        fn count(threads: &[CommentThread]) -> usize {
+            threads
+                .iter()
+                .filter(|thread| !thread.comment.resolved)
+                .map(|thread| 1 + count(&thread.replies))
+                .sum()
+        }
+        Ok(count(&self.get_comment_threads(entity_id)?))
+    }
+
+    fn add_comment(
+        &self,
+        target_type: EntityType,
+        target_id: &Uuid,
+        link_type: LinkType,
+        author: String,
+        body: String,
+    ) -> EdtResult<Comment> {
+        let mut link_manager = self.lock_link_manager()?;
+
+        // The comment and the link placing it in its thread go together
+        let label = if link_type == LinkType::Replies { "Reply to Comment" } else { "Add Comment" };
+        let created = self.storage.with_undo_group(label, || {
+            let comment = self.comment_manager.create_comment(author, body)?;
+            link_manager.create_link(
+                comment.metadata.id,
+                EntityType::Comment,
+                *target_id,
+                target_type,
+                link_type,
+                None,
+            )?;
+            Ok(comment)
+        })?;
+
+        self.audit.record_create(&created)?;
+        Ok(created)
+    }
+
+    /// Comments linked to the target by `link_type`, each with its replies
+    fn comment_threads(
+        &self,
+        link_manager: &LinkManager,
+        target_id: &Uuid,
+        link_type: &LinkType,
+    ) -> EdtResult<Vec<CommentThread>> {
+        let mut threads = Vec::new();
+        for link in link_manager.get_links_to(target_id) {
+            if &link.link_type != link_type || link.from_entity_type != EntityType::Comment {
+                continue;
+            }
+            // Links survive their comment when it was deleted under the Orphan policy
+            if !self.storage.exists(&EntityType::Comment, &link.from_entity_id) {
+                continue;
+            }
+
+            let comment = self.comment_manager.get_comment(&link.from_entity_id)?;
+            let replies = self.comment_threads(link_manager, &comment.metadata.id, &LinkType::Replies)?;
+            threads.push(CommentThread { comment, replies });
+        }
+
+        threads.sort_by_key(|thread| thread.comment.metadata.created_at);
+        Ok(threads)
+    }
 }
 
 #[cfg(test)]
@@ -610,14 +1149,89 @@ mod tests {
     use super::*;
     use tempfile::TempDir;
     use chrono::Duration;
+    use crate::core::AuditQuery;
 
     fn create_test_manager() -> (TempDir, EntityManager) {
         let temp_dir = TempDir::new().unwrap();
         let storage = Arc::new(RonStorage::new(temp_dir.path()).unwrap());
-        let manager = EntityManager::new(storage);
+        let link_manager = Arc::new(Mutex::new(
+            LinkManager::with_storage(Arc::clone(&storage)).unwrap(),
+        ));
+        let config = Arc::new(RwLock::new(ProjectConfig::default()));
+        let manager = EntityManager::new(storage, link_manager, config).unwrap();
         (temp_dir, manager)
     }
 
+    /// One entity of every stored type
+    fn create_one_of_each(manager: &EntityManager) {
+        let start = Utc::now();
+        let text = |value: &str| value.to_string();
+        let task = manager
+            .create_task(text("Task"), text("Description"), start, start + Duration::days(5), TaskType::EffortDriven)
+            .unwrap();
+        manager.create_milestone(text("Milestone"), text("Description"), start).unwrap();
+        manager.create_resource(text("Resource"), text("Description"), ResourceType::Labor).unwrap();
+        manager.create_calendar(text("Calendar"), 8.0, vec![chrono::Weekday::Mon]).unwrap();
+        manager.create_baseline(text("Baseline"), text("Description"), vec![task.metadata.id]).unwrap();
+        let requirement = manager
+            .create_requirement(text("Requirement"), text("Description"), text("System Requirement"))
+            .unwrap();
+        manager.create_hazard(text("Hazard"), text("Description"), vec![text("Cause")], vec![text("Harm")]).unwrap();
+        manager.create_risk(text("Risk"), text("Description"), text("Safety Risk"), 3, 4).unwrap();
+        manager.create_risk_control(text("Control"), text("Description"), text("Design Control")).unwrap();
+        manager.create_assembly(text("Assembly"), text("Description"), text("A")).unwrap();
+        manager.create_component(text("Component"), text("Description"), text("A")).unwrap();
+        manager
+            .create_feature(text("Feature"), text("Description"), FeatureType::External, 10.0, 0.1, -0.1, DistributionType::Normal)
+            .unwrap();
+        manager.create_mate(text("Mate"), text("Description"), MateType::Clearance).unwrap();
+        manager.create_stackup(text("Stackup"), text("Description"), vec![AnalysisType::WorstCase]).unwrap();
+        manager.create_supplier(text("Supplier"), text("Description")).unwrap();
+        manager
+            .create_quote(text("Q-1"), start.date_naive(), vec![(1, 10.0), (100, 5.0)], CostDistribution::Linear)
+            .unwrap();
+        manager
+            .create_verification(text("Verification"), text("Description"), text("Functional"), vec![], vec![], TestPriority::High)
+            .unwrap();
+        manager
+            .create_validation(text("Validation"), text("Description"), text("User Study"), vec![], vec![], TestPriority::High)
+            .unwrap();
+        manager.create_manufacturing(text("Process"), text("Description"), text("Assembly"), vec![], 1).unwrap();
+        manager
+            .create_comment(EntityType::Requirement, &requirement.metadata.id, text("Dana Reyes"), text("Comment"))
+            .unwrap();
+    }
+
+    fn link(manager: &EntityManager, from: &EntityMetadata, to: &EntityMetadata, link_type: LinkType) -> Link {
+        manager
+            .link_manager
+            .lock()
+            .unwrap()
+            .create_link(
+                from.id,
+                from.entity_type.clone(),
+                to.id,
+                to.entity_type.clone(),
+                link_type,
+                None,
+            )
+            .unwrap()
+    }
+
+    fn create_feature(manager: &EntityManager, name: &str) -> Feature {
+        manager
+            .create_feature(
+                name.to_string(),
+                "Description".to_string(),
+                FeatureType::Internal,
+                10.0,
+                10.05,
+                9.95,
+                DistributionType::Normal,
+            )
+            .unwrap()
+    }
+
     #[test]
     fn test_create_task() {
         let (_temp, manager) = create_test_manager();
@@ -804,6 +1418,27 @@ mod tests {
         assert_eq!(req.requirement_type, "System Requirement");
     }
 
+    #[test]
+    fn test_created_entities_get_human_ids() {
+        let (_temp, manager) = create_test_manager();
+
+        let create = |name: &str| {
+            manager
+                .create_requirement(name.to_string(), "Description".to_string(), "System Requirement".to_string())
+                .unwrap()
+        };
+        let first = create("First");
+        let second = create("Second");
+        let risk = manager
+            .create_risk("Risk".to_string(), "Description".to_string(), "Safety Risk".to_string(), 2, 3)
+            .unwrap();
+
+        assert_eq!(first.metadata.human_id.as_deref(), Some("REQ-0001"));
+        assert_eq!(second.metadata.human_id.as_deref(), Some("REQ-0002"));
+        assert_eq!(risk.metadata.human_id.as_deref(), Some("RSK-0001"));
+        assert_eq!(manager.id_registry().resolve("REQ-0002").unwrap(), second.metadata.id);
+    }
+
     #[test]
     fn test_get_and_update_requirement() {
         let (_temp, manager) = create_test_manager();
@@ -844,6 +1479,117 @@ mod tests {
         assert_eq!(risk.probability, 3);
         assert_eq!(risk.severity, 4);
         assert_eq!(risk.risk_score, 12); // 3 * 4
+        assert!(!risk.is_acceptable);
+    }
+
+    #[test]
+    fn test_risk_score_uses_configured_matrix() {
+        let (_temp, manager) = create_test_manager();
+        {
+            // Non-multiplicative 5x4 matrix
+            let mut config = manager.config.write().unwrap();
+            config.severity_levels = vec![1, 2, 3, 4];
+            config.risk_matrix.clear();
+            for probability in 1..=5 {
+                for severity in 1..=4 {
+                    config
+                        .risk_matrix
+                        .insert(format!("{},{}", probability, severity), probability + 2 * severity);
+                }
+            }
+            config.acceptable_risk_threshold = 6;
+        }
+
+        let mut risk = manager
+            .create_risk("RISK-001".to_string(), "Test".to_string(), "Safety Risk".to_string(), 3, 4)
+            .unwrap();
+        assert_eq!(risk.risk_score, 11);
+        assert!(!risk.is_acceptable);
+
+        risk.residual_probability = Some(1);
+        risk.residual_severity = Some(2);
+        let updated = manager.update_risk(risk).unwrap();
+        assert_eq!(updated.residual_risk_score, Some(5));
+        assert_eq!(updated.residual_is_acceptable, Some(true));
+    }
+
+    #[test]
+    fn test_config_types_validated() {
+        let (_temp, manager) = create_test_manager();
+
+        let result = manager.create_requirement(
+            "REQ-001".to_string(), "Test".to_string(), "Marketing Requirement".to_string(),
+        );
+        assert!(matches!(result.unwrap_err(), EdtError::ValidationError(_)));
+
+        let result = manager.create_risk(
+            "RISK-001".to_string(), "Test".to_string(), "Cost Risk".to_string(), 1, 1,
+        );
+        assert!(matches!(result.unwrap_err(), EdtError::ValidationError(_)));
+
+        let result = manager.create_risk_control(
+            "RC-001".to_string(), "Test".to_string(), "Wishful Control".to_string(),
+        );
+        assert!(matches!(result.unwrap_err(), EdtError::ValidationError(_)));
+
+        let mut requirement = manager
+            .create_requirement("REQ-002".to_string(), "Test".to_string(), "User Requirement".to_string())
+            .unwrap();
+        requirement.requirement_type = "Unknown".to_string();
+        assert!(manager.update_requirement(requirement).is_err());
+    }
+
+    #[test]
+    fn test_revalidate_entity_types() {
+        let (_temp, manager) = create_test_manager();
+
+        let requirement = manager
+            .create_requirement("REQ-001".to_string(), "Test".to_string(), "User Requirement".to_string())
+            .unwrap();
+        manager
+            .create_requirement("REQ-002".to_string(), "Test".to_string(), "System Requirement".to_string())
+            .unwrap();
+        manager
+            .create_risk("RISK-001".to_string(), "Test".to_string(), "Safety Risk".to_string(), 1, 1)
+            .unwrap();
+        assert!(manager.revalidate_entity_types().unwrap().is_empty());
+
+        // Remove a type that is still in use
+        manager
+            .config
+            .write()
+            .unwrap()
+            .requirement_types
+            .retain(|t| t != "User Requirement");
+
+        let issues = manager.revalidate_entity_types().unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].entity_id, requirement.metadata.id);
+        assert_eq!(issues[0].field, "requirement_type");
+        assert!(issues[0].message.contains("User Requirement"));
+    }
+
+    #[test]
+    fn test_risk_levels_outside_config_rejected() {
+        let (_temp, manager) = create_test_manager();
+
+        let result = manager.create_risk(
+            "RISK-001".to_string(), "Test".to_string(), "Safety Risk".to_string(), 6, 1,
+        );
+        assert!(matches!(result.unwrap_err(), EdtError::ValidationError(_)));
+
+        let mut risk = manager
+            .create_risk("RISK-002".to_string(), "Test".to_string(), "Safety Risk".to_string(), 2, 2)
+            .unwrap();
+        assert!(risk.is_acceptable);
+
+        risk.residual_probability = Some(1);
+        risk.residual_severity = Some(0);
+        assert!(manager.update_risk(risk.clone()).is_err());
+
+        // Residual levels must come in pairs
+        risk.residual_severity = None;
+        assert!(manager.update_risk(risk).is_err());
     }
 
     #[test]
@@ -973,12 +1719,48 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_every_stored_type_round_trips_and_deletes() {
+        let (_temp, manager) = create_test_manager();
+        create_one_of_each(&manager);
+
+        let mut covered = Vec::new();
+        for entity_type in EntityType::all().iter().filter(|t| RonStorage::has_model(t)) {
+            let ids = manager.storage.list_ids(entity_type).unwrap();
+            assert!(!ids.is_empty(), "no {:?} created", entity_type);
+            for id in ids {
+                let json = manager.storage.read_entity_json(entity_type, &id).unwrap();
+                let ron = RonStorage::entity_json_to_ron(entity_type, &json).unwrap();
+                assert_eq!(RonStorage::entity_ron_to_json(entity_type, &ron).unwrap(), json);
+
+                manager.delete_entity_file(entity_type, &id).unwrap();
+                assert!(!manager.storage.exists(entity_type, &id));
+                assert!(matches!(manager.delete_entity_file(entity_type, &id), Err(EdtError::EntityNotFound(_))));
+            }
+            covered.push(entity_type.clone());
+        }
+        // General is the only type without a stored model
+        assert_eq!(covered.len(), EntityType::all().len() - 1);
+
+        let result = manager.delete_entity_file(&EntityType::General, &Uuid::new_v4());
+        assert!(matches!(result, Err(EdtError::ValidationError(_))));
+        assert!(RonStorage::entity_json_to_ron(&EntityType::General, &serde_json::json!({})).is_err());
+    }
+
     #[test]
     fn test_create_baseline() {
         let (_temp, manager) = create_test_manager();
+        let start = Utc::now();
+        let create_task = |name: &str| {
+            manager
+                .create_task(name.to_string(), "Description".to_string(), start, start + Duration::days(5), TaskType::EffortDriven)
+                .unwrap()
+                .metadata
+                .id
+        };
 
-        let task1_id = Uuid::new_v4();
-        let task2_id = Uuid::new_v4();
+        let task1_id = create_task("Task 1");
+        let task2_id = create_task("Task 2");
 
         let baseline = manager
             .create_baseline(
@@ -991,6 +1773,113 @@ mod tests {
         assert_eq!(baseline.name, "Q1 Baseline");
         assert_eq!(baseline.task_ids.len(), 2);
         assert!(baseline.task_ids.contains(&task1_id));
+
+        // The snapshot written into each task is part of the audit trail
+        let query = AuditQuery { entity_id: Some(task1_id), ..Default::default() };
+        let records = manager.audit_log().query(&query).unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(records[1].changes.iter().any(|c| c.path.starts_with("baseline_data")));
+    }
+
+    #[test]
+    fn test_create_baseline_snapshots_tasks() {
+        let (_temp, manager) = create_test_manager();
+        let start = Utc::now();
+
+        let task = manager
+            .create_task(
+                "Design Phase".to_string(),
+                "Initial design".to_string(),
+                start,
+                start + chrono::Duration::days(10),
+                TaskType::EffortDriven,
+            )
+            .unwrap();
+
+        let baseline = manager
+            .create_baseline("Q1 Baseline".to_string(), "Description".to_string(), vec![task.metadata.id])
+            .unwrap();
+
+        let snapshot = manager.get_task(&task.metadata.id).unwrap().baseline_data.unwrap();
+        assert_eq!(snapshot.baseline_id, baseline.metadata.id);
+        assert_eq!(snapshot.start, task.scheduled_start);
+        assert_eq!(snapshot.end, task.deadline);
+
+        // The snapshot and the baseline are undone together
+        let history = manager.storage.undo_history().unwrap();
+        assert_eq!(history.undo[0].label, "Create Baseline");
+    }
+
+    #[test]
+    fn test_create_baseline_rejects_missing_tasks() {
+        let (_temp, manager) = create_test_manager();
+
+        let missing = Uuid::new_v4();
+        let result = manager.create_baseline("Q1 Baseline".to_string(), "Description".to_string(), vec![missing]);
+        assert!(matches!(result, Err(EdtError::EntityNotFound(id)) if id == missing.to_string()));
+        assert!(manager.storage.list::<Baseline>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_new_baseline_replaces_task_snapshot() {
+        let (_temp, manager) = create_test_manager();
+        let start = Utc::now();
+
+        let task = manager
+            .create_task(
+                "Design Phase".to_string(),
+                "Initial design".to_string(),
+                start,
+                start + chrono::Duration::days(10),
+                TaskType::EffortDriven,
+            )
+            .unwrap();
+        let first = manager
+            .create_baseline("Q1 Baseline".to_string(), "Description".to_string(), vec![task.metadata.id])
+            .unwrap();
+
+        let mut moved = manager.get_task(&task.metadata.id).unwrap();
+        moved.deadline = start + chrono::Duration::days(20);
+        manager.update_task(moved).unwrap();
+        let second = manager
+            .create_baseline("Q2 Baseline".to_string(), "Description".to_string(), vec![task.metadata.id])
+            .unwrap();
+
+        let snapshot = manager.get_task(&task.metadata.id).unwrap().baseline_data.unwrap();
+        assert_eq!(snapshot.baseline_id, second.metadata.id);
+        assert_eq!(snapshot.end, start + chrono::Duration::days(20));
+        // The earlier baseline still lists the task, but its snapshot is gone
+        assert!(manager.get_baseline(&first.metadata.id).unwrap().task_ids.contains(&task.metadata.id));
+    }
+
+    #[test]
+    fn test_diff_task_baseline() {
+        let (_temp, manager) = create_test_manager();
+        let start = Utc::now();
+
+        let task = manager
+            .create_task(
+                "Design Phase".to_string(),
+                "Initial design".to_string(),
+                start,
+                start + chrono::Duration::days(10),
+                TaskType::EffortDriven,
+            )
+            .unwrap();
+        assert!(manager.diff_task_baseline(&task.metadata.id).is_err());
+
+        manager
+            .create_baseline("Q1 Baseline".to_string(), "Description".to_string(), vec![task.metadata.id])
+            .unwrap();
+        assert!(manager.diff_task_baseline(&task.metadata.id).unwrap().is_empty());
+
+        let mut updated = manager.get_task(&task.metadata.id).unwrap();
+        updated.percent_complete = 0.5;
+        manager.update_task(updated).unwrap();
+
+        let changes = manager.diff_task_baseline(&task.metadata.id).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "percent_complete");
     }
 
     #[test]
@@ -1011,13 +1900,11 @@ mod tests {
     fn test_get_and_update_baseline() {
         let (_temp, manager) = create_test_manager();
 
-        let task_id = Uuid::new_v4();
-
         let baseline = manager
             .create_baseline(
                 "Q1 Baseline".to_string(),
                 "Description".to_string(),
-                vec![task_id],
+                vec![],
             )
             .unwrap();
 
@@ -2630,4 +3517,269 @@ mod tests {
         assert_eq!(updated.setup_time_minutes, Some(30.0));
         assert_eq!(updated.cycle_time_minutes, Some(17.0));
     }
+
+    #[test]
+    fn test_delete_cascades_links() {
+        let (_temp, manager) = create_test_manager();
+
+        let supplier = manager
+            .create_supplier("Acme".to_string(), "Supplier".to_string())
+            .unwrap();
+        let quote = manager
+            .create_quote(
+                "Q-1".to_string(),
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                vec![(1, 10.0)],
+                CostDistribution::Linear,
+            )
+            .unwrap();
+        let created = link(&manager, &supplier.metadata, &quote.metadata, LinkType::Supplies);
+
+        let impact = manager.delete_supplier(&supplier.metadata.id).unwrap();
+
+        assert_eq!(impact.policy, DeletePolicy::CascadeLinks);
+        assert!(!impact.blocked);
+        assert_eq!(impact.links.len(), 1);
+        assert_eq!(impact.links[0].id, created.id);
+        assert!(manager.get_supplier(&supplier.metadata.id).is_err());
+        assert!(manager.link_manager.lock().unwrap().get_link(&created.id).is_none());
+    }
+
+    #[test]
+    fn test_delete_blocked_by_links() {
+        let (_temp, manager) = create_test_manager();
+
+        let parent = manager
+            .create_requirement("Parent".to_string(), "Description".to_string(), "System Requirement".to_string())
+            .unwrap();
+        let child = manager
+            .create_requirement("Child".to_string(), "Description".to_string(), "System Requirement".to_string())
+            .unwrap();
+        link(&manager, &parent.metadata, &child.metadata, LinkType::Derives);
+
+        let result = manager.delete_requirement(&parent.metadata.id);
+        assert!(matches!(result.unwrap_err(), EdtError::HasDependencies(1)));
+
+        // Nothing was removed
+        assert!(manager.get_requirement(&parent.metadata.id).is_ok());
+        assert_eq!(manager.link_manager.lock().unwrap().get_all_links(&parent.metadata.id).len(), 1);
+
+        let preview = manager
+            .preview_delete(EntityType::Requirement, &parent.metadata.id)
+            .unwrap();
+        assert!(preview.blocked);
+        assert_eq!(preview.links.len(), 1);
+    }
+
+    #[test]
+    fn test_delete_unlinked_blocked_type() {
+        let (_temp, manager) = create_test_manager();
+
+        let requirement = manager
+            .create_requirement("Alone".to_string(), "Description".to_string(), "System Requirement".to_string())
+            .unwrap();
+
+        let impact = manager.delete_requirement(&requirement.metadata.id).unwrap();
+        assert_eq!(impact.policy, DeletePolicy::Block);
+        assert!(impact.links.is_empty());
+        assert!(manager.get_requirement(&requirement.metadata.id).is_err());
+    }
+
+    #[test]
+    fn test_delete_component_cascades_features() {
+        let (_temp, manager) = create_test_manager();
+
+        let component = manager
+            .create_component("Housing".to_string(), "Description".to_string(), "A".to_string())
+            .unwrap();
+        let owned = create_feature(&manager, "Bore");
+        let other = create_feature(&manager, "Shaft");
+        link(&manager, &component.metadata, &owned.metadata, LinkType::HasFeature);
+        link(&manager, &owned.metadata, &other.metadata, LinkType::Mates);
+
+        let preview = manager
+            .preview_delete(EntityType::Component, &component.metadata.id)
+            .unwrap();
+        assert!(manager.get_feature(&owned.metadata.id).is_ok());
+
+        let impact = manager.delete_component(&component.metadata.id).unwrap();
+        assert_eq!(impact.children, preview.children);
+        assert_eq!(impact.children.len(), 1);
+        assert_eq!(impact.children[0].entity_id, owned.metadata.id);
+        // HasFeature and the owned feature's Mates link
+        assert_eq!(impact.links.len(), 2);
+
+        assert!(manager.get_component(&component.metadata.id).is_err());
+        assert!(manager.get_feature(&owned.metadata.id).is_err());
+        assert!(manager.get_feature(&other.metadata.id).is_ok());
+        assert!(manager.link_manager.lock().unwrap().get_all_links(&other.metadata.id).is_empty());
+    }
+
+    #[test]
+    fn test_delete_cascades_to_grandchildren() {
+        use crate::core::LinkRules;
+        use crate::models::config::LinkRuleConfig;
+
+        let (_temp, manager) = create_test_manager();
+        let mut config = manager.config.read().unwrap().clone();
+        config.link_rules.push(LinkRuleConfig {
+            from_entity_type: EntityType::Feature,
+            link_type: LinkType::HasFeature,
+            to_entity_type: EntityType::Feature,
+        });
+        manager.link_manager.lock().unwrap().set_link_rules(LinkRules::from_config(&config));
+
+        let component = manager
+            .create_component("Housing".to_string(), "Description".to_string(), "A".to_string())
+            .unwrap();
+        let bore = create_feature(&manager, "Bore");
+        let chamfer = create_feature(&manager, "Chamfer");
+        link(&manager, &component.metadata, &bore.metadata, LinkType::HasFeature);
+        link(&manager, &bore.metadata, &chamfer.metadata, LinkType::HasFeature);
+        // Ownership loops back; each feature is still deleted once
+        link(&manager, &chamfer.metadata, &bore.metadata, LinkType::HasFeature);
+
+        let impact = manager.delete_component(&component.metadata.id).unwrap();
+        let children: Vec<Uuid> = impact.children.iter().map(|child| child.entity_id).collect();
+        assert_eq!(children, vec![bore.metadata.id, chamfer.metadata.id]);
+        assert_eq!(impact.links.len(), 3);
+
+        assert!(manager.get_feature(&bore.metadata.id).is_err());
+        assert!(manager.get_feature(&chamfer.metadata.id).is_err());
+        assert_eq!(manager.link_manager.lock().unwrap().link_count(), 0);
+    }
+
+    #[test]
+    fn test_delete_refused_when_child_is_locked() {
+        use crate::models::EntityStatus;
+
+        let (_temp, manager) = create_test_manager();
+        let component = manager
+            .create_component("Housing".to_string(), "Description".to_string(), "A".to_string())
+            .unwrap();
+        let mut bore = create_feature(&manager, "Bore");
+        link(&manager, &component.metadata, &bore.metadata, LinkType::HasFeature);
+        bore.metadata.status = EntityStatus::Released;
+        manager.storage.write(&bore).unwrap();
+
+        let result = manager.delete_component(&component.metadata.id);
+        assert!(matches!(result, Err(EdtError::EntityLocked(EntityStatus::Released))));
+
+        // Nothing was removed
+        assert!(manager.get_component(&component.metadata.id).is_ok());
+        assert!(manager.get_feature(&bore.metadata.id).is_ok());
+        assert_eq!(manager.link_manager.lock().unwrap().link_count(), 1);
+    }
+
+    #[test]
+    fn test_delete_orphan_policy_keeps_links() {
+        let (_temp, manager) = create_test_manager();
+        manager
+            .config
+            .write()
+            .unwrap()
+            .delete_policies
+            .insert(EntityType::Feature, DeletePolicy::Orphan);
+
+        let first = create_feature(&manager, "First");
+        let second = create_feature(&manager, "Second");
+        link(&manager, &first.metadata, &second.metadata, LinkType::Mates);
+
+        let impact = manager.delete_feature(&first.metadata.id).unwrap();
+        assert_eq!(impact.links.len(), 1);
+        assert!(manager.get_feature(&first.metadata.id).is_err());
+        assert_eq!(manager.link_manager.lock().unwrap().get_all_links(&second.metadata.id).len(), 1);
+    }
+
+    #[test]
+    fn test_delete_not_found() {
+        let (_temp, manager) = create_test_manager();
+
+        let result = manager.delete_task(&Uuid::new_v4());
+        assert!(matches!(result.unwrap_err(), EdtError::EntityNotFound(_)));
+    }
+
+    fn create_commented_task(manager: &EntityManager) -> Task {
+        let start = Utc::now();
+        manager
+            .create_task(
+                "Seal design".to_string(),
+                "Pick the housing seal".to_string(),
+                start,
+                start + Duration::days(5),
+                TaskType::EffortDriven,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_comment_threads() {
+        let (_temp, manager) = create_test_manager();
+        let task = create_commented_task(&manager);
+
+        let first = manager
+            .create_comment(EntityType::Task, &task.metadata.id, "Dana".to_string(), "O-ring or gasket?".to_string())
+            .unwrap();
+        let reply = manager
+            .reply_to_comment(&first.metadata.id, "Sam".to_string(), "O-ring, it's cheaper".to_string())
+            .unwrap();
+        manager
+            .reply_to_comment(&reply.metadata.id, "Dana".to_string(), "Agreed".to_string())
+            .unwrap();
+        let second = manager
+            .create_comment(EntityType::Task, &task.metadata.id, "Sam".to_string(), "Due date is tight".to_string())
+            .unwrap();
+        let labels: Vec<String> = manager.storage.undo_history().unwrap().undo.into_iter().map(|e| e.label).collect();
+        assert_eq!(labels[..4], ["Add Comment", "Reply to Comment", "Reply to Comment", "Add Comment"]);
+
+        let threads = manager.get_comment_threads(&task.metadata.id).unwrap();
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].comment.metadata.id, first.metadata.id);
+        assert_eq!(threads[0].replies.len(), 1);
+        assert_eq!(threads[0].replies[0].comment.metadata.id, reply.metadata.id);
+        assert_eq!(threads[0].replies[0].replies.len(), 1);
+        assert_eq!(threads[1].comment.metadata.id, second.metadata.id);
+        assert!(threads[1].replies.is_empty());
+
+        // Replies count, nested ones included
+        assert_eq!(manager.unresolved_comment_count(&task.metadata.id).unwrap(), 4);
+        manager.set_comment_resolved(&reply.metadata.id, true).unwrap();
+        assert_eq!(manager.unresolved_comment_count(&task.metadata.id).unwrap(), 2);
+        manager.set_comment_resolved(&reply.metadata.id, false).unwrap();
+
+        // Resolving a top-level comment closes its whole thread
+        let resolved = manager.set_comment_resolved(&first.metadata.id, true).unwrap();
+        assert!(resolved.resolved);
+        assert!(resolved.edited_at.is_none());
+        assert_eq!(manager.unresolved_comment_count(&task.metadata.id).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_edit_and_delete_comment() {
+        let (_temp, manager) = create_test_manager();
+        let task = create_commented_task(&manager);
+
+        let comment = manager
+            .create_comment(EntityType::Task, &task.metadata.id, "Dana".to_string(), "Typo".to_string())
+            .unwrap();
+        let edited = manager.edit_comment(&comment.metadata.id, "Fixed".to_string()).unwrap();
+        assert_eq!(edited.body, "Fixed");
+        assert!(edited.edited_at.is_some());
+        assert!(manager.edit_comment(&comment.metadata.id, "  ".to_string()).is_err());
+
+        let impact = manager.delete_comment(&comment.metadata.id).unwrap();
+        assert_eq!(impact.links.len(), 1);
+        assert!(manager.get_comment_threads(&task.metadata.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_comment_requires_existing_target() {
+        let (_temp, manager) = create_test_manager();
+
+        let result = manager.create_comment(EntityType::Task, &Uuid::new_v4(), "Dana".to_string(), "Hello".to_string());
+        assert!(matches!(result, Err(EdtError::EntityNotFound(_))));
+        let result = manager.reply_to_comment(&Uuid::new_v4(), "Dana".to_string(), "Hello".to_string());
+        assert!(matches!(result, Err(EdtError::EntityNotFound(_))));
+        assert!(manager.list_comment_ids().unwrap().is_empty());
+    }
 }
@@ -15,6 +15,12 @@ pub enum EdtError {
     #[error("Link type not allowed between {0} and {1}")]
     InvalidLink(String, String),
 
+    #[error("Status transition not allowed: {0}")]
+    InvalidTransition(String),
+
+    #[error("Entity is {0:?} and read-only until revised")]
+    EntityLocked(crate::models::EntityStatus),
+
     #[error("Calculation failed: {0}")]
     CalculationError(String),
 
@@ -24,6 +30,9 @@ pub enum EdtError {
     #[error("Database error: {0}")]
     DatabaseError(String),
 
+    #[error("Version control error: {0}")]
+    VersionControlError(String),
+
     #[error("RON serialization error: {0}")]
     RonSerError(#[from] ron::Error),
 
@@ -36,6 +45,9 @@ pub enum EdtError {
     #[error("TOML serialization error: {0}")]
     TomlSerError(#[from] toml::ser::Error),
 
+    #[error("JSON serialization error: {0}")]
+    JsonError(#[from] serde_json::Error),
+
     #[error("UUID parsing error: {0}")]
     UuidError(#[from] uuid::Error),
 
@@ -49,6 +61,27 @@ pub enum EdtError {
     SchemaVersionMismatch { expected: String, found: String },
 }
 
+impl From<rusqlite::Error> for EdtError {
+    fn from(e: rusqlite::Error) -> Self {
+        EdtError::DatabaseError(e.to_string())
+    }
+}
+
+impl From<git2::Error> for EdtError {
+    fn from(e: git2::Error) -> Self {
+        EdtError::VersionControlError(e.message().to_string())
+    }
+}
+
+impl From<notify::Error> for EdtError {
+    fn from(e: notify::Error) -> Self {
+        match e.kind {
+            notify::ErrorKind::Io(io) => EdtError::FileSystemError(io),
+            _ => EdtError::FileSystemError(std::io::Error::other(e.to_string())),
+        }
+    }
+}
+
 /// Result type alias for EDT operations
 pub type EdtResult<T> = Result<T, EdtError>;
 
@@ -92,6 +125,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_entity_locked_error() {
+        let error = EdtError::EntityLocked(crate::models::EntityStatus::Released);
+        assert_eq!(
+            error.to_string(),
+            "Entity is Released and read-only until revised"
+        );
+    }
+
     #[test]
     fn test_calculation_error() {
         let error = EdtError::CalculationError("Division by zero".to_string());
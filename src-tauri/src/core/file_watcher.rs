@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::core::{EdtResult, LinkManager};
+use crate::models::EntityType;
+use crate::storage::SqliteCache;
+
+/// Name of the event emitted to the frontend for each changed entity
+pub const ENTITY_CHANGED_EVENT: &str = "entity-changed";
+
+/// Editors and git write files in several steps; events closer together than this are batched
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// How an entity file changed on disk
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FileChangeKind {
+    Created,
+    Modified,
+    Deleted,
+}
+
+/// An entity file created, modified or deleted outside the app
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EntityChange {
+    pub entity_type: EntityType,
+    pub entity_id: Uuid,
+    pub kind: FileChangeKind,
+}
+
+/// A watched file whose content actually changed
+#[derive(Debug, PartialEq)]
+enum FileChange {
+    Entity(EntityChange),
+    Link,
+}
+
+/// Last seen blake3 hash of every watched file, so no-op touches are ignored
+struct ChangeTracker {
+    project_root: PathBuf,
+    hashes: HashMap<PathBuf, blake3::Hash>,
+}
+
+impl ChangeTracker {
+    fn new(project_root: PathBuf) -> EdtResult<Self> {
+        let mut tracker = Self {
+            project_root,
+            hashes: HashMap::new(),
+        };
+
+        let mut dirs = vec![tracker.project_root.join("links")];
+        for entity_type in EntityType::all() {
+            dirs.push(tracker.project_root.join("entities").join(entity_type.folder_name()));
+        }
+        for dir in dirs.iter().filter(|dir| dir.exists()) {
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+                if path.extension().and_then(|s| s.to_str()) == Some("ron") {
+                    let hash = blake3::hash(&fs::read(&path)?);
+                    tracker.hashes.insert(path, hash);
+                }
+            }
+        }
+        Ok(tracker)
+    }
+
+    /// Compare a path reported by the watcher with its last known content
+    fn check(&mut self, path: &Path) -> Option<FileChange> {
+        let target = self.classify(path)?;
+
+        let kind = match fs::read(path) {
+            Ok(content) => {
+                let hash = blake3::hash(&content);
+                match self.hashes.insert(path.to_path_buf(), hash) {
+                    Some(previous) if previous == hash => return None,
+                    Some(_) => FileChangeKind::Modified,
+                    None => FileChangeKind::Created,
+                }
+            }
+            Err(_) => {
+                self.hashes.remove(path)?;
+                FileChangeKind::Deleted
+            }
+        };
+
+        Some(match target {
+            Some((entity_type, entity_id)) => FileChange::Entity(EntityChange {
+                entity_type,
+                entity_id,
+                kind,
+            }),
+            None => FileChange::Link,
+        })
+    }
+
+    /// `Some(Some(..))` for an entity file, `Some(None)` for a link file, `None` for anything else
+    fn classify(&self, path: &Path) -> Option<Option<(EntityType, Uuid)>> {
+        // Skips the `.name.tmp` files written by atomic saves
+        if path.extension()? != "ron" {
+            return None;
+        }
+        let relative = path.strip_prefix(&self.project_root).ok()?;
+        let folder = relative.parent()?;
+
+        if folder == Path::new("links") {
+            return Some(None);
+        }
+        let folder = folder.strip_prefix("entities").ok()?.to_str()?;
+        let entity_type = EntityType::from_folder_name(folder)?;
+        let entity_id = Uuid::parse_str(relative.file_stem()?.to_str()?).ok()?;
+        Some(Some((entity_type, entity_id)))
+    }
+}
+
+/// Watches the entity and link folders and keeps the cache and link graph in sync
+///
+/// The app's own saves are picked up too; they refresh the cache and notify any
+/// other open views of the same entity. Dropping the watcher stops it.
+pub struct FileWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl FileWatcher {
+    /// Start watching; `on_change` is called from a background thread after each batch is applied
+    pub fn start<F>(
+        project_root: PathBuf,
+        cache: Arc<SqliteCache>,
+        link_manager: Arc<Mutex<LinkManager>>,
+        on_change: F,
+    ) -> EdtResult<Self>
+    where
+        F: Fn(&[EntityChange]) + Send + 'static,
+    {
+        let entities_dir = project_root.join("entities");
+        let links_dir = project_root.join("links");
+        fs::create_dir_all(&entities_dir)?;
+        fs::create_dir_all(&links_dir)?;
+
+        // Event paths are canonical, e.g. `/private/var` rather than `/var` on macOS
+        let tracker = ChangeTracker::new(fs::canonicalize(&project_root)?)?;
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            if let Ok(event) = event {
+                // Fails only once the worker has stopped
+                let _ = sender.send(event);
+            }
+        })?;
+        watcher.watch(&entities_dir, RecursiveMode::Recursive)?;
+        watcher.watch(&links_dir, RecursiveMode::NonRecursive)?;
+
+        thread::spawn(move || Self::run(receiver, tracker, &cache, &link_manager, on_change));
+
+        Ok(Self { _watcher: watcher })
+    }
+
+    /// Worker loop; ends when the watcher is dropped and the channel closes
+    fn run<F>(
+        receiver: Receiver<Event>,
+        mut tracker: ChangeTracker,
+        cache: &SqliteCache,
+        link_manager: &Mutex<LinkManager>,
+        on_change: F,
+    ) where
+        F: Fn(&[EntityChange]),
+    {
+        while let Ok(first) = receiver.recv() {
+            let mut events = vec![first];
+            loop {
+                match receiver.recv_timeout(DEBOUNCE) {
+                    Ok(event) => events.push(event),
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+
+            let mut entity_changes = Vec::new();
+            let mut links_changed = false;
+            for event in events {
+                if matches!(event.kind, EventKind::Access(_)) {
+                    continue;
+                }
+                for path in &event.paths {
+                    match tracker.check(path) {
+                        Some(FileChange::Entity(change)) => entity_changes.push(change),
+                        Some(FileChange::Link) => links_changed = true,
+                        None => {}
+                    }
+                }
+            }
+            if entity_changes.is_empty() && !links_changed {
+                continue;
+            }
+
+            // The views refetch on the event, so a failed refresh is reported but not fatal
+            if let Err(e) = cache.refresh() {
+                eprintln!("Failed to refresh cache after file change: {}", e);
+            }
+            if links_changed {
+                let reloaded = link_manager.lock().map(|mut links| links.reload());
+                if let Ok(Err(e)) = reloaded {
+                    eprintln!("Failed to reload links after file change: {}", e);
+                }
+            }
+            if !entity_changes.is_empty() {
+                on_change(&entity_changes);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{EntityMetadata, Requirement};
+    use crate::storage::RonStorage;
+    use tempfile::TempDir;
+
+    fn requirement() -> Requirement {
+        Requirement {
+            metadata: EntityMetadata::new(EntityType::Requirement),
+            name: "REQ-001".to_string(),
+            description: "Description".to_string(),
+            notes: None,
+            requirement_type: "System Requirement".to_string(),
+            rationale: None,
+            source: None,
+            verification_method: None,
+        }
+    }
+
+    #[test]
+    fn test_tracker_detects_changes_and_skips_touches() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = RonStorage::new(temp_dir.path()).unwrap();
+        let mut req = requirement();
+        let id = req.metadata.id;
+        storage.write(&req).unwrap();
+
+        let mut tracker = ChangeTracker::new(temp_dir.path().to_path_buf()).unwrap();
+        let path = storage.get_entity_path(&EntityType::Requirement, &id);
+
+        // Unchanged content, e.g. a save without edits
+        assert_eq!(tracker.check(&path), None);
+
+        req.description = "Edited".to_string();
+        storage.write(&req).unwrap();
+        let expected = |kind| {
+            Some(FileChange::Entity(EntityChange {
+                entity_type: EntityType::Requirement,
+                entity_id: id,
+                kind,
+            }))
+        };
+        assert_eq!(tracker.check(&path), expected(FileChangeKind::Modified));
+
+        storage.delete(&EntityType::Requirement, &id).unwrap();
+        assert_eq!(tracker.check(&path), expected(FileChangeKind::Deleted));
+        assert_eq!(tracker.check(&path), None);
+
+        storage.write(&req).unwrap();
+        assert_eq!(tracker.check(&path), expected(FileChangeKind::Created));
+    }
+
+    #[test]
+    fn test_tracker_ignores_other_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut tracker = ChangeTracker::new(temp_dir.path().to_path_buf()).unwrap();
+
+        let dir = temp_dir.path().join("entities").join("requirements");
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["notes.txt", "not-a-uuid.ron", ".draft.ron.tmp"] {
+            fs::write(dir.join(name), "x").unwrap();
+            assert_eq!(tracker.check(&dir.join(name)), None);
+        }
+
+        let links = temp_dir.path().join("links");
+        fs::create_dir_all(&links).unwrap();
+        let link_path = links.join(format!("{}.ron", Uuid::new_v4()));
+        fs::write(&link_path, "x").unwrap();
+        assert_eq!(tracker.check(&link_path), Some(FileChange::Link));
+    }
+
+    #[test]
+    fn test_watcher_reports_external_edit() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = RonStorage::new(temp_dir.path()).unwrap();
+        let cache = Arc::new(SqliteCache::open(temp_dir.path()).unwrap());
+        let link_manager = Arc::new(Mutex::new(LinkManager::new()));
+
+        let (sender, receiver) = mpsc::channel();
+        let _watcher = FileWatcher::start(temp_dir.path().to_path_buf(), cache, link_manager, move |changes| {
+            sender.send(changes.to_vec()).unwrap();
+        })
+        .unwrap();
+
+        let req = requirement();
+        storage.write(&req).unwrap();
+
+        let changes = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].entity_id, req.metadata.id);
+        assert_eq!(changes[0].kind, FileChangeKind::Created);
+    }
+}
@@ -0,0 +1,294 @@
+use std::sync::{Arc, Mutex, RwLock};
+use uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use crate::core::{EdtResult, EdtError, EntityManager, LinkManager};
+use crate::models::{EntityType, LinkType, ProjectConfig};
+
+/// Kind of traceability gap found by the health check
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FindingKind {
+    HazardWithoutRisk,
+    RiskWithoutControl,
+    RiskWithoutVerification,
+    RequirementWithoutVerification,
+    RequirementWithoutDesign,
+}
+
+/// A single traceability gap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthFinding {
+    pub kind: FindingKind,
+    pub entity_id: Uuid,
+    pub entity_type: EntityType,
+    pub entity_name: String,
+    pub message: String,
+}
+
+/// Result of a project health check
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    pub findings: Vec<HealthFinding>,
+    pub entities_checked: usize,
+}
+
+/// Evaluates the `warn_*` flags in `ProjectConfig` against the link graph
+pub struct HealthChecker {
+    entity_manager: Arc<EntityManager>,
+    link_manager: Arc<Mutex<LinkManager>>,
+    config: Arc<RwLock<ProjectConfig>>,
+}
+
+impl HealthChecker {
+    pub fn new(
+        entity_manager: Arc<EntityManager>,
+        link_manager: Arc<Mutex<LinkManager>>,
+        config: Arc<RwLock<ProjectConfig>>,
+    ) -> Self {
+        Self {
+            entity_manager,
+            link_manager,
+            config,
+        }
+    }
+
+    /// Run every enabled check and collect the findings
+    pub fn check(&self) -> EdtResult<HealthReport> {
+        let config = self
+            .config
+            .read()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to read project config: {}", e)))?
+            .clone();
+        let link_manager = self.link_manager.lock()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to lock link manager: {}", e)))?;
+
+        let mut findings = Vec::new();
+        let mut entities_checked = 0;
+
+        // True if any link joins the entity to an entity of the given type
+        let linked_to_type = |id: &Uuid, other_type: &EntityType| {
+            link_manager.get_all_links(id).into_iter().any(|link| {
+                (link.from_entity_id == *id && link.to_entity_type == *other_type)
+                    || (link.to_entity_id == *id && link.from_entity_type == *other_type)
+            })
+        };
+        // True if an incoming link of the given type exists
+        let has_incoming = |id: &Uuid, link_type: &LinkType| {
+            link_manager
+                .get_links_to(id)
+                .into_iter()
+                .any(|link| link.link_type == *link_type)
+        };
+
+        for id in self.entity_manager.list_hazard_ids()? {
+            entities_checked += 1;
+            if config.warn_hazard_without_risk && !linked_to_type(&id, &EntityType::Risk) {
+                let hazard = self.entity_manager.get_hazard(&id)?;
+                findings.push(HealthFinding {
+                    kind: FindingKind::HazardWithoutRisk,
+                    entity_id: id,
+                    entity_type: EntityType::Hazard,
+                    message: format!("Hazard '{}' has no linked risk", hazard.name),
+                    entity_name: hazard.name,
+                });
+            }
+        }
+
+        for id in self.entity_manager.list_risk_ids()? {
+            entities_checked += 1;
+            let missing_control =
+                config.warn_risk_without_control && !has_incoming(&id, &LinkType::Mitigates);
+            let missing_verification =
+                config.warn_risk_without_verification && !has_incoming(&id, &LinkType::Verifies);
+            if !missing_control && !missing_verification {
+                continue;
+            }
+
+            let risk = self.entity_manager.get_risk(&id)?;
+            if missing_control {
+                findings.push(HealthFinding {
+                    kind: FindingKind::RiskWithoutControl,
+                    entity_id: id,
+                    entity_type: EntityType::Risk,
+                    entity_name: risk.name.clone(),
+                    message: format!("Risk '{}' has no mitigating control", risk.name),
+                });
+            }
+            if missing_verification {
+                findings.push(HealthFinding {
+                    kind: FindingKind::RiskWithoutVerification,
+                    entity_id: id,
+                    entity_type: EntityType::Risk,
+                    entity_name: risk.name.clone(),
+                    message: format!("Risk '{}' is not verified", risk.name),
+                });
+            }
+        }
+
+        for id in self.entity_manager.list_requirement_ids()? {
+            entities_checked += 1;
+            let missing_verification = config.warn_requirement_without_verification
+                && !has_incoming(&id, &LinkType::Verifies);
+            let missing_design = config.warn_requirement_without_design
+                && !has_incoming(&id, &LinkType::Satisfies);
+            if !missing_verification && !missing_design {
+                continue;
+            }
+
+            let requirement = self.entity_manager.get_requirement(&id)?;
+            if missing_verification {
+                findings.push(HealthFinding {
+                    kind: FindingKind::RequirementWithoutVerification,
+                    entity_id: id,
+                    entity_type: EntityType::Requirement,
+                    entity_name: requirement.name.clone(),
+                    message: format!("Requirement '{}' is not verified", requirement.name),
+                });
+            }
+            if missing_design {
+                findings.push(HealthFinding {
+                    kind: FindingKind::RequirementWithoutDesign,
+                    entity_id: id,
+                    entity_type: EntityType::Requirement,
+                    entity_name: requirement.name.clone(),
+                    message: format!(
+                        "Requirement '{}' is not satisfied by any design element",
+                        requirement.name
+                    ),
+                });
+            }
+        }
+
+        Ok(HealthReport {
+            findings,
+            entities_checked,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::RonStorage;
+    use tempfile::TempDir;
+
+    fn create_test_checker() -> (TempDir, HealthChecker) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(RonStorage::new(temp_dir.path()).unwrap());
+        let link_manager = Arc::new(Mutex::new(LinkManager::new()));
+        let config = Arc::new(RwLock::new(ProjectConfig::default()));
+        let entity_manager = Arc::new(EntityManager::new(
+            storage,
+            Arc::clone(&link_manager),
+            Arc::clone(&config),
+        ).unwrap());
+        let checker = HealthChecker::new(entity_manager, link_manager, config);
+        (temp_dir, checker)
+    }
+
+    fn kinds(report: &HealthReport, id: &Uuid) -> Vec<FindingKind> {
+        report
+            .findings
+            .iter()
+            .filter(|f| f.entity_id == *id)
+            .map(|f| f.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_project_is_healthy() {
+        let (_temp, checker) = create_test_checker();
+
+        let report = checker.check().unwrap();
+        assert!(report.findings.is_empty());
+        assert_eq!(report.entities_checked, 0);
+    }
+
+    #[test]
+    fn test_unlinked_entities_reported() {
+        let (_temp, checker) = create_test_checker();
+        let em = &checker.entity_manager;
+
+        let hazard = em
+            .create_hazard("Shock".to_string(), "Description".to_string(), vec![], vec![])
+            .unwrap();
+        let risk = em
+            .create_risk("RISK-001".to_string(), "Test".to_string(), "Safety Risk".to_string(), 2, 2)
+            .unwrap();
+        let requirement = em
+            .create_requirement("REQ-001".to_string(), "Test".to_string(), "User Requirement".to_string())
+            .unwrap();
+
+        let report = checker.check().unwrap();
+        assert_eq!(report.entities_checked, 3);
+        assert_eq!(kinds(&report, &hazard.metadata.id), vec![FindingKind::HazardWithoutRisk]);
+        assert_eq!(
+            kinds(&report, &risk.metadata.id),
+            vec![FindingKind::RiskWithoutControl, FindingKind::RiskWithoutVerification]
+        );
+        assert_eq!(
+            kinds(&report, &requirement.metadata.id),
+            vec![FindingKind::RequirementWithoutVerification, FindingKind::RequirementWithoutDesign]
+        );
+    }
+
+    #[test]
+    fn test_linked_entities_not_reported() {
+        let (_temp, checker) = create_test_checker();
+        let em = &checker.entity_manager;
+
+        let hazard = em
+            .create_hazard("Shock".to_string(), "Description".to_string(), vec![], vec![])
+            .unwrap();
+        let risk = em
+            .create_risk("RISK-001".to_string(), "Test".to_string(), "Safety Risk".to_string(), 2, 2)
+            .unwrap();
+        let control = em
+            .create_risk_control("RC-001".to_string(), "Test".to_string(), "Design Control".to_string())
+            .unwrap();
+        let requirement = em
+            .create_requirement("REQ-001".to_string(), "Test".to_string(), "User Requirement".to_string())
+            .unwrap();
+        let component = em
+            .create_component("Housing".to_string(), "Description".to_string(), "A".to_string())
+            .unwrap();
+        let verification_id = Uuid::new_v4();
+
+        {
+            let mut lm = checker.link_manager.lock().unwrap();
+            let mut link = |from: Uuid, from_type, to: Uuid, to_type, link_type| {
+                lm.create_link(from, from_type, to, to_type, link_type, None).unwrap();
+            };
+            link(hazard.metadata.id, EntityType::Hazard, risk.metadata.id, EntityType::Risk, LinkType::Hazardous);
+            link(control.metadata.id, EntityType::RiskControl, risk.metadata.id, EntityType::Risk, LinkType::Mitigates);
+            link(verification_id, EntityType::Verification, risk.metadata.id, EntityType::Risk, LinkType::Verifies);
+            link(verification_id, EntityType::Verification, requirement.metadata.id, EntityType::Requirement, LinkType::Verifies);
+            link(component.metadata.id, EntityType::Component, requirement.metadata.id, EntityType::Requirement, LinkType::Satisfies);
+        }
+
+        let report = checker.check().unwrap();
+        assert!(report.findings.is_empty());
+    }
+
+    #[test]
+    fn test_disabled_warnings_skipped() {
+        let (_temp, checker) = create_test_checker();
+        {
+            let mut config = checker.config.write().unwrap();
+            config.warn_hazard_without_risk = false;
+            config.warn_requirement_without_design = false;
+        }
+
+        checker
+            .entity_manager
+            .create_hazard("Shock".to_string(), "Description".to_string(), vec![], vec![])
+            .unwrap();
+        checker
+            .entity_manager
+            .create_requirement("REQ-001".to_string(), "Test".to_string(), "User Requirement".to_string())
+            .unwrap();
+
+        let report = checker.check().unwrap();
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].kind, FindingKind::RequirementWithoutVerification);
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::fs;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::core::{EdtResult, EdtError};
+use crate::core::managers::config_checks::read_config;
+use crate::models::{EntityMetadata, EntityRef, EntityType, ProjectConfig};
+use crate::storage::RonStorage;
+
+#[derive(Deserialize)]
+struct HumanIdProbe {
+    metadata: MetadataProbe,
+}
+
+#[derive(Deserialize)]
+struct MetadataProbe {
+    #[serde(default)]
+    human_id: Option<String>,
+}
+
+/// Assigns human-readable IDs such as `REQ-0001` and resolves them to UUIDs
+///
+/// The index is built from the entity files on first use and rebuilt when an ID is
+/// not found, which picks up newly written entities; `invalidate` forces a rebuild
+/// after external edits.
+pub struct IdRegistry {
+    storage: Arc<RonStorage>,
+    config: Arc<RwLock<ProjectConfig>>,
+    /// Upper-cased human ID to entity
+    index: Mutex<Option<HashMap<String, EntityRef>>>,
+    /// Highest number handed out per upper-cased prefix, including entities not yet written
+    issued: Mutex<HashMap<String, u32>>,
+}
+
+impl IdRegistry {
+    pub fn new(storage: Arc<RonStorage>, config: Arc<RwLock<ProjectConfig>>) -> Self {
+        Self {
+            storage,
+            config,
+            index: Mutex::new(None),
+            issued: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Metadata for a new entity, with the next free human-readable ID for its type
+    ///
+    /// The ID only enters the index once the entity is written. Numbers are not handed
+    /// out twice, so a create that fails leaves a gap rather than an ID that resolves
+    /// to nothing.
+    pub fn new_metadata(&self, entity_type: EntityType) -> EdtResult<EntityMetadata> {
+        let prefix = read_config(&self.config)?.id_prefix(&entity_type);
+        let mut metadata = EntityMetadata::new(entity_type);
+
+        let mut guard = self.lock_index()?;
+        let index = self.loaded_index(&mut guard)?;
+        let mut issued = self
+            .issued
+            .lock()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to lock ID registry: {}", e)))?;
+        let last_issued = issued.entry(prefix.to_uppercase()).or_insert(0);
+
+        let next = index
+            .keys()
+            .filter_map(|key| Self::sequence_number(key, &prefix))
+            .chain([*last_issued])
+            .max()
+            .unwrap_or(0)
+            + 1;
+        *last_issued = next;
+
+        metadata.human_id = Some(format!("{}-{:04}", prefix, next));
+        Ok(metadata)
+    }
+
+    /// Parse a UUID, or resolve a human-readable ID to its entity's UUID
+    pub fn resolve(&self, id: &str) -> EdtResult<Uuid> {
+        if let Ok(uuid) = Uuid::parse_str(id.trim()) {
+            return Ok(uuid);
+        }
+        self.lookup(id)?
+            .map(|entity| entity.entity_id)
+            .ok_or_else(|| EdtError::EntityNotFound(id.to_string()))
+    }
+
+    /// Find the entity for a UUID or human-readable ID (case-insensitive)
+    pub fn lookup(&self, id: &str) -> EdtResult<Option<EntityRef>> {
+        let id = id.trim();
+        if let Ok(entity_id) = Uuid::parse_str(id) {
+            return Ok(EntityType::all()
+                .iter()
+                .find(|entity_type| self.storage.exists(entity_type, &entity_id))
+                .map(|entity_type| EntityRef {
+                    entity_id,
+                    entity_type: entity_type.clone(),
+                }));
+        }
+
+        let key = id.to_uppercase();
+        let mut guard = self.lock_index()?;
+        if let Some(entity) = self.loaded_index(&mut guard)?.get(&key) {
+            return Ok(Some(entity.clone()));
+        }
+
+        // The ID may have arrived with files created outside the app
+        let index = guard.insert(self.build_index()?);
+        Ok(index.get(&key).cloned())
+    }
+
+    /// Drop the index so it is rebuilt from the entity files on next use
+    pub fn invalidate(&self) -> EdtResult<()> {
+        *self.lock_index()? = None;
+        Ok(())
+    }
+
+    fn lock_index(&self) -> EdtResult<MutexGuard<'_, Option<HashMap<String, EntityRef>>>> {
+        self.index
+            .lock()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to lock ID registry: {}", e)))
+    }
+
+    fn loaded_index<'a>(
+        &self,
+        guard: &'a mut Option<HashMap<String, EntityRef>>,
+    ) -> EdtResult<&'a mut HashMap<String, EntityRef>> {
+        if guard.is_none() {
+            *guard = Some(self.build_index()?);
+        }
+        Ok(guard.get_or_insert_with(HashMap::new))
+    }
+
+    fn build_index(&self) -> EdtResult<HashMap<String, EntityRef>> {
+        let mut index = HashMap::new();
+        for entity_type in EntityType::all() {
+            for entity_id in self.storage.list_ids(entity_type)? {
+                let path = self.storage.get_entity_path(entity_type, &entity_id);
+                // Unreadable files are reported by the health check, not here
+                let human_id = fs::read_to_string(path)
+                    .ok()
+                    .and_then(|content| ron::from_str::<HumanIdProbe>(&content).ok())
+                    .and_then(|probe| probe.metadata.human_id);
+                if let Some(human_id) = human_id {
+                    index.insert(
+                        human_id.to_uppercase(),
+                        EntityRef {
+                            entity_id,
+                            entity_type: entity_type.clone(),
+                        },
+                    );
+                }
+            }
+        }
+        Ok(index)
+    }
+
+    /// Number of an upper-cased ID with the given prefix, e.g. 42 for `RSK-0042`
+    fn sequence_number(key: &str, prefix: &str) -> Option<u32> {
+        let (key_prefix, number) = key.rsplit_once('-')?;
+        if !key_prefix.eq_ignore_ascii_case(prefix) {
+            return None;
+        }
+        number.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Requirement;
+    use tempfile::TempDir;
+
+    fn create_test_registry() -> (TempDir, Arc<RonStorage>, IdRegistry) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(RonStorage::new(temp_dir.path()).unwrap());
+        let config = Arc::new(RwLock::new(ProjectConfig::default()));
+        let registry = IdRegistry::new(Arc::clone(&storage), config);
+        (temp_dir, storage, registry)
+    }
+
+    fn requirement(metadata: EntityMetadata) -> Requirement {
+        Requirement {
+            metadata,
+            name: "Requirement".to_string(),
+            description: "Description".to_string(),
+            notes: None,
+            requirement_type: "System Requirement".to_string(),
+            rationale: None,
+            source: None,
+            verification_method: None,
+        }
+    }
+
+    #[test]
+    fn test_ids_are_sequential_per_type() {
+        let (_temp, _storage, registry) = create_test_registry();
+
+        let first = registry.new_metadata(EntityType::Requirement).unwrap();
+        let second = registry.new_metadata(EntityType::Requirement).unwrap();
+        let risk = registry.new_metadata(EntityType::Risk).unwrap();
+
+        assert_eq!(first.human_id.as_deref(), Some("REQ-0001"));
+        assert_eq!(second.human_id.as_deref(), Some("REQ-0002"));
+        assert_eq!(risk.human_id.as_deref(), Some("RSK-0001"));
+    }
+
+    #[test]
+    fn test_numbering_continues_from_existing_files() {
+        let (_temp, storage, registry) = create_test_registry();
+        let mut metadata = EntityMetadata::new(EntityType::Requirement);
+        metadata.human_id = Some("REQ-0041".to_string());
+        storage.write(&requirement(metadata)).unwrap();
+
+        let next = registry.new_metadata(EntityType::Requirement).unwrap();
+        assert_eq!(next.human_id.as_deref(), Some("REQ-0042"));
+    }
+
+    #[test]
+    fn test_resolve_human_id_and_uuid() {
+        let (_temp, storage, registry) = create_test_registry();
+        let metadata = registry.new_metadata(EntityType::Requirement).unwrap();
+        let id = metadata.id;
+        storage.write(&requirement(metadata)).unwrap();
+
+        assert_eq!(registry.resolve("REQ-0001").unwrap(), id);
+        assert_eq!(registry.resolve("req-0001").unwrap(), id);
+        assert_eq!(registry.resolve(&id.to_string()).unwrap(), id);
+        assert!(matches!(registry.resolve("REQ-0099"), Err(EdtError::EntityNotFound(_))));
+
+        let entity = registry.lookup(&id.to_string()).unwrap().unwrap();
+        assert_eq!(entity.entity_type, EntityType::Requirement);
+    }
+
+    #[test]
+    fn test_unwritten_ids_do_not_resolve_and_are_not_reused() {
+        let (_temp, storage, registry) = create_test_registry();
+        // Assigned, but the create failed before the file was written
+        registry.new_metadata(EntityType::Requirement).unwrap();
+        assert!(registry.lookup("REQ-0001").unwrap().is_none());
+
+        let metadata = registry.new_metadata(EntityType::Requirement).unwrap();
+        assert_eq!(metadata.human_id.as_deref(), Some("REQ-0002"));
+        let id = metadata.id;
+        storage.write(&requirement(metadata)).unwrap();
+        assert_eq!(registry.resolve("REQ-0002").unwrap(), id);
+    }
+
+    #[test]
+    fn test_resolve_finds_files_added_externally() {
+        let (_temp, storage, registry) = create_test_registry();
+        registry.new_metadata(EntityType::Requirement).unwrap();
+
+        // Written directly, as after a git pull
+        let mut metadata = EntityMetadata::new(EntityType::Requirement);
+        metadata.human_id = Some("REQ-0007".to_string());
+        let id = metadata.id;
+        storage.write(&requirement(metadata)).unwrap();
+
+        assert_eq!(registry.resolve("REQ-0007").unwrap(), id);
+    }
+}
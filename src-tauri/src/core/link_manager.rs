@@ -1,10 +1,12 @@
 use std::sync::Arc;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use uuid::Uuid;
 use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::EdgeRef;
 use petgraph::Direction;
-use crate::core::{EdtResult, EdtError};
+use crate::core::{AuditAction, AuditLog, EdtResult, EdtError, LinkRules};
 use crate::models::{Link, LinkType, LinkMetadata, EntityType};
+use crate::storage::RonStorage;
 
 /// Manages entity relationships and links
 pub struct LinkManager {
@@ -12,8 +14,14 @@ pub struct LinkManager {
     graph: DiGraph<Uuid, LinkType>,
     /// Map from UUID to NodeIndex for quick lookups
     node_map: HashMap<Uuid, NodeIndex>,
-    /// All links (in a real implementation, this would use storage)
+    /// All links, keyed by link ID
     links: HashMap<Uuid, Link>,
+    /// Backing storage; links are kept in memory only when this is `None`
+    storage: Option<Arc<RonStorage>>,
+    /// Allowed (from type, link type, to type) combinations
+    rules: LinkRules,
+    /// Where link changes are recorded, if anywhere
+    audit: Option<Arc<AuditLog>>,
 }
 
 impl LinkManager {
@@ -22,9 +30,53 @@ impl LinkManager {
             graph: DiGraph::new(),
             node_map: HashMap::new(),
             links: HashMap::new(),
+            storage: None,
+            rules: LinkRules::default(),
+            audit: None,
         }
     }
 
+    /// Create a LinkManager backed by storage, loading all persisted links into the graph
+    pub fn with_storage(storage: Arc<RonStorage>) -> EdtResult<Self> {
+        let mut manager = Self::new();
+
+        for link_id in storage.list_link_ids()? {
+            let link = storage.read_link(&link_id)?;
+            manager.insert_link(link);
+        }
+
+        manager.storage = Some(storage);
+        Ok(manager)
+    }
+
+    /// Rebuild the graph from storage, discarding in-memory changes
+    ///
+    /// Used after a storage transaction is rolled back. Managers without storage are left as-is.
+    pub fn reload(&mut self) -> EdtResult<()> {
+        let Some(storage) = self.storage.clone() else {
+            return Ok(());
+        };
+
+        self.graph = DiGraph::new();
+        self.node_map.clear();
+        self.links.clear();
+        for link_id in storage.list_link_ids()? {
+            let link = storage.read_link(&link_id)?;
+            self.insert_link(link);
+        }
+        Ok(())
+    }
+
+    /// Replace the link rules used to validate new links
+    pub fn set_link_rules(&mut self, rules: LinkRules) {
+        self.rules = rules;
+    }
+
+    /// Record link creates and deletes in the audit log
+    pub fn set_audit_log(&mut self, audit: Arc<AuditLog>) {
+        self.audit = Some(audit);
+    }
+
     /// Get or create a node in the graph for an entity
     fn get_or_create_node(&mut self, entity_id: Uuid) -> NodeIndex {
         if let Some(&node_idx) = self.node_map.get(&entity_id) {
@@ -36,22 +88,59 @@ impl LinkManager {
         node_idx
     }
 
-    /// Check if creating a link would create a cycle
-    fn would_create_cycle(&self, from: Uuid, to: Uuid) -> bool {
-        // Get node indices
-        let from_idx = match self.node_map.get(&from) {
-            Some(&idx) => idx,
-            None => return false, // New node, can't create cycle
-        };
+    /// Find the path that a new `from -> to` link of the given type would close into a cycle.
+    ///
+    /// Only edges of the same hierarchy are followed, so e.g. a `Related` loop never blocks a
+    /// `Derives` link. Inverse types are read in one direction first: `A PartOf B` counts as
+    /// `B Contains A`, and `Child` as the reverse of `Parent`. Returns the cycle as entity IDs
+    /// in that direction, starting and ending at the same end of the new link.
+    fn find_cycle_path(&self, from: Uuid, to: Uuid, link_type: &LinkType) -> Option<Vec<Uuid>> {
+        let (canonical, reversed) = link_type.canonical();
+        let (from, to) = if reversed { (to, from) } else { (from, to) };
+
+        // New nodes can't be part of a cycle
+        let from_idx = *self.node_map.get(&from)?;
+        let to_idx = *self.node_map.get(&to)?;
+
+        // Breadth-first search from 'to' back to 'from' along edges of this hierarchy
+        let mut predecessors: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut queue = VecDeque::from([to_idx]);
+        let mut visited = HashSet::from([to_idx]);
+
+        while let Some(current) = queue.pop_front() {
+            if current == from_idx {
+                let mut path = vec![self.graph[current]];
+                let mut node = current;
+                while let Some(&previous) = predecessors.get(&node) {
+                    path.push(self.graph[previous]);
+                    node = previous;
+                }
+                path.reverse();
+                path.insert(0, from);
+                return Some(path);
+            }
 
-        let to_idx = match self.node_map.get(&to) {
-            Some(&idx) => idx,
-            None => return false, // New node, can't create cycle
-        };
+            // Forward edges are followed as stored, inverse edges against their direction
+            let forward = self
+                .graph
+                .edges_directed(current, Direction::Outgoing)
+                .filter(|edge| edge.weight().canonical() == (canonical.clone(), false))
+                .map(|edge| edge.target());
+            let inverse = self
+                .graph
+                .edges_directed(current, Direction::Incoming)
+                .filter(|edge| edge.weight().canonical() == (canonical.clone(), true))
+                .map(|edge| edge.source());
+
+            for next in forward.chain(inverse) {
+                if visited.insert(next) {
+                    predecessors.insert(next, current);
+                    queue.push_back(next);
+                }
+            }
+        }
 
-        // Check if there's already a path from 'to' to 'from'
-        // If so, adding from->to would create a cycle
-        petgraph::algo::has_path_connecting(&self.graph, to_idx, from_idx, None)
+        None
     }
 
     /// Create a new link between entities
@@ -71,11 +160,22 @@ impl LinkManager {
             ));
         }
 
-        // Check for cycles (for directional links)
-        if self.would_create_cycle(from_entity_id, to_entity_id) {
-            return Err(EdtError::ValidationError(
-                "Link would create a cycle".to_string()
-            ));
+        // Validate: link type must be allowed between the two entity types
+        self.rules.validate(&from_entity_type, &link_type, &to_entity_type)?;
+
+        // Check for cycles (hierarchical link types only)
+        if link_type.is_hierarchical() {
+            if let Some(path) = self.find_cycle_path(from_entity_id, to_entity_id, &link_type) {
+                let path = path
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                return Err(EdtError::ValidationError(format!(
+                    "Link would create a {:?} cycle: {}",
+                    link_type, path
+                )));
+            }
         }
 
         // Create the link
@@ -84,21 +184,32 @@ impl LinkManager {
             from_entity_type,
             to_entity_id,
             to_entity_type,
-            link_type.clone(),
+            link_type,
             metadata,
         );
 
-        // Add to graph
-        let from_node = self.get_or_create_node(from_entity_id);
-        let to_node = self.get_or_create_node(to_entity_id);
-        self.graph.add_edge(from_node, to_node, link_type);
+        // Persist before touching the in-memory graph so a failed write leaves no trace
+        if let Some(storage) = &self.storage {
+            storage.write_link(&link)?;
+        }
 
-        // Store link
-        self.links.insert(link.id, link.clone());
+        self.insert_link(link.clone());
+
+        if let Some(audit) = &self.audit {
+            audit.record_link(AuditAction::Create, &link)?;
+        }
 
         Ok(link)
     }
 
+    /// Add a link to the graph and link map without validation
+    fn insert_link(&mut self, link: Link) {
+        let from_node = self.get_or_create_node(link.from_entity_id);
+        let to_node = self.get_or_create_node(link.to_entity_id);
+        self.graph.add_edge(from_node, to_node, link.link_type.clone());
+        self.links.insert(link.id, link);
+    }
+
     /// Get a link by ID
     pub fn get_link(&self, link_id: &Uuid) -> Option<&Link> {
         self.links.get(link_id)
@@ -132,21 +243,43 @@ impl LinkManager {
 
     /// Delete a link
     pub fn delete_link(&mut self, link_id: &Uuid) -> EdtResult<()> {
-        if let Some(link) = self.links.remove(link_id) {
-            // Remove from graph
-            if let (Some(&from_node), Some(&to_node)) = (
-                self.node_map.get(&link.from_entity_id),
-                self.node_map.get(&link.to_entity_id),
-            ) {
-                // Find and remove the edge
-                if let Some(edge) = self.graph.find_edge(from_node, to_node) {
-                    self.graph.remove_edge(edge);
-                }
+        let link = self.remove_link(link_id)?;
+        if let Some(audit) = &self.audit {
+            audit.record_link(AuditAction::Delete, &link)?;
+        }
+        Ok(())
+    }
+
+    /// Delete a link without recording it, for callers that audit the change once it is committed
+    pub(crate) fn remove_link(&mut self, link_id: &Uuid) -> EdtResult<Link> {
+        let link = match self.links.get(link_id) {
+            Some(link) => link.clone(),
+            None => return Err(EdtError::EntityNotFound(format!("Link not found: {}", link_id))),
+        };
+
+        if let Some(storage) = &self.storage {
+            storage.delete_link(link_id)?;
+        }
+
+        self.links.remove(link_id);
+
+        // Remove from graph
+        if let (Some(&from_node), Some(&to_node)) = (
+            self.node_map.get(&link.from_entity_id),
+            self.node_map.get(&link.to_entity_id),
+        ) {
+            // Find and remove the edge of the same type (two entities may share several links)
+            let edge = self
+                .graph
+                .edges_connecting(from_node, to_node)
+                .find(|edge| *edge.weight() == link.link_type)
+                .map(|edge| edge.id());
+            if let Some(edge) = edge {
+                self.graph.remove_edge(edge);
             }
-            Ok(())
-        } else {
-            Err(EdtError::EntityNotFound(format!("Link not found: {}", link_id)))
         }
+
+        Ok(link)
     }
 
     /// Get impact analysis - all entities reachable from this entity
@@ -186,6 +319,7 @@ impl LinkManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::TempDir;
 
     #[test]
     fn test_create_link() {
@@ -261,6 +395,137 @@ mod tests {
         assert!(matches!(result.unwrap_err(), EdtError::ValidationError(_)));
     }
 
+    #[test]
+    fn test_cycle_detection_across_inverse_link_types() {
+        let mut manager = LinkManager::new();
+        let top = Uuid::new_v4();
+        let sub = Uuid::new_v4();
+
+        // `sub PartOf top` is `top Contains sub`, so `sub Contains top` closes a loop
+        manager
+            .create_link(sub, EntityType::Assembly, top, EntityType::Assembly, LinkType::PartOf, None)
+            .unwrap();
+        let result = manager.create_link(sub, EntityType::Assembly, top, EntityType::Assembly, LinkType::Contains, None);
+        assert!(matches!(result, Err(EdtError::ValidationError(_))));
+
+        // Mixed Parent/Child chain: a Parent b, c Child b, then c Parent a closes a -> b -> c -> a
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+        manager
+            .create_link(a, EntityType::Task, b, EntityType::Task, LinkType::Parent, None)
+            .unwrap();
+        manager
+            .create_link(c, EntityType::Task, b, EntityType::Task, LinkType::Child, None)
+            .unwrap();
+        let result = manager.create_link(c, EntityType::Task, a, EntityType::Task, LinkType::Parent, None);
+        assert!(matches!(result, Err(EdtError::ValidationError(_))));
+        let result = manager.create_link(a, EntityType::Task, c, EntityType::Task, LinkType::Child, None);
+        assert!(matches!(result, Err(EdtError::ValidationError(_))));
+
+        // The same hierarchy read the same way round is not a cycle
+        manager
+            .create_link(a, EntityType::Task, c, EntityType::Task, LinkType::Parent, None)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_create_link_rejects_disallowed_link_type() {
+        let mut manager = LinkManager::new();
+
+        let result = manager.create_link(
+            Uuid::new_v4(),
+            EntityType::Task,
+            Uuid::new_v4(),
+            EntityType::Hazard,
+            LinkType::Mates,
+            None,
+        );
+
+        assert!(matches!(result.unwrap_err(), EdtError::InvalidLink(_, _)));
+        assert_eq!(manager.link_count(), 0);
+    }
+
+    #[test]
+    fn test_create_link_with_custom_rules() {
+        let mut manager = LinkManager::new();
+
+        let mut rules = LinkRules::empty();
+        rules.allow(EntityType::Task, LinkType::Mates, EntityType::Hazard);
+        manager.set_link_rules(rules);
+
+        let result = manager.create_link(
+            Uuid::new_v4(),
+            EntityType::Task,
+            Uuid::new_v4(),
+            EntityType::Hazard,
+            LinkType::Mates,
+            None,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_cycle_error_reports_path() {
+        let mut manager = LinkManager::new();
+
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+
+        manager
+            .create_link(a, EntityType::Requirement, b, EntityType::Requirement, LinkType::Derives, None)
+            .unwrap();
+        manager
+            .create_link(b, EntityType::Requirement, c, EntityType::Requirement, LinkType::Derives, None)
+            .unwrap();
+
+        let error = manager
+            .create_link(c, EntityType::Requirement, a, EntityType::Requirement, LinkType::Derives, None)
+            .unwrap_err();
+
+        let expected = format!("{} -> {} -> {} -> {}", c, a, b, c);
+        assert!(error.to_string().contains(&expected), "{}", error);
+    }
+
+    #[test]
+    fn test_associative_links_may_form_loops() {
+        let mut manager = LinkManager::new();
+
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+
+        manager
+            .create_link(a, EntityType::Task, b, EntityType::Requirement, LinkType::Related, None)
+            .unwrap();
+        let result = manager.create_link(b, EntityType::Requirement, a, EntityType::Task, LinkType::Related, None);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_cycle_check_scoped_to_link_type() {
+        let mut manager = LinkManager::new();
+
+        let parent = Uuid::new_v4();
+        let child = Uuid::new_v4();
+
+        // A Related loop back from child to parent must not block a Derives link
+        manager
+            .create_link(child, EntityType::Requirement, parent, EntityType::Requirement, LinkType::Related, None)
+            .unwrap();
+        let result = manager.create_link(
+            parent,
+            EntityType::Requirement,
+            child,
+            EntityType::Requirement,
+            LinkType::Derives,
+            None,
+        );
+
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_get_link() {
         let mut manager = LinkManager::new();
@@ -485,4 +750,111 @@ mod tests {
         assert!(link.metadata.is_some());
         assert_eq!(link.metadata.as_ref().unwrap().quantity, Some(5));
     }
+
+    #[test]
+    fn test_links_persist_across_reload() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(RonStorage::new(temp_dir.path()).unwrap());
+
+        let assembly_id = Uuid::new_v4();
+        let component_id = Uuid::new_v4();
+        let requirement_id = Uuid::new_v4();
+
+        let contains_id = {
+            let mut manager = LinkManager::with_storage(Arc::clone(&storage)).unwrap();
+            let contains = manager
+                .create_link(
+                    assembly_id,
+                    EntityType::Assembly,
+                    component_id,
+                    EntityType::Component,
+                    LinkType::Contains,
+                    Some(LinkMetadata { quantity: Some(2), notes: None }),
+                )
+                .unwrap();
+            manager
+                .create_link(
+                    component_id,
+                    EntityType::Component,
+                    requirement_id,
+                    EntityType::Requirement,
+                    LinkType::Satisfies,
+                    None,
+                )
+                .unwrap();
+            contains.id
+        };
+
+        let reloaded = LinkManager::with_storage(Arc::clone(&storage)).unwrap();
+        assert_eq!(reloaded.link_count(), 2);
+        assert_eq!(
+            reloaded.get_link(&contains_id).unwrap().metadata.as_ref().unwrap().quantity,
+            Some(2)
+        );
+
+        // The graph is rebuilt as well, so traversal works after a restart
+        let impacted = reloaded.get_impacted_entities(&assembly_id);
+        assert_eq!(impacted.len(), 3);
+        assert!(impacted.contains(&requirement_id));
+    }
+
+    #[test]
+    fn test_delete_link_removes_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(RonStorage::new(temp_dir.path()).unwrap());
+        let mut manager = LinkManager::with_storage(Arc::clone(&storage)).unwrap();
+
+        let link = manager
+            .create_link(
+                Uuid::new_v4(),
+                EntityType::Component,
+                Uuid::new_v4(),
+                EntityType::Requirement,
+                LinkType::Satisfies,
+                None,
+            )
+            .unwrap();
+        assert_eq!(storage.list_link_ids().unwrap().len(), 1);
+
+        manager.delete_link(&link.id).unwrap();
+        assert!(storage.list_link_ids().unwrap().is_empty());
+
+        let reloaded = LinkManager::with_storage(storage).unwrap();
+        assert_eq!(reloaded.link_count(), 0);
+    }
+
+    #[test]
+    fn test_delete_one_of_parallel_links() {
+        let mut manager = LinkManager::new();
+
+        let component_id = Uuid::new_v4();
+        let requirement_id = Uuid::new_v4();
+
+        let satisfies = manager
+            .create_link(
+                component_id,
+                EntityType::Component,
+                requirement_id,
+                EntityType::Requirement,
+                LinkType::Satisfies,
+                None,
+            )
+            .unwrap();
+        manager
+            .create_link(
+                component_id,
+                EntityType::Component,
+                requirement_id,
+                EntityType::Requirement,
+                LinkType::Related,
+                None,
+            )
+            .unwrap();
+
+        manager.delete_link(&satisfies.id).unwrap();
+
+        // The remaining Related link still connects the two entities in the graph
+        let impacted = manager.get_impacted_entities(&component_id);
+        assert!(impacted.contains(&requirement_id));
+    }
 }
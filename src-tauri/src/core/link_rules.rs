@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+use crate::core::{EdtResult, EdtError};
+use crate::models::{EntityType, LinkType, ProjectConfig};
+
+/// Table of allowed links, keyed by (from entity type, link type, to entity type)
+#[derive(Debug, Clone)]
+pub struct LinkRules {
+    allowed: HashSet<(EntityType, LinkType, EntityType)>,
+}
+
+impl LinkRules {
+    /// Create a rule table that allows nothing
+    pub fn empty() -> Self {
+        Self {
+            allowed: HashSet::new(),
+        }
+    }
+
+    /// Build the built-in rules extended with the project's configured rules
+    pub fn from_config(config: &ProjectConfig) -> Self {
+        let mut rules = Self::default();
+        for rule in &config.link_rules {
+            rules.allow(
+                rule.from_entity_type.clone(),
+                rule.link_type.clone(),
+                rule.to_entity_type.clone(),
+            );
+        }
+        rules
+    }
+
+    /// Allow a link type between two entity types
+    pub fn allow(&mut self, from: EntityType, link_type: LinkType, to: EntityType) {
+        self.allowed.insert((from, link_type, to));
+    }
+
+    /// Allow a link type from one entity type to several target types
+    fn allow_many(&mut self, from: EntityType, link_type: LinkType, to: &[EntityType]) {
+        for target in to {
+            self.allow(from.clone(), link_type.clone(), target.clone());
+        }
+    }
+
+    /// Check whether a link type is allowed between two entity types
+    pub fn is_allowed(&self, from: &EntityType, link_type: &LinkType, to: &EntityType) -> bool {
+        self.allowed
+            .contains(&(from.clone(), link_type.clone(), to.clone()))
+    }
+
+    /// Return `InvalidLink` if the link is not allowed
+    pub fn validate(&self, from: &EntityType, link_type: &LinkType, to: &EntityType) -> EdtResult<()> {
+        if self.is_allowed(from, link_type, to) {
+            Ok(())
+        } else {
+            Err(EdtError::InvalidLink(
+                format!("{:?}", from),
+                format!("{:?} via {:?}", to, link_type),
+            ))
+        }
+    }
+}
+
+impl Default for LinkRules {
+    /// Built-in rules, matching the documented direction of each `LinkType`
+    fn default() -> Self {
+        use EntityType::*;
+
+        let mut rules = Self::empty();
+
+        for from in EntityType::all() {
+            // Generic: anything can be related to anything
+            rules.allow_many(from.clone(), LinkType::Related, EntityType::all());
+
+            // Hierarchical: parent/child within the same entity type
+            rules.allow(from.clone(), LinkType::Parent, from.clone());
+            rules.allow(from.clone(), LinkType::Child, from.clone());
+        }
+
+        // Design
+        rules.allow_many(Assembly, LinkType::Contains, &[Component, Assembly]);
+        rules.allow_many(Component, LinkType::PartOf, &[Assembly]);
+        rules.allow_many(Assembly, LinkType::PartOf, &[Assembly]);
+        rules.allow_many(Component, LinkType::HasFeature, &[Feature]);
+        rules.allow_many(Feature, LinkType::Mates, &[Feature]);
+        rules.allow_many(Mate, LinkType::Mates, &[Feature]);
+        rules.allow_many(Feature, LinkType::UsedInStackup, &[Stackup]);
+
+        // Requirements
+        rules.allow_many(Requirement, LinkType::Derives, &[Requirement]);
+        for design in [Assembly, Component, Feature, Mate, Stackup] {
+            rules.allow_many(design, LinkType::Satisfies, &[Requirement]);
+        }
+        rules.allow_many(Verification, LinkType::Verifies, &[Requirement, Risk, RiskControl]);
+        rules.allow_many(Validation, LinkType::Verifies, &[Requirement]);
+
+        // Risk
+        rules.allow_many(RiskControl, LinkType::Mitigates, &[Risk]);
+        rules.allow_many(Component, LinkType::Hazardous, &[Risk, Hazard]);
+        rules.allow_many(Assembly, LinkType::Hazardous, &[Risk, Hazard]);
+        rules.allow_many(Hazard, LinkType::Hazardous, &[Risk]);
+
+        // BOM
+        rules.allow_many(Supplier, LinkType::Supplies, &[Component, Quote]);
+        rules.allow_many(Quote, LinkType::Quotes, &[Component]);
+
+        // Comments
+        rules.allow_many(Comment, LinkType::Comments, EntityType::all());
+        rules.allow_many(Comment, LinkType::Replies, &[Comment]);
+
+        // Manufacturing
+        rules.allow_many(Manufacturing, LinkType::Manufactures, &[Component, Assembly]);
+
+        rules
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::config::LinkRuleConfig;
+
+    #[test]
+    fn test_default_rules_allow_documented_links() {
+        let rules = LinkRules::default();
+
+        assert!(rules.is_allowed(&EntityType::Assembly, &LinkType::Contains, &EntityType::Component));
+        assert!(rules.is_allowed(&EntityType::Assembly, &LinkType::Contains, &EntityType::Assembly));
+        assert!(rules.is_allowed(&EntityType::Verification, &LinkType::Verifies, &EntityType::Requirement));
+        assert!(rules.is_allowed(&EntityType::Verification, &LinkType::Verifies, &EntityType::Risk));
+        assert!(rules.is_allowed(&EntityType::RiskControl, &LinkType::Mitigates, &EntityType::Risk));
+        assert!(rules.is_allowed(&EntityType::Quote, &LinkType::Quotes, &EntityType::Component));
+        assert!(rules.is_allowed(&EntityType::Comment, &LinkType::Comments, &EntityType::Hazard));
+        assert!(rules.is_allowed(&EntityType::Task, &LinkType::Related, &EntityType::Hazard));
+        assert!(rules.is_allowed(&EntityType::Task, &LinkType::Parent, &EntityType::Task));
+    }
+
+    #[test]
+    fn test_default_rules_reject_invalid_links() {
+        let rules = LinkRules::default();
+
+        assert!(!rules.is_allowed(&EntityType::Task, &LinkType::Mates, &EntityType::Hazard));
+        assert!(!rules.is_allowed(&EntityType::Component, &LinkType::Contains, &EntityType::Assembly));
+        assert!(!rules.is_allowed(&EntityType::Risk, &LinkType::Mitigates, &EntityType::RiskControl));
+        assert!(!rules.is_allowed(&EntityType::Task, &LinkType::Parent, &EntityType::Milestone));
+    }
+
+    #[test]
+    fn test_validate_returns_invalid_link() {
+        let rules = LinkRules::default();
+
+        let result = rules.validate(&EntityType::Task, &LinkType::Mates, &EntityType::Hazard);
+        assert!(matches!(result.unwrap_err(), EdtError::InvalidLink(_, _)));
+    }
+
+    #[test]
+    fn test_rules_extended_from_config() {
+        let mut config = ProjectConfig::default();
+        config.link_rules.push(LinkRuleConfig {
+            from_entity_type: EntityType::Manufacturing,
+            link_type: LinkType::Verifies,
+            to_entity_type: EntityType::Requirement,
+        });
+
+        let rules = LinkRules::from_config(&config);
+        assert!(rules.is_allowed(&EntityType::Manufacturing, &LinkType::Verifies, &EntityType::Requirement));
+        // Built-in rules are kept
+        assert!(rules.is_allowed(&EntityType::Assembly, &LinkType::Contains, &EntityType::Component));
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use uuid::Uuid;
+use crate::core::{EdtResult, EdtError};
+use crate::core::IdRegistry;
+use crate::storage::RonStorage;
+use crate::models::{Comment, EntityType};
+use chrono::Utc;
+
+/// Manages Comment entities
+///
+/// Only handles the comment files; attaching comments to entities and to each
+/// other is done by `EntityManager`, which owns the link graph.
+pub struct CommentManager {
+    storage: Arc<RonStorage>,
+    ids: Arc<IdRegistry>,
+}
+
+impl CommentManager {
+    pub fn new(storage: Arc<RonStorage>, ids: Arc<IdRegistry>) -> Self {
+        Self { storage, ids }
+    }
+
+    /// Create a new Comment
+    pub fn create_comment(&self, author: String, body: String) -> EdtResult<Comment> {
+        if author.trim().is_empty() {
+            return Err(EdtError::ValidationError("Comment author cannot be empty".to_string()));
+        }
+        if body.trim().is_empty() {
+            return Err(EdtError::ValidationError("Comment body cannot be empty".to_string()));
+        }
+
+        let metadata = self.ids.new_metadata(EntityType::Comment)?;
+
+        let comment = Comment {
+            metadata,
+            author,
+            body,
+            edited_at: None,
+            resolved: false,
+        };
+
+        self.storage.write(&comment)?;
+        Ok(comment)
+    }
+
+    /// Get a Comment by ID
+    pub fn get_comment(&self, id: &Uuid) -> EdtResult<Comment> {
+        self.storage.read(id)
+    }
+
+    /// Update a Comment
+    pub fn update_comment(&self, comment: Comment) -> EdtResult<Comment> {
+        if comment.body.trim().is_empty() {
+            return Err(EdtError::ValidationError("Comment body cannot be empty".to_string()));
+        }
+
+        let mut updated = comment;
+        updated.metadata.updated_at = Utc::now();
+
+        self.storage.write(&updated)?;
+        Ok(updated)
+    }
+
+    /// List all Comment IDs
+    pub fn list_comment_ids(&self) -> EdtResult<Vec<Uuid>> {
+        self.storage.list::<Comment>()
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::{RwLock, RwLockReadGuard};
+use crate::core::{EdtResult, EdtError};
+use crate::models::ProjectConfig;
+
+/// Acquire a read lock on the shared project configuration
+pub(crate) fn read_config(config: &RwLock<ProjectConfig>) -> EdtResult<RwLockReadGuard<'_, ProjectConfig>> {
+    config.read().map_err(|e| {
+        EdtError::ValidationError(format!("Failed to read project config: {}", e))
+    })
+}
+
+/// Check a configurable type name (e.g. `requirement_type`) against the allowed values
+pub(crate) fn validate_config_type(field: &str, value: &str, allowed: &[String]) -> EdtResult<()> {
+    if allowed.iter().any(|t| t == value) {
+        Ok(())
+    } else {
+        Err(EdtError::ValidationError(format!(
+            "Invalid {} '{}'; allowed values: {}",
+            field,
+            value,
+            allowed.join(", ")
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_config_type() {
+        let allowed = vec!["Safety Risk".to_string(), "Quality Risk".to_string()];
+
+        assert!(validate_config_type("risk_type", "Safety Risk", &allowed).is_ok());
+
+        let message = validate_config_type("risk_type", "Cost Risk", &allowed)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("'Cost Risk'"));
+        assert!(message.contains("Safety Risk, Quality Risk"));
+    }
+}
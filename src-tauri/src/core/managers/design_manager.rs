@@ -2,6 +2,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 use chrono::NaiveDate;
 use crate::core::{EdtResult, EdtError};
+use crate::core::IdRegistry;
 use crate::storage::RonStorage;
 use crate::models::{
     EntityType,
@@ -13,11 +14,12 @@ use chrono::Utc;
 /// Manages Design, BOM, and Tolerance entities
 pub struct DesignManager {
     storage: Arc<RonStorage>,
+    ids: Arc<IdRegistry>,
 }
 
 impl DesignManager {
-    pub fn new(storage: Arc<RonStorage>) -> Self {
-        Self { storage }
+    pub fn new(storage: Arc<RonStorage>, ids: Arc<IdRegistry>) -> Self {
+        Self { storage, ids }
     }
 
     // ============================================================================
@@ -35,7 +37,7 @@ impl DesignManager {
             return Err(EdtError::ValidationError("Assembly name cannot be empty".to_string()));
         }
 
-        let metadata = crate::models::EntityMetadata::new(EntityType::Assembly);
+        let metadata = self.ids.new_metadata(EntityType::Assembly)?;
 
         let assembly = Assembly {
             metadata,
@@ -45,16 +47,13 @@ impl DesignManager {
             notes: None,
         };
 
-        self.storage.write_assembly(&assembly)?;
+        self.storage.write(&assembly)?;
         Ok(assembly)
     }
 
     /// Get an Assembly by ID
     pub fn get_assembly(&self, id: &Uuid) -> EdtResult<Assembly> {
-        if !self.storage.exists(&EntityType::Assembly, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_assembly(id)
+        self.storage.read(id)
     }
 
     /// Update an Assembly
@@ -66,21 +65,13 @@ impl DesignManager {
         let mut updated = assembly;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_assembly(&updated)?;
+        self.storage.write(&updated)?;
         Ok(updated)
     }
 
-    /// Delete an Assembly
-    pub fn delete_assembly(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Assembly, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Assembly, id)
-    }
-
     /// List all Assembly IDs
     pub fn list_assembly_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Assembly)
+        self.storage.list::<Assembly>()
     }
 
     // ============================================================================
@@ -98,7 +89,7 @@ impl DesignManager {
             return Err(EdtError::ValidationError("Component name cannot be empty".to_string()));
         }
 
-        let metadata = crate::models::EntityMetadata::new(EntityType::Component);
+        let metadata = self.ids.new_metadata(EntityType::Component)?;
 
         let component = Component {
             metadata,
@@ -111,16 +102,13 @@ impl DesignManager {
             notes: None,
         };
 
-        self.storage.write_component(&component)?;
+        self.storage.write(&component)?;
         Ok(component)
     }
 
     /// Get a Component by ID
     pub fn get_component(&self, id: &Uuid) -> EdtResult<Component> {
-        if !self.storage.exists(&EntityType::Component, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_component(id)
+        self.storage.read(id)
     }
 
     /// Update a Component
@@ -132,21 +120,13 @@ impl DesignManager {
         let mut updated = component;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_component(&updated)?;
+        self.storage.write(&updated)?;
         Ok(updated)
     }
 
-    /// Delete a Component
-    pub fn delete_component(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Component, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Component, id)
-    }
-
     /// List all Component IDs
     pub fn list_component_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Component)
+        self.storage.list::<Component>()
     }
 
     // ============================================================================
@@ -174,7 +154,7 @@ impl DesignManager {
             ));
         }
 
-        let metadata = crate::models::EntityMetadata::new(EntityType::Feature);
+        let metadata = self.ids.new_metadata(EntityType::Feature)?;
 
         let feature = Feature {
             metadata,
@@ -191,16 +171,13 @@ impl DesignManager {
             drawing_location: None,
         };
 
-        self.storage.write_feature(&feature)?;
+        self.storage.write(&feature)?;
         Ok(feature)
     }
 
     /// Get a Feature by ID
     pub fn get_feature(&self, id: &Uuid) -> EdtResult<Feature> {
-        if !self.storage.exists(&EntityType::Feature, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_feature(id)
+        self.storage.read(id)
     }
 
     /// Update a Feature
@@ -218,21 +195,13 @@ impl DesignManager {
         let mut updated = feature;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_feature(&updated)?;
+        self.storage.write(&updated)?;
         Ok(updated)
     }
 
-    /// Delete a Feature
-    pub fn delete_feature(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Feature, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Feature, id)
-    }
-
     /// List all Feature IDs
     pub fn list_feature_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Feature)
+        self.storage.list::<Feature>()
     }
 
     // ============================================================================
@@ -250,7 +219,7 @@ impl DesignManager {
             return Err(EdtError::ValidationError("Mate name cannot be empty".to_string()));
         }
 
-        let metadata = crate::models::EntityMetadata::new(EntityType::Mate);
+        let metadata = self.ids.new_metadata(EntityType::Mate)?;
 
         let mate = Mate {
             metadata,
@@ -263,16 +232,13 @@ impl DesignManager {
             analysis_result: None,
         };
 
-        self.storage.write_mate(&mate)?;
+        self.storage.write(&mate)?;
         Ok(mate)
     }
 
     /// Get a Mate by ID
     pub fn get_mate(&self, id: &Uuid) -> EdtResult<Mate> {
-        if !self.storage.exists(&EntityType::Mate, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_mate(id)
+        self.storage.read(id)
     }
 
     /// Update a Mate
@@ -284,21 +250,13 @@ impl DesignManager {
         let mut updated = mate;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_mate(&updated)?;
+        self.storage.write(&updated)?;
         Ok(updated)
     }
 
-    /// Delete a Mate
-    pub fn delete_mate(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Mate, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Mate, id)
-    }
-
     /// List all Mate IDs
     pub fn list_mate_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Mate)
+        self.storage.list::<Mate>()
     }
 
     // ============================================================================
@@ -322,7 +280,7 @@ impl DesignManager {
             ));
         }
 
-        let metadata = crate::models::EntityMetadata::new(EntityType::Stackup);
+        let metadata = self.ids.new_metadata(EntityType::Stackup)?;
 
         let stackup = Stackup {
             metadata,
@@ -338,16 +296,13 @@ impl DesignManager {
             monte_carlo_result: None,
         };
 
-        self.storage.write_stackup(&stackup)?;
+        self.storage.write(&stackup)?;
         Ok(stackup)
     }
 
     /// Get a Stackup by ID
     pub fn get_stackup(&self, id: &Uuid) -> EdtResult<Stackup> {
-        if !self.storage.exists(&EntityType::Stackup, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_stackup(id)
+        self.storage.read(id)
     }
 
     /// Update a Stackup
@@ -365,21 +320,13 @@ impl DesignManager {
         let mut updated = stackup;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_stackup(&updated)?;
+        self.storage.write(&updated)?;
         Ok(updated)
     }
 
-    /// Delete a Stackup
-    pub fn delete_stackup(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Stackup, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Stackup, id)
-    }
-
     /// List all Stackup IDs
     pub fn list_stackup_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Stackup)
+        self.storage.list::<Stackup>()
     }
 
     // ============================================================================
@@ -396,7 +343,7 @@ impl DesignManager {
             return Err(EdtError::ValidationError("Supplier name cannot be empty".to_string()));
         }
 
-        let metadata = crate::models::EntityMetadata::new(EntityType::Supplier);
+        let metadata = self.ids.new_metadata(EntityType::Supplier)?;
 
         let supplier = Supplier {
             metadata,
@@ -409,16 +356,13 @@ impl DesignManager {
             notes: None,
         };
 
-        self.storage.write_supplier(&supplier)?;
+        self.storage.write(&supplier)?;
         Ok(supplier)
     }
 
     /// Get a Supplier by ID
     pub fn get_supplier(&self, id: &Uuid) -> EdtResult<Supplier> {
-        if !self.storage.exists(&EntityType::Supplier, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_supplier(id)
+        self.storage.read(id)
     }
 
     /// Update a Supplier
@@ -430,21 +374,13 @@ impl DesignManager {
         let mut updated = supplier;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_supplier(&updated)?;
+        self.storage.write(&updated)?;
         Ok(updated)
     }
 
-    /// Delete a Supplier
-    pub fn delete_supplier(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Supplier, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Supplier, id)
-    }
-
     /// List all Supplier IDs
     pub fn list_supplier_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Supplier)
+        self.storage.list::<Supplier>()
     }
 
     // ============================================================================
@@ -469,7 +405,7 @@ impl DesignManager {
             ));
         }
 
-        let metadata = crate::models::EntityMetadata::new(EntityType::Quote);
+        let metadata = self.ids.new_metadata(EntityType::Quote)?;
 
         let quote = Quote {
             metadata,
@@ -481,16 +417,13 @@ impl DesignManager {
             notes: None,
         };
 
-        self.storage.write_quote(&quote)?;
+        self.storage.write(&quote)?;
         Ok(quote)
     }
 
     /// Get a Quote by ID
     pub fn get_quote(&self, id: &Uuid) -> EdtResult<Quote> {
-        if !self.storage.exists(&EntityType::Quote, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_quote(id)
+        self.storage.read(id)
     }
 
     /// Update a Quote
@@ -508,20 +441,12 @@ impl DesignManager {
         let mut updated = quote;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_quote(&updated)?;
+        self.storage.write(&updated)?;
         Ok(updated)
     }
 
-    /// Delete a Quote
-    pub fn delete_quote(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Quote, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Quote, id)
-    }
-
     /// List all Quote IDs
     pub fn list_quote_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Quote)
+        self.storage.list::<Quote>()
     }
 }
@@ -1,21 +1,23 @@
 use std::sync::Arc;
 use uuid::Uuid;
 use crate::core::{EdtResult, EdtError};
+use crate::core::IdRegistry;
 use crate::storage::RonStorage;
 use crate::models::{
     EntityType, Manufacturing, ProcessStatus, WorkInstructionStep,
-    QualityCheckpoint, ProductionBatch, EntityMetadata,
+    QualityCheckpoint, ProductionBatch,
 };
 use chrono::Utc;
 
 /// Manages Manufacturing entities
 pub struct ManufacturingManager {
     storage: Arc<RonStorage>,
+    ids: Arc<IdRegistry>,
 }
 
 impl ManufacturingManager {
-    pub fn new(storage: Arc<RonStorage>) -> Self {
-        Self { storage }
+    pub fn new(storage: Arc<RonStorage>, ids: Arc<IdRegistry>) -> Self {
+        Self { storage, ids }
     }
 
     /// Create a new Manufacturing entity
@@ -31,7 +33,7 @@ impl ManufacturingManager {
             return Err(EdtError::ValidationError("Manufacturing name cannot be empty".to_string()));
         }
 
-        let metadata = EntityMetadata::new(EntityType::Manufacturing);
+        let metadata = self.ids.new_metadata(EntityType::Manufacturing)?;
 
         let manufacturing = Manufacturing {
             metadata,
@@ -61,16 +63,13 @@ impl ManufacturingManager {
             nonconformances: vec![],
         };
 
-        self.storage.write_manufacturing(&manufacturing)?;
+        self.storage.write(&manufacturing)?;
         Ok(manufacturing)
     }
 
     /// Get a Manufacturing entity by ID
     pub fn get_manufacturing(&self, id: &Uuid) -> EdtResult<Manufacturing> {
-        if !self.storage.exists(&EntityType::Manufacturing, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_manufacturing(id)
+        self.storage.read(id)
     }
 
     /// Update a Manufacturing entity
@@ -82,20 +81,12 @@ impl ManufacturingManager {
         let mut updated = manufacturing;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_manufacturing(&updated)?;
+        self.storage.write(&updated)?;
         Ok(updated)
     }
 
-    /// Delete a Manufacturing entity
-    pub fn delete_manufacturing(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Manufacturing, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Manufacturing, id)
-    }
-
     /// List all Manufacturing IDs
     pub fn list_manufacturing_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Manufacturing)
+        self.storage.list::<Manufacturing>()
     }
 }
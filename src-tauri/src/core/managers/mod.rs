@@ -5,6 +5,8 @@ pub mod risk_manager;
 pub mod design_manager;
 pub mod testing_manager;
 pub mod manufacturing_manager;
+pub mod comment_manager;
+pub(crate) mod config_checks;
 
 pub use task_manager::TaskManager;
 pub use requirement_manager::RequirementManager;
@@ -12,3 +14,4 @@ pub use risk_manager::RiskManager;
 pub use design_manager::DesignManager;
 pub use testing_manager::TestingManager;
 pub use manufacturing_manager::ManufacturingManager;
+pub use comment_manager::CommentManager;
@@ -1,18 +1,22 @@
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 use crate::core::{EdtResult, EdtError};
+use crate::core::IdRegistry;
 use crate::storage::RonStorage;
-use crate::models::{EntityType, EntityMetadata, Requirement};
+use crate::core::managers::config_checks::{read_config, validate_config_type};
+use crate::models::{EntityType, ProjectConfig, Requirement};
 use chrono::Utc;
 
 /// Manages Requirement entities
 pub struct RequirementManager {
     storage: Arc<RonStorage>,
+    ids: Arc<IdRegistry>,
+    config: Arc<RwLock<ProjectConfig>>,
 }
 
 impl RequirementManager {
-    pub fn new(storage: Arc<RonStorage>) -> Self {
-        Self { storage }
+    pub fn new(storage: Arc<RonStorage>, ids: Arc<IdRegistry>, config: Arc<RwLock<ProjectConfig>>) -> Self {
+        Self { storage, ids, config }
     }
 
     /// Create a new Requirement entity
@@ -25,8 +29,13 @@ impl RequirementManager {
         if name.trim().is_empty() {
             return Err(EdtError::ValidationError("Requirement name cannot be empty".to_string()));
         }
+        validate_config_type(
+            "requirement_type",
+            &requirement_type,
+            &read_config(&self.config)?.requirement_types,
+        )?;
 
-        let metadata = EntityMetadata::new(EntityType::Requirement);
+        let metadata = self.ids.new_metadata(EntityType::Requirement)?;
 
         let requirement = Requirement {
             metadata,
@@ -39,17 +48,14 @@ impl RequirementManager {
             verification_method: None,
         };
 
-        self.storage.write_requirement(&requirement)?;
+        self.storage.write(&requirement)?;
 
         Ok(requirement)
     }
 
     /// Get a Requirement by ID
     pub fn get_requirement(&self, id: &Uuid) -> EdtResult<Requirement> {
-        if !self.storage.exists(&EntityType::Requirement, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_requirement(id)
+        self.storage.read(id)
     }
 
     /// Update a Requirement
@@ -57,25 +63,22 @@ impl RequirementManager {
         if requirement.name.trim().is_empty() {
             return Err(EdtError::ValidationError("Requirement name cannot be empty".to_string()));
         }
+        validate_config_type(
+            "requirement_type",
+            &requirement.requirement_type,
+            &read_config(&self.config)?.requirement_types,
+        )?;
 
         let mut updated = requirement;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_requirement(&updated)?;
+        self.storage.write(&updated)?;
 
         Ok(updated)
     }
 
-    /// Delete a Requirement
-    pub fn delete_requirement(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Requirement, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Requirement, id)
-    }
-
     /// List all Requirement IDs
     pub fn list_requirement_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Requirement)
+        self.storage.list::<Requirement>()
     }
 }
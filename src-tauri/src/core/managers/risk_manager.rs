@@ -1,18 +1,72 @@
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 use crate::core::{EdtResult, EdtError};
+use crate::core::IdRegistry;
 use crate::storage::RonStorage;
-use crate::models::{EntityType, EntityMetadata, Risk, Hazard, RiskControl};
+use crate::core::managers::config_checks::{read_config, validate_config_type};
+use crate::models::{EntityType, ProjectConfig, Risk, Hazard, RiskControl};
 use chrono::Utc;
 
 /// Manages Risk entities
 pub struct RiskManager {
     storage: Arc<RonStorage>,
+    ids: Arc<IdRegistry>,
+    config: Arc<RwLock<ProjectConfig>>,
 }
 
 impl RiskManager {
-    pub fn new(storage: Arc<RonStorage>) -> Self {
-        Self { storage }
+    pub fn new(storage: Arc<RonStorage>, ids: Arc<IdRegistry>, config: Arc<RwLock<ProjectConfig>>) -> Self {
+        Self { storage, ids, config }
+    }
+
+    /// Look up a score in the configured risk matrix, rejecting unconfigured levels
+    fn score(config: &ProjectConfig, probability: u32, severity: u32) -> EdtResult<u32> {
+        if !config.probability_levels.contains(&probability) {
+            return Err(EdtError::ValidationError(format!(
+                "Probability {} is not one of the configured levels {:?}",
+                probability, config.probability_levels
+            )));
+        }
+        if !config.severity_levels.contains(&severity) {
+            return Err(EdtError::ValidationError(format!(
+                "Severity {} is not one of the configured levels {:?}",
+                severity, config.severity_levels
+            )));
+        }
+
+        config.get_risk_score(probability, severity).ok_or_else(|| {
+            EdtError::ValidationError(format!(
+                "Risk matrix has no score for probability {} and severity {}",
+                probability, severity
+            ))
+        })
+    }
+
+    /// Recalculate initial and residual scores and acceptability from the config
+    fn apply_scores(&self, risk: &mut Risk) -> EdtResult<()> {
+        let config = read_config(&self.config)?;
+
+        risk.risk_score = Self::score(&config, risk.probability, risk.severity)?;
+        risk.is_acceptable = config.is_risk_acceptable(risk.risk_score);
+
+        match (risk.residual_probability, risk.residual_severity) {
+            (Some(probability), Some(severity)) => {
+                let residual_score = Self::score(&config, probability, severity)?;
+                risk.residual_risk_score = Some(residual_score);
+                risk.residual_is_acceptable = Some(config.is_risk_acceptable(residual_score));
+            }
+            (None, None) => {
+                risk.residual_risk_score = None;
+                risk.residual_is_acceptable = None;
+            }
+            _ => {
+                return Err(EdtError::ValidationError(
+                    "Residual probability and severity must be set together".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
     }
 
     /// Create a new Risk entity
@@ -27,13 +81,11 @@ impl RiskManager {
         if name.trim().is_empty() {
             return Err(EdtError::ValidationError("Risk name cannot be empty".to_string()));
         }
+        validate_config_type("risk_type", &risk_type, &read_config(&self.config)?.risk_types)?;
 
-        // Simple risk score calculation (probability * severity)
-        let risk_score = probability * severity;
-
-        let metadata = EntityMetadata::new(EntityType::Risk);
+        let metadata = self.ids.new_metadata(EntityType::Risk)?;
 
-        let risk = Risk {
+        let mut risk = Risk {
             metadata,
             name,
             description,
@@ -41,23 +93,23 @@ impl RiskManager {
             risk_type,
             probability,
             severity,
-            risk_score,
+            risk_score: 0,
+            is_acceptable: false,
             residual_probability: None,
             residual_severity: None,
             residual_risk_score: None,
+            residual_is_acceptable: None,
         };
+        self.apply_scores(&mut risk)?;
 
-        self.storage.write_risk(&risk)?;
+        self.storage.write(&risk)?;
 
         Ok(risk)
     }
 
     /// Get a Risk by ID
     pub fn get_risk(&self, id: &Uuid) -> EdtResult<Risk> {
-        if !self.storage.exists(&EntityType::Risk, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_risk(id)
+        self.storage.read(id)
     }
 
     /// Update a Risk
@@ -65,26 +117,20 @@ impl RiskManager {
         if risk.name.trim().is_empty() {
             return Err(EdtError::ValidationError("Risk name cannot be empty".to_string()));
         }
+        validate_config_type("risk_type", &risk.risk_type, &read_config(&self.config)?.risk_types)?;
 
         let mut updated = risk;
+        self.apply_scores(&mut updated)?;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_risk(&updated)?;
+        self.storage.write(&updated)?;
 
         Ok(updated)
     }
 
-    /// Delete a Risk
-    pub fn delete_risk(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Risk, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Risk, id)
-    }
-
     /// List all Risk IDs
     pub fn list_risk_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Risk)
+        self.storage.list::<Risk>()
     }
 
     // ============================================================================
@@ -103,7 +149,7 @@ impl RiskManager {
             return Err(EdtError::ValidationError("Hazard name cannot be empty".to_string()));
         }
 
-        let metadata = EntityMetadata::new(EntityType::Hazard);
+        let metadata = self.ids.new_metadata(EntityType::Hazard)?;
 
         let hazard = Hazard {
             metadata,
@@ -114,17 +160,14 @@ impl RiskManager {
             harms,
         };
 
-        self.storage.write_hazard(&hazard)?;
+        self.storage.write(&hazard)?;
 
         Ok(hazard)
     }
 
     /// Get a Hazard by ID
     pub fn get_hazard(&self, id: &Uuid) -> EdtResult<Hazard> {
-        if !self.storage.exists(&EntityType::Hazard, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_hazard(id)
+        self.storage.read(id)
     }
 
     /// Update a Hazard
@@ -136,22 +179,14 @@ impl RiskManager {
         let mut updated = hazard;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_hazard(&updated)?;
+        self.storage.write(&updated)?;
 
         Ok(updated)
     }
 
-    /// Delete a Hazard
-    pub fn delete_hazard(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Hazard, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Hazard, id)
-    }
-
     /// List all Hazard IDs
     pub fn list_hazard_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Hazard)
+        self.storage.list::<Hazard>()
     }
 
     // ============================================================================
@@ -168,8 +203,13 @@ impl RiskManager {
         if name.trim().is_empty() {
             return Err(EdtError::ValidationError("RiskControl name cannot be empty".to_string()));
         }
+        validate_config_type(
+            "control_type",
+            &control_type,
+            &read_config(&self.config)?.risk_control_types,
+        )?;
 
-        let metadata = EntityMetadata::new(EntityType::RiskControl);
+        let metadata = self.ids.new_metadata(EntityType::RiskControl)?;
 
         let control = RiskControl {
             metadata,
@@ -179,17 +219,14 @@ impl RiskManager {
             control_type,
         };
 
-        self.storage.write_risk_control(&control)?;
+        self.storage.write(&control)?;
 
         Ok(control)
     }
 
     /// Get a RiskControl by ID
     pub fn get_risk_control(&self, id: &Uuid) -> EdtResult<RiskControl> {
-        if !self.storage.exists(&EntityType::RiskControl, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_risk_control(id)
+        self.storage.read(id)
     }
 
     /// Update a RiskControl
@@ -197,25 +234,22 @@ impl RiskManager {
         if control.name.trim().is_empty() {
             return Err(EdtError::ValidationError("RiskControl name cannot be empty".to_string()));
         }
+        validate_config_type(
+            "control_type",
+            &control.control_type,
+            &read_config(&self.config)?.risk_control_types,
+        )?;
 
         let mut updated = control;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_risk_control(&updated)?;
+        self.storage.write(&updated)?;
 
         Ok(updated)
     }
 
-    /// Delete a RiskControl
-    pub fn delete_risk_control(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::RiskControl, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::RiskControl, id)
-    }
-
     /// List all RiskControl IDs
     pub fn list_risk_control_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::RiskControl)
+        self.storage.list::<RiskControl>()
     }
 }
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 use uuid::Uuid;
 use crate::core::{EdtResult, EdtError};
+use crate::core::IdRegistry;
 use crate::storage::RonStorage;
 use crate::models::{
-    EntityType, EntityMetadata,
+    EntityType,
     Task, TaskType, SchedulingMode,
     Milestone, Resource, ResourceType, Calendar, Baseline,
 };
@@ -12,11 +13,12 @@ use chrono::Utc;
 /// Manages Task, Milestone, Resource, Calendar, and Baseline entities
 pub struct TaskManager {
     storage: Arc<RonStorage>,
+    ids: Arc<IdRegistry>,
 }
 
 impl TaskManager {
-    pub fn new(storage: Arc<RonStorage>) -> Self {
-        Self { storage }
+    pub fn new(storage: Arc<RonStorage>, ids: Arc<IdRegistry>) -> Self {
+        Self { storage, ids }
     }
 
     // ============================================================================
@@ -43,7 +45,7 @@ impl TaskManager {
             ));
         }
 
-        let metadata = EntityMetadata::new(EntityType::Task);
+        let metadata = self.ids.new_metadata(EntityType::Task)?;
 
         let task = Task {
             metadata,
@@ -69,17 +71,14 @@ impl TaskManager {
         };
 
         // Write to storage
-        self.storage.write_task(&task)?;
+        self.storage.write(&task)?;
 
         Ok(task)
     }
 
     /// Get a Task by ID
     pub fn get_task(&self, id: &Uuid) -> EdtResult<Task> {
-        if !self.storage.exists(&EntityType::Task, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_task(id)
+        self.storage.read(id)
     }
 
     /// Update a Task
@@ -100,22 +99,14 @@ impl TaskManager {
         updated_task.metadata.updated_at = Utc::now();
 
         // Write to storage
-        self.storage.write_task(&updated_task)?;
+        self.storage.write(&updated_task)?;
 
         Ok(updated_task)
     }
 
-    /// Delete a Task
-    pub fn delete_task(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Task, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Task, id)
-    }
-
     /// List all Task IDs
     pub fn list_task_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Task)
+        self.storage.list::<Task>()
     }
 
     // ============================================================================
@@ -133,7 +124,7 @@ impl TaskManager {
             return Err(EdtError::ValidationError("Milestone name cannot be empty".to_string()));
         }
 
-        let metadata = EntityMetadata::new(EntityType::Milestone);
+        let metadata = self.ids.new_metadata(EntityType::Milestone)?;
 
         let milestone = Milestone {
             metadata,
@@ -144,17 +135,14 @@ impl TaskManager {
             is_critical_path: false,
         };
 
-        self.storage.write_milestone(&milestone)?;
+        self.storage.write(&milestone)?;
 
         Ok(milestone)
     }
 
     /// Get a Milestone by ID
     pub fn get_milestone(&self, id: &Uuid) -> EdtResult<Milestone> {
-        if !self.storage.exists(&EntityType::Milestone, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_milestone(id)
+        self.storage.read(id)
     }
 
     /// Update a Milestone
@@ -166,19 +154,11 @@ impl TaskManager {
         let mut updated = milestone;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_milestone(&updated)?;
+        self.storage.write(&updated)?;
 
         Ok(updated)
     }
 
-    /// Delete a Milestone
-    pub fn delete_milestone(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Milestone, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Milestone, id)
-    }
-
     // ============================================================================
     // Resource Methods
     // ============================================================================
@@ -194,7 +174,7 @@ impl TaskManager {
             return Err(EdtError::ValidationError("Resource name cannot be empty".to_string()));
         }
 
-        let metadata = EntityMetadata::new(EntityType::Resource);
+        let metadata = self.ids.new_metadata(EntityType::Resource)?;
 
         let resource = Resource {
             metadata,
@@ -206,17 +186,14 @@ impl TaskManager {
             calendar_id: None,
         };
 
-        self.storage.write_resource(&resource)?;
+        self.storage.write(&resource)?;
 
         Ok(resource)
     }
 
     /// Get a Resource by ID
     pub fn get_resource(&self, id: &Uuid) -> EdtResult<Resource> {
-        if !self.storage.exists(&EntityType::Resource, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_resource(id)
+        self.storage.read(id)
     }
 
     /// Update a Resource
@@ -228,19 +205,11 @@ impl TaskManager {
         let mut updated = resource;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_resource(&updated)?;
+        self.storage.write(&updated)?;
 
         Ok(updated)
     }
 
-    /// Delete a Resource
-    pub fn delete_resource(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Resource, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Resource, id)
-    }
-
     // ============================================================================
     // Calendar Methods
     // ============================================================================
@@ -268,7 +237,7 @@ impl TaskManager {
             ));
         }
 
-        let metadata = EntityMetadata::new(EntityType::Calendar);
+        let metadata = self.ids.new_metadata(EntityType::Calendar)?;
 
         let calendar = Calendar {
             metadata,
@@ -278,17 +247,14 @@ impl TaskManager {
             holidays: vec![],
         };
 
-        self.storage.write_calendar(&calendar)?;
+        self.storage.write(&calendar)?;
 
         Ok(calendar)
     }
 
     /// Get a Calendar by ID
     pub fn get_calendar(&self, id: &Uuid) -> EdtResult<Calendar> {
-        if !self.storage.exists(&EntityType::Calendar, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_calendar(id)
+        self.storage.read(id)
     }
 
     /// Update a Calendar
@@ -306,24 +272,19 @@ impl TaskManager {
         let mut updated = calendar;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_calendar(&updated)?;
+        self.storage.write(&updated)?;
 
         Ok(updated)
     }
 
-    /// Delete a Calendar
-    pub fn delete_calendar(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Calendar, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Calendar, id)
-    }
-
     // ============================================================================
     // Baseline Methods
     // ============================================================================
 
     /// Create a Baseline
+    ///
+    /// Each listed task's `baseline_data` is replaced by a snapshot for this baseline, so a
+    /// task only keeps the snapshot of the most recent baseline that includes it.
     pub fn create_baseline(
         &self,
         name: String,
@@ -333,8 +294,11 @@ impl TaskManager {
         if name.trim().is_empty() {
             return Err(EdtError::ValidationError("Baseline name cannot be empty".to_string()));
         }
+        if let Some(missing) = task_ids.iter().find(|id| !self.storage.exists(&EntityType::Task, id)) {
+            return Err(EdtError::EntityNotFound(missing.to_string()));
+        }
 
-        let metadata = EntityMetadata::new(EntityType::Baseline);
+        let metadata = self.ids.new_metadata(EntityType::Baseline)?;
 
         let baseline = Baseline {
             metadata,
@@ -344,17 +308,22 @@ impl TaskManager {
             task_ids,
         };
 
-        self.storage.write_baseline(&baseline)?;
+        // Snapshot every task and write the baseline as one transaction and undo step
+        self.storage.with_undo_group("Create Baseline", || {
+            for task_id in &baseline.task_ids {
+                let mut task = self.storage.read::<Task>(task_id)?;
+                task.baseline_data = Some(task.baseline_snapshot(baseline.metadata.id));
+                self.storage.write(&task)?;
+            }
+            self.storage.write(&baseline)
+        })?;
 
         Ok(baseline)
     }
 
     /// Get a Baseline by ID
     pub fn get_baseline(&self, id: &Uuid) -> EdtResult<Baseline> {
-        if !self.storage.exists(&EntityType::Baseline, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_baseline(id)
+        self.storage.read(id)
     }
 
     /// Update a Baseline
@@ -366,16 +335,8 @@ impl TaskManager {
         let mut updated = baseline;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_baseline(&updated)?;
+        self.storage.write(&updated)?;
 
         Ok(updated)
     }
-
-    /// Delete a Baseline
-    pub fn delete_baseline(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Baseline, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Baseline, id)
-    }
 }
@@ -1,21 +1,23 @@
 use std::sync::Arc;
 use uuid::Uuid;
 use crate::core::{EdtResult, EdtError};
+use crate::core::IdRegistry;
 use crate::storage::RonStorage;
 use crate::models::{
     EntityType, Verification, Validation, TestStatus, TestPriority,
-    TestStep, EntityMetadata,
+    TestStep,
 };
 use chrono::Utc;
 
 /// Manages Verification and Validation entities
 pub struct TestingManager {
     storage: Arc<RonStorage>,
+    ids: Arc<IdRegistry>,
 }
 
 impl TestingManager {
-    pub fn new(storage: Arc<RonStorage>) -> Self {
-        Self { storage }
+    pub fn new(storage: Arc<RonStorage>, ids: Arc<IdRegistry>) -> Self {
+        Self { storage, ids }
     }
 
     // ============================================================================
@@ -36,7 +38,7 @@ impl TestingManager {
             return Err(EdtError::ValidationError("Verification name cannot be empty".to_string()));
         }
 
-        let metadata = EntityMetadata::new(EntityType::Verification);
+        let metadata = self.ids.new_metadata(EntityType::Verification)?;
 
         let verification = Verification {
             metadata,
@@ -57,16 +59,13 @@ impl TestingManager {
             defects_found: vec![],
         };
 
-        self.storage.write_verification(&verification)?;
+        self.storage.write(&verification)?;
         Ok(verification)
     }
 
     /// Get a Verification by ID
     pub fn get_verification(&self, id: &Uuid) -> EdtResult<Verification> {
-        if !self.storage.exists(&EntityType::Verification, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_verification(id)
+        self.storage.read(id)
     }
 
     /// Update a Verification
@@ -78,21 +77,13 @@ impl TestingManager {
         let mut updated = verification;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_verification(&updated)?;
+        self.storage.write(&updated)?;
         Ok(updated)
     }
 
-    /// Delete a Verification
-    pub fn delete_verification(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Verification, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Verification, id)
-    }
-
     /// List all Verification IDs
     pub fn list_verification_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Verification)
+        self.storage.list::<Verification>()
     }
 
     // ============================================================================
@@ -113,7 +104,7 @@ impl TestingManager {
             return Err(EdtError::ValidationError("Validation name cannot be empty".to_string()));
         }
 
-        let metadata = EntityMetadata::new(EntityType::Validation);
+        let metadata = self.ids.new_metadata(EntityType::Validation)?;
 
         let validation = Validation {
             metadata,
@@ -137,16 +128,13 @@ impl TestingManager {
             approved_at: None,
         };
 
-        self.storage.write_validation(&validation)?;
+        self.storage.write(&validation)?;
         Ok(validation)
     }
 
     /// Get a Validation by ID
     pub fn get_validation(&self, id: &Uuid) -> EdtResult<Validation> {
-        if !self.storage.exists(&EntityType::Validation, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.read_validation(id)
+        self.storage.read(id)
     }
 
     /// Update a Validation
@@ -158,20 +146,12 @@ impl TestingManager {
         let mut updated = validation;
         updated.metadata.updated_at = Utc::now();
 
-        self.storage.write_validation(&updated)?;
+        self.storage.write(&updated)?;
         Ok(updated)
     }
 
-    /// Delete a Validation
-    pub fn delete_validation(&self, id: &Uuid) -> EdtResult<()> {
-        if !self.storage.exists(&EntityType::Validation, id) {
-            return Err(EdtError::EntityNotFound(id.to_string()));
-        }
-        self.storage.delete(&EntityType::Validation, id)
-    }
-
     /// List all Validation IDs
     pub fn list_validation_ids(&self) -> EdtResult<Vec<Uuid>> {
-        self.storage.list_ids(&EntityType::Validation)
+        self.storage.list::<Validation>()
     }
 }
@@ -0,0 +1,546 @@
+use std::fs;
+use std::path::Path;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use crate::core::{EdtResult, EdtError};
+use crate::core::diff::{all_keyed, array_key, join, key_label, key_value, keyed_path, ArrayKey};
+use crate::models::EntityType;
+use crate::storage::RonStorage;
+
+/// A field changed differently on both sides of a merge
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MergeConflict {
+    /// Same path syntax as `FieldChange::path`
+    pub path: String,
+    /// `None` means the field or element is absent on that side
+    pub base: Option<Value>,
+    pub ours: Option<Value>,
+    pub theirs: Option<Value>,
+}
+
+/// Chosen value for a conflicting path; `None` removes the field or element
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictResolution {
+    pub path: String,
+    pub value: Option<Value>,
+}
+
+/// Three-way merge of one entity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityMerge {
+    pub entity_type: EntityType,
+    /// Merged entity; conflicting paths hold our side until resolved
+    pub merged: Value,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl EntityMerge {
+    /// Apply a resolution for every conflict; fails if any conflict is left unresolved
+    pub fn resolve(&mut self, resolutions: &[ConflictResolution]) -> EdtResult<()> {
+        let unresolved: Vec<&str> = self
+            .conflicts
+            .iter()
+            .filter(|c| !resolutions.iter().any(|r| r.path == c.path))
+            .map(|c| c.path.as_str())
+            .collect();
+        if !unresolved.is_empty() {
+            return Err(EdtError::ValidationError(format!(
+                "Unresolved merge conflicts: {}",
+                unresolved.join(", ")
+            )));
+        }
+
+        for resolution in resolutions {
+            let segments = parse_path(&resolution.path)?;
+            set_path(&mut self.merged, None, &segments, resolution.value.clone())?;
+        }
+        self.conflicts.clear();
+        Ok(())
+    }
+
+    /// Render the merged entity as RON, validating it against the model
+    pub fn to_ron(&self) -> EdtResult<String> {
+        RonStorage::entity_json_to_ron(&self.entity_type, &self.merged)
+    }
+}
+
+#[derive(Deserialize)]
+struct TypeProbe {
+    metadata: MetadataProbe,
+}
+
+#[derive(Deserialize)]
+struct MetadataProbe {
+    entity_type: EntityType,
+}
+
+/// Three-way merge of entity RON files; an empty `base` means the file was added on both sides
+///
+/// The entity type is read from whichever side still has the file, so a delete on one
+/// side merged with an edit on the other is reported as a conflict rather than failing.
+pub fn merge_entity_ron(base: &str, ours: &str, theirs: &str) -> EdtResult<EntityMerge> {
+    fn present(content: &str) -> Option<&str> {
+        Some(content).filter(|c| !c.trim().is_empty())
+    }
+    let probe = present(ours)
+        .or(present(theirs))
+        .or(present(base))
+        .ok_or_else(|| EdtError::ValidationError("No side of the merge has content".to_string()))?;
+    let entity_type = ron::from_str::<TypeProbe>(probe)?.metadata.entity_type;
+    merge_entity(&entity_type, present(base), present(ours), present(theirs))
+}
+
+/// Three-way merge of one entity; `None` means the file is absent on that side
+///
+/// Each side is parsed into its model struct first, so unknown fields or invalid
+/// values fail the merge instead of producing a file that cannot be loaded.
+/// A merged value of `Null` means the entity was deleted.
+pub fn merge_entity(
+    entity_type: &EntityType,
+    base: Option<&str>,
+    ours: Option<&str>,
+    theirs: Option<&str>,
+) -> EdtResult<EntityMerge> {
+    let parse = |content: Option<&str>| -> EdtResult<Option<Value>> {
+        content
+            .map(|content| RonStorage::entity_ron_to_json(entity_type, content))
+            .transpose()
+    };
+
+    let base = parse(base)?;
+    let ours = parse(ours)?;
+    let theirs = parse(theirs)?;
+
+    let mut conflicts = Vec::new();
+    let merged = merge_values("", None, base.as_ref(), ours.as_ref(), theirs.as_ref(), &mut conflicts)
+        .unwrap_or(Value::Null);
+
+    Ok(EntityMerge {
+        entity_type: entity_type.clone(),
+        merged,
+        conflicts,
+    })
+}
+
+/// Git merge driver: merge `theirs` into `ours` in place; returns false if conflicts remain
+///
+/// On conflict `ours` is still rewritten with every non-conflicting change applied, so
+/// the file stays loadable while the conflicts are resolved in the UI. A merge that
+/// deletes the entity leaves `ours` empty.
+pub fn run_merge_driver(base: &Path, ours: &Path, theirs: &Path) -> EdtResult<bool> {
+    let merge = merge_entity_ron(
+        &fs::read_to_string(base)?,
+        &fs::read_to_string(ours)?,
+        &fs::read_to_string(theirs)?,
+    )?;
+    let content = if merge.merged.is_null() { String::new() } else { merge.to_ron()? };
+    fs::write(ours, content)?;
+    Ok(merge.conflicts.is_empty())
+}
+
+fn merge_values(
+    path: &str,
+    field: Option<&str>,
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Option<Value> {
+    if ours == theirs || base == theirs {
+        return ours.cloned();
+    }
+    if base == ours {
+        return theirs.cloned();
+    }
+
+    // Bookkeeping fields that change on every save; merging them never needs a decision
+    match (path, ours, theirs) {
+        ("metadata.updated_at", Some(Value::String(ours_at)), Some(Value::String(theirs_at))) => {
+            return Some(Value::String(latest(ours_at, theirs_at).clone()));
+        }
+        ("metadata.status_history", Some(Value::Array(ours_items)), Some(Value::Array(theirs_items))) => {
+            return Some(append_union(ours_items, theirs_items));
+        }
+        _ => {}
+    }
+
+    let empty = Map::new();
+    match (base, ours, theirs) {
+        (None | Some(Value::Object(_)), Some(Value::Object(ours_map)), Some(Value::Object(theirs_map))) => {
+            let base_map = base.and_then(Value::as_object).unwrap_or(&empty);
+            let mut merged = Map::new();
+            let keys = ours_map.keys().chain(theirs_map.keys().filter(|k| !ours_map.contains_key(*k)));
+            for key in keys {
+                let value = merge_values(
+                    &join(path, key),
+                    Some(key),
+                    base_map.get(key),
+                    ours_map.get(key),
+                    theirs_map.get(key),
+                    conflicts,
+                );
+                if let Some(value) = value {
+                    merged.insert(key.clone(), value);
+                }
+            }
+            Some(Value::Object(merged))
+        }
+        (None | Some(Value::Array(_)), Some(Value::Array(ours_items)), Some(Value::Array(theirs_items))) => {
+            let base_items = base.and_then(Value::as_array).map(Vec::as_slice).unwrap_or(&[]);
+            match field.and_then(array_key) {
+                Some(key)
+                    if all_keyed(base_items, key)
+                        && all_keyed(ours_items, key)
+                        && all_keyed(theirs_items, key) =>
+                {
+                    Some(merge_keyed(path, key, base_items, ours_items, theirs_items, conflicts))
+                }
+                _ => conflict(path, base, ours, theirs, conflicts),
+            }
+        }
+        _ => conflict(path, base, ours, theirs, conflicts),
+    }
+}
+
+/// Merge vectors element by element, keeping our order and appending their additions
+fn merge_keyed(
+    path: &str,
+    key: &ArrayKey,
+    base: &[Value],
+    ours: &[Value],
+    theirs: &[Value],
+    conflicts: &mut Vec<MergeConflict>,
+) -> Value {
+    let find = |items: &'_ [Value], id: &Value| items.iter().find(|item| key_value(item, key) == Some(id)).cloned();
+
+    let mut ids: Vec<&Value> = ours.iter().filter_map(|item| key_value(item, key)).collect();
+    for id in theirs.iter().chain(base).filter_map(|item| key_value(item, key)) {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    let merged = ids
+        .into_iter()
+        .filter_map(|id| {
+            merge_values(
+                &keyed_path(path, key, id),
+                None,
+                find(base, id).as_ref(),
+                find(ours, id).as_ref(),
+                find(theirs, id).as_ref(),
+                conflicts,
+            )
+        })
+        .collect();
+    Value::Array(merged)
+}
+
+/// The later of two RFC 3339 timestamps; unparsable values lose to parsable ones
+fn latest<'a>(ours: &'a String, theirs: &'a String) -> &'a String {
+    let parse = |value: &str| DateTime::parse_from_rfc3339(value).ok();
+    match (parse(ours), parse(theirs)) {
+        (Some(o), Some(t)) if t > o => theirs,
+        (None, Some(_)) => theirs,
+        _ => ours,
+    }
+}
+
+/// Our entries followed by their new ones, ordered by `changed_at`
+///
+/// Status history is append-only, so entries from both sides are kept.
+fn append_union(ours: &[Value], theirs: &[Value]) -> Value {
+    let mut merged = ours.to_vec();
+    for item in theirs {
+        if !merged.contains(item) {
+            merged.push(item.clone());
+        }
+    }
+    let changed_at = |item: &Value| {
+        item.get("changed_at")
+            .and_then(Value::as_str)
+            .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+    };
+    merged.sort_by_key(changed_at);
+    Value::Array(merged)
+}
+
+fn conflict(
+    path: &str,
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Option<Value> {
+    conflicts.push(MergeConflict {
+        path: path.to_string(),
+        base: base.cloned(),
+        ours: ours.cloned(),
+        theirs: theirs.cloned(),
+    });
+    ours.cloned()
+}
+
+/// One step of a conflict path
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Field(String),
+    Index(usize),
+    /// Keyed element, e.g. `[step_number=2]`
+    Key(String),
+}
+
+fn parse_path(path: &str) -> EdtResult<Vec<Segment>> {
+    let invalid = || EdtError::ValidationError(format!("Invalid conflict path '{}'", path));
+    let mut segments = Vec::new();
+    let mut rest = path;
+
+    while !rest.is_empty() {
+        if let Some(inner) = rest.strip_prefix('[') {
+            let end = inner.find(']').ok_or_else(invalid)?;
+            let content = &inner[..end];
+            segments.push(match content.split_once('=') {
+                Some((_, id)) => Segment::Key(id.to_string()),
+                None => Segment::Index(content.parse().map_err(|_| invalid())?),
+            });
+            rest = &inner[end + 1..];
+        } else {
+            let rest_field = rest.strip_prefix('.').unwrap_or(rest);
+            let end = rest_field.find(['.', '[']).unwrap_or(rest_field.len());
+            if end == 0 {
+                return Err(invalid());
+            }
+            segments.push(Segment::Field(rest_field[..end].to_string()));
+            rest = &rest_field[end..];
+        }
+    }
+    Ok(segments)
+}
+
+/// Set (or with `None`, remove) the value at a path
+fn set_path(target: &mut Value, field: Option<&str>, segments: &[Segment], value: Option<Value>) -> EdtResult<()> {
+    let Some((segment, rest)) = segments.split_first() else {
+        *target = value.unwrap_or(Value::Null);
+        return Ok(());
+    };
+    let missing = || EdtError::ValidationError("Conflict path does not match the merged entity".to_string());
+
+    match segment {
+        Segment::Field(name) => {
+            let map = target.as_object_mut().ok_or_else(missing)?;
+            if rest.is_empty() {
+                match value {
+                    Some(value) => map.insert(name.clone(), value),
+                    None => map.remove(name),
+                };
+                return Ok(());
+            }
+            let child = map.get_mut(name).ok_or_else(missing)?;
+            set_path(child, Some(name), rest, value)
+        }
+        Segment::Index(index) => {
+            let items = target.as_array_mut().ok_or_else(missing)?;
+            if rest.is_empty() {
+                match value {
+                    Some(value) if *index < items.len() => items[*index] = value,
+                    Some(value) if *index == items.len() => items.push(value),
+                    None if *index < items.len() => {
+                        items.remove(*index);
+                    }
+                    _ => return Err(missing()),
+                }
+                return Ok(());
+            }
+            let child = items.get_mut(*index).ok_or_else(missing)?;
+            set_path(child, None, rest, value)
+        }
+        Segment::Key(id) => {
+            let key = field.and_then(array_key).ok_or_else(missing)?;
+            let items = target.as_array_mut().ok_or_else(missing)?;
+            let position = items
+                .iter()
+                .position(|item| key_value(item, key).map(key_label).as_deref() == Some(id.as_str()));
+            match (position, rest.is_empty(), value) {
+                (Some(i), true, Some(value)) => items[i] = value,
+                (Some(i), true, None) => {
+                    items.remove(i);
+                }
+                (None, true, Some(value)) => items.push(value),
+                (None, true, None) => {}
+                (Some(i), false, value) => set_path(&mut items[i], None, rest, value)?,
+                (None, false, _) => return Err(missing()),
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use crate::models::{EntityMetadata, Requirement};
+
+    fn merge(base: Value, ours: Value, theirs: Value) -> (Option<Value>, Vec<MergeConflict>) {
+        let mut conflicts = Vec::new();
+        let merged = merge_values("", None, Some(&base), Some(&ours), Some(&theirs), &mut conflicts);
+        (merged, conflicts)
+    }
+
+    #[test]
+    fn test_non_overlapping_changes_merge() {
+        let base = json!({ "name": "A", "description": "D", "notes": null });
+        let ours = json!({ "name": "B", "description": "D", "notes": null });
+        let theirs = json!({ "name": "A", "description": "D", "notes": "N" });
+
+        let (merged, conflicts) = merge(base, ours, theirs);
+        assert!(conflicts.is_empty());
+        assert_eq!(merged, Some(json!({ "name": "B", "description": "D", "notes": "N" })));
+    }
+
+    #[test]
+    fn test_overlapping_change_is_conflict() {
+        let base = json!({ "metadata": { "status": "Draft" } });
+        let ours = json!({ "metadata": { "status": "Approved" } });
+        let theirs = json!({ "metadata": { "status": "Released" } });
+
+        let (merged, conflicts) = merge(base, ours, theirs);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, "metadata.status");
+        assert_eq!(conflicts[0].theirs, Some(json!("Released")));
+        assert_eq!(merged, Some(json!({ "metadata": { "status": "Approved" } })));
+    }
+
+    #[test]
+    fn test_keyed_steps_merge_independently() {
+        let base = json!({ "test_steps": [
+            { "step_number": 1, "description": "Power on" },
+            { "step_number": 2, "description": "Measure" },
+        ]});
+        let ours = json!({ "test_steps": [
+            { "step_number": 1, "description": "Power on at 5V" },
+            { "step_number": 2, "description": "Measure" },
+        ]});
+        let theirs = json!({ "test_steps": [
+            { "step_number": 1, "description": "Power on" },
+            { "step_number": 3, "description": "Record" },
+        ]});
+
+        let (merged, conflicts) = merge(base, ours, theirs);
+        assert!(conflicts.is_empty());
+        assert_eq!(merged, Some(json!({ "test_steps": [
+            { "step_number": 1, "description": "Power on at 5V" },
+            { "step_number": 3, "description": "Record" },
+        ]})));
+    }
+
+    #[test]
+    fn test_resolve_applies_chosen_values() {
+        let base = json!({ "test_steps": [{ "step_number": 1, "description": "A" }], "name": "X" });
+        let ours = json!({ "test_steps": [{ "step_number": 1, "description": "B" }], "name": "Y" });
+        let theirs = json!({ "test_steps": [{ "step_number": 1, "description": "C" }], "name": "Z" });
+
+        let (merged, conflicts) = merge(base, ours, theirs);
+        let paths: Vec<&str> = conflicts.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["name", "test_steps[step_number=1].description"]);
+
+        let mut merged = merged.unwrap();
+        for (path, value) in [("name", json!("Z")), ("test_steps[step_number=1].description", json!("C"))] {
+            set_path(&mut merged, None, &parse_path(path).unwrap(), Some(value)).unwrap();
+        }
+        assert_eq!(merged["name"], "Z");
+        assert_eq!(merged["test_steps"][0]["description"], "C");
+    }
+
+    #[test]
+    fn test_merge_entity_ron_round_trip() {
+        let requirement = Requirement {
+            metadata: EntityMetadata::new(EntityType::Requirement),
+            name: "REQ-001".to_string(),
+            description: "Description".to_string(),
+            notes: None,
+            requirement_type: "System Requirement".to_string(),
+            rationale: None,
+            source: None,
+            verification_method: None,
+        };
+        let mut ours = requirement.clone();
+        ours.rationale = Some("Safety".to_string());
+        ours.description = "Ours".to_string();
+        let mut theirs = requirement.clone();
+        theirs.source = Some("Customer".to_string());
+        theirs.description = "Theirs".to_string();
+
+        let to_ron = |r: &Requirement| ron::ser::to_string_pretty(r, ron::ser::PrettyConfig::default()).unwrap();
+        let merge = merge_entity_ron(&to_ron(&requirement), &to_ron(&ours), &to_ron(&theirs)).unwrap();
+        assert_eq!(merge.entity_type, EntityType::Requirement);
+        assert_eq!(merge.conflicts.len(), 1);
+
+        let mut merge = merge;
+        assert!(merge.resolve(&[]).is_err());
+        merge
+            .resolve(&[ConflictResolution {
+                path: "description".to_string(),
+                value: Some(json!("Theirs")),
+            }])
+            .unwrap();
+        let merged: Requirement = ron::from_str(&merge.to_ron().unwrap()).unwrap();
+        assert_eq!(merged.description, "Theirs");
+        assert_eq!(merged.rationale, Some("Safety".to_string()));
+        assert_eq!(merged.source, Some("Customer".to_string()));
+    }
+
+    #[test]
+    fn test_metadata_bookkeeping_merges_without_conflict() {
+        let change = |to: &str, at: &str| json!({
+            "from": "Draft", "to": to, "changed_by": "user", "changed_at": at, "reason": null
+        });
+        let base = json!({ "metadata": {
+            "updated_at": "2026-01-01T00:00:00Z", "status_history": []
+        }, "name": "A", "notes": null });
+        let ours = json!({ "metadata": {
+            "updated_at": "2026-01-03T00:00:00Z",
+            "status_history": [change("PendingApproval", "2026-01-03T00:00:00Z")]
+        }, "name": "B", "notes": null });
+        let theirs = json!({ "metadata": {
+            "updated_at": "2026-01-02T00:00:00Z",
+            "status_history": [change("Obsolete", "2026-01-02T00:00:00Z")]
+        }, "name": "A", "notes": "N" });
+
+        let (merged, conflicts) = merge(base, ours, theirs);
+        assert!(conflicts.is_empty());
+        let merged = merged.unwrap();
+        assert_eq!(merged["metadata"]["updated_at"], "2026-01-03T00:00:00Z");
+        assert_eq!(merged["metadata"]["status_history"], json!([
+            change("Obsolete", "2026-01-02T00:00:00Z"),
+            change("PendingApproval", "2026-01-03T00:00:00Z"),
+        ]));
+        assert_eq!(merged["name"], "B");
+        assert_eq!(merged["notes"], "N");
+    }
+
+    #[test]
+    fn test_delete_on_ours_conflicts_with_edit_on_theirs() {
+        let requirement = Requirement {
+            metadata: EntityMetadata::new(EntityType::Requirement),
+            name: "REQ-001".to_string(),
+            description: "Description".to_string(),
+            notes: None,
+            requirement_type: "System Requirement".to_string(),
+            rationale: None,
+            source: None,
+            verification_method: None,
+        };
+        let mut theirs = requirement.clone();
+        theirs.description = "Edited".to_string();
+
+        let to_ron = |r: &Requirement| ron::ser::to_string_pretty(r, ron::ser::PrettyConfig::default()).unwrap();
+        let merge = merge_entity_ron(&to_ron(&requirement), "", &to_ron(&theirs)).unwrap();
+        assert_eq!(merge.entity_type, EntityType::Requirement);
+        assert_eq!(merge.merged, Value::Null);
+        assert_eq!(merge.conflicts.len(), 1);
+        assert_eq!(merge.conflicts[0].path, "");
+        assert_eq!(merge.conflicts[0].ours, None);
+    }
+}
@@ -2,12 +2,34 @@
 pub mod error;
 pub mod entity_manager;
 pub mod link_manager;
+pub mod link_rules;
 pub mod app_state;
 pub mod calculation_engine;
+pub mod health_check;
+pub mod diff;
+pub mod merge;
+pub mod version_control;
+pub mod file_watcher;
+pub mod id_registry;
+pub mod workspace;
+pub mod workflow;
+pub mod signatures;
+pub mod audit;
 pub mod managers;
 
 pub use error::{EdtError, EdtResult};
-pub use entity_manager::EntityManager;
+pub use entity_manager::{EntityManager, CommentThread, DeleteImpact, TypeValidationIssue};
 pub use link_manager::LinkManager;
+pub use link_rules::LinkRules;
 pub use app_state::AppState;
 pub use calculation_engine::{CalculationEngine, CriticalPathResult, EvmMetrics};
+pub use health_check::{HealthChecker, HealthReport};
+pub use version_control::{VersionControl, CommitInfo, EntityRevisionDiff, EntityConflict};
+pub use diff::FieldChange;
+pub use id_registry::IdRegistry;
+pub use file_watcher::{FileWatcher, EntityChange, ENTITY_CHANGED_EVENT};
+pub use merge::ConflictResolution;
+pub use workspace::{Workspace, ProjectInfo};
+pub use workflow::{WorkflowEngine, AvailableTransition};
+pub use signatures::{SignatureManager, SignatureRecord};
+pub use audit::{AuditLog, AuditAction, AuditRecord, AuditQuery, AuditVerification};
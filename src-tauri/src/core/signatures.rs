@@ -0,0 +1,213 @@
+use std::sync::{Arc, RwLock};
+use chrono::Utc;
+use serde::Serialize;
+use uuid::Uuid;
+use crate::core::{EdtResult, EdtError};
+use crate::core::managers::config_checks::{read_config, validate_config_type};
+use crate::models::{EntityType, ProjectConfig, Signature, SignatureMeaning};
+use crate::storage::RonStorage;
+
+/// A signature and whether it still matches the entity's current content
+#[derive(Debug, Clone, Serialize)]
+pub struct SignatureRecord {
+    pub signature: Signature,
+    pub valid: bool,
+}
+
+/// Applies and verifies electronic signatures on entity revisions
+pub struct SignatureManager {
+    storage: Arc<RonStorage>,
+    config: Arc<RwLock<ProjectConfig>>,
+}
+
+impl SignatureManager {
+    pub fn new(storage: Arc<RonStorage>, config: Arc<RwLock<ProjectConfig>>) -> Self {
+        Self { storage, config }
+    }
+
+    /// Sign the entity's current content
+    pub fn sign(
+        &self,
+        entity_type: &EntityType,
+        id: &Uuid,
+        signer: &str,
+        role: &str,
+        meaning: SignatureMeaning,
+    ) -> EdtResult<Signature> {
+        let signer = signer.trim();
+        if signer.is_empty() {
+            return Err(EdtError::ValidationError("Signer name cannot be empty".to_string()));
+        }
+        validate_config_type("role", role, &read_config(&self.config)?.signature_roles)?;
+
+        let content_hash = self.content_hash(entity_type, id)?;
+        let already_signed = self.storage.read_signatures(id)?.iter().any(|existing| {
+            existing.signer == signer && existing.meaning == meaning && existing.content_hash == content_hash
+        });
+        if already_signed {
+            return Err(EdtError::ValidationError(format!(
+                "{} has already signed this revision as {:?}",
+                signer, meaning
+            )));
+        }
+
+        let signature = Signature {
+            id: Uuid::new_v4(),
+            entity_id: *id,
+            entity_type: entity_type.clone(),
+            signer: signer.to_string(),
+            role: role.to_string(),
+            meaning,
+            content_hash,
+            signed_at: Utc::now(),
+        };
+        self.storage.write_signature(&signature)?;
+        Ok(signature)
+    }
+
+    /// All signatures on an entity, each checked against its current content
+    pub fn signatures(&self, entity_type: &EntityType, id: &Uuid) -> EdtResult<Vec<SignatureRecord>> {
+        let current = self.current_hash(entity_type, id)?;
+        Ok(self
+            .storage
+            .read_signatures(id)?
+            .into_iter()
+            .map(|signature| SignatureRecord {
+                valid: current.as_deref() == Some(signature.content_hash.as_str()),
+                signature,
+            })
+            .collect())
+    }
+
+    /// True if some signature with this meaning matches the entity's current content
+    pub fn has_valid_signature(&self, entity_type: &EntityType, id: &Uuid, meaning: &SignatureMeaning) -> EdtResult<bool> {
+        Ok(self
+            .signatures(entity_type, id)?
+            .iter()
+            .any(|record| record.valid && &record.signature.meaning == meaning))
+    }
+
+    /// blake3 hash identifying the entity's current revision
+    ///
+    /// Hashes the RON content with the workflow bookkeeping (status, status history
+    /// and `updated_at`) reset, so moving a signed entity through its lifecycle does
+    /// not invalidate its signatures but any edit to its content does.
+    pub fn content_hash(&self, entity_type: &EntityType, id: &Uuid) -> EdtResult<String> {
+        let mut entity = self.storage.read_entity_json(entity_type, id)?;
+        let metadata = &mut entity["metadata"];
+        metadata["status"] = serde_json::json!("Draft");
+        metadata["status_history"] = serde_json::json!([]);
+        metadata["updated_at"] = metadata["created_at"].clone();
+
+        let normalized = RonStorage::entity_json_to_ron(entity_type, &entity)?;
+        Ok(blake3::hash(normalized.as_bytes()).to_hex().to_string())
+    }
+
+    /// Hash of the entity's content, or `None` once it has been deleted
+    fn current_hash(&self, entity_type: &EntityType, id: &Uuid) -> EdtResult<Option<String>> {
+        match self.content_hash(entity_type, id) {
+            Ok(hash) => Ok(Some(hash)),
+            Err(EdtError::EntityNotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{EntityManager, LinkManager};
+    use crate::models::EntityStatus;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    fn create_test_manager() -> (TempDir, EntityManager, Arc<SignatureManager>) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(RonStorage::new(temp_dir.path()).unwrap());
+        let link_manager = Arc::new(Mutex::new(LinkManager::with_storage(Arc::clone(&storage)).unwrap()));
+        let config = Arc::new(RwLock::new(ProjectConfig::default()));
+        let manager = EntityManager::new(storage, link_manager, config).unwrap();
+        let signatures = manager.signatures();
+        (temp_dir, manager, signatures)
+    }
+
+    fn create_risk(manager: &EntityManager) -> Uuid {
+        manager
+            .create_risk(
+                "Overpressure".to_string(),
+                "Line bursts".to_string(),
+                "Safety Risk".to_string(),
+                3,
+                4,
+            )
+            .unwrap()
+            .metadata
+            .id
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let (_temp, manager, signatures) = create_test_manager();
+        let id = create_risk(&manager);
+
+        let signature = signatures
+            .sign(&EntityType::Risk, &id, "Dana Reyes", "Approver", SignatureMeaning::Approved)
+            .unwrap();
+        assert_eq!(signature.content_hash, signatures.content_hash(&EntityType::Risk, &id).unwrap());
+
+        let records = signatures.signatures(&EntityType::Risk, &id).unwrap();
+        assert_eq!(records.len(), 1);
+        assert!(records[0].valid);
+        assert!(signatures
+            .has_valid_signature(&EntityType::Risk, &id, &SignatureMeaning::Approved)
+            .unwrap());
+
+        // The same signer cannot sign the same revision twice with the same meaning
+        let result = signatures.sign(&EntityType::Risk, &id, "Dana Reyes", "Approver", SignatureMeaning::Approved);
+        assert!(matches!(result, Err(EdtError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_sign_requires_configured_role() {
+        let (_temp, manager, signatures) = create_test_manager();
+        let id = create_risk(&manager);
+
+        let result = signatures.sign(&EntityType::Risk, &id, "Dana Reyes", "Intern", SignatureMeaning::Reviewed);
+        assert!(matches!(result, Err(EdtError::ValidationError(_))));
+        let result = signatures.sign(&EntityType::Risk, &id, "  ", "Reviewer", SignatureMeaning::Reviewed);
+        assert!(matches!(result, Err(EdtError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_content_change_invalidates_signature() {
+        let (_temp, manager, signatures) = create_test_manager();
+        let id = create_risk(&manager);
+        signatures
+            .sign(&EntityType::Risk, &id, "Dana Reyes", "Reviewer", SignatureMeaning::Reviewed)
+            .unwrap();
+
+        let mut risk = manager.get_risk(&id).unwrap();
+        risk.severity = 5;
+        manager.update_risk(risk).unwrap();
+
+        let records = signatures.signatures(&EntityType::Risk, &id).unwrap();
+        assert!(!records[0].valid);
+    }
+
+    #[test]
+    fn test_status_changes_keep_signature_valid() {
+        let (_temp, manager, signatures) = create_test_manager();
+        let id = create_risk(&manager);
+        signatures
+            .sign(&EntityType::Risk, &id, "Dana Reyes", "Approver", SignatureMeaning::Approved)
+            .unwrap();
+
+        let workflow = manager.workflow();
+        for status in [EntityStatus::PendingApproval, EntityStatus::Approved] {
+            workflow.transition(&EntityType::Risk, &id, status, "Dana Reyes", None).unwrap();
+        }
+
+        let records = signatures.signatures(&EntityType::Risk, &id).unwrap();
+        assert!(records[0].valid);
+    }
+}
@@ -0,0 +1,647 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use chrono::{DateTime, TimeZone, Utc};
+use git2::{Commit, IndexAddOption, IndexConflict, IndexEntry, Repository, Signature, Sort};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+use crate::core::{AuditLog, EdtResult, EdtError, WorkflowEngine};
+use crate::core::diff::{diff_json, FieldChange};
+use crate::core::merge::{merge_entity, ConflictResolution, EntityMerge, MergeConflict};
+use crate::models::EntityType;
+use crate::storage::RonStorage;
+
+/// Paths that never belong in the project repository
+const GITIGNORE_ENTRIES: &[&str] = &[".edt/.cache/", ".edt/journal.ron", ".edt/seen_comments.ron", ".*.tmp"];
+
+/// Routes entity files through the `tessera-merge` driver binary
+const GITATTRIBUTES_ENTRY: &str = "entities/**/*.ron merge=tessera";
+const MERGE_DRIVER: &str = "tessera-merge %O %A %B %P";
+
+/// Summary of a commit in the project repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitInfo {
+    pub id: String,
+    pub message: String,
+    pub author: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Field-level differences for one entity between two revisions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityRevisionDiff {
+    pub entity_id: Uuid,
+    pub entity_type: EntityType,
+    pub from_revision: String,
+    /// `None` compares against the working tree
+    pub to_revision: Option<String>,
+    pub changes: Vec<FieldChange>,
+}
+
+/// An entity left conflicted in the index by a merge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityConflict {
+    pub entity_id: Uuid,
+    pub entity_type: EntityType,
+    /// Fields changed on both sides; empty if only the text merge conflicted
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// Git operations on the project folder
+///
+/// Restores and merge resolutions go through the workflow, so they cannot change
+/// locked entities or their status.
+pub struct VersionControl {
+    project_root: PathBuf,
+    storage: Arc<RonStorage>,
+    workflow: Arc<WorkflowEngine>,
+    audit: Arc<AuditLog>,
+}
+
+impl VersionControl {
+    pub fn new(
+        project_root: PathBuf,
+        storage: Arc<RonStorage>,
+        workflow: Arc<WorkflowEngine>,
+        audit: Arc<AuditLog>,
+    ) -> Self {
+        Self {
+            project_root,
+            storage,
+            workflow,
+            audit,
+        }
+    }
+
+    /// Initialize a repository in the project root; returns false if one already existed
+    pub fn init(&self) -> EdtResult<bool> {
+        let created = match Repository::open(&self.project_root) {
+            Ok(_) => false,
+            Err(_) => {
+                Repository::init(&self.project_root)?;
+                true
+            }
+        };
+        self.ensure_gitignore()?;
+        self.ensure_merge_driver()?;
+        Ok(created)
+    }
+
+    /// Stage every change in the project and commit it
+    pub fn commit(&self, message: &str) -> EdtResult<CommitInfo> {
+        if message.trim().is_empty() {
+            return Err(EdtError::ValidationError("Commit message cannot be empty".to_string()));
+        }
+
+        let repo = self.open()?;
+        let mut index = repo.index()?;
+        index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+        // Stage deletions as well
+        index.update_all(["*"].iter(), None)?;
+        index.write()?;
+
+        let tree_id = index.write_tree()?;
+        let parent = match repo.head() {
+            Ok(head) => Some(head.peel_to_commit()?),
+            Err(_) => None,
+        };
+        if parent.as_ref().is_some_and(|p| p.tree_id() == tree_id) {
+            return Err(EdtError::ValidationError("No changes to commit".to_string()));
+        }
+
+        let tree = repo.find_tree(tree_id)?;
+        let signature = repo
+            .signature()
+            .or_else(|_| Signature::now("Tessera", "tessera@localhost"))?;
+        let parents: Vec<&Commit> = parent.iter().collect();
+        let oid = repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)?;
+
+        let commit = repo.find_commit(oid)?;
+        Ok(Self::commit_info(&commit))
+    }
+
+    /// Commits that added, changed or removed the entity's file, newest first
+    pub fn entity_history(&self, entity_type: &EntityType, entity_id: &Uuid) -> EdtResult<Vec<CommitInfo>> {
+        let repo = self.open()?;
+        if repo.head().is_err() {
+            return Ok(Vec::new());
+        }
+
+        let path = self.entity_relative_path(entity_type, entity_id);
+        let blob_id = |commit: &Commit| {
+            commit
+                .tree()
+                .ok()
+                .and_then(|tree| tree.get_path(&path).ok())
+                .map(|entry| entry.id())
+        };
+
+        let mut walk = repo.revwalk()?;
+        walk.push_head()?;
+        walk.set_sorting(Sort::TIME)?;
+
+        let mut history = Vec::new();
+        for oid in walk {
+            let commit = repo.find_commit(oid?)?;
+            let parent_blob = commit.parent(0).ok().and_then(|parent| blob_id(&parent));
+            if blob_id(&commit) != parent_blob {
+                history.push(Self::commit_info(&commit));
+            }
+        }
+        Ok(history)
+    }
+
+    /// Field-level diff of an entity between two revisions, or a revision and the working tree
+    pub fn diff_entity(
+        &self,
+        entity_type: &EntityType,
+        entity_id: &Uuid,
+        from_revision: &str,
+        to_revision: Option<&str>,
+    ) -> EdtResult<EntityRevisionDiff> {
+        let repo = self.open()?;
+        let path = self.entity_relative_path(entity_type, entity_id);
+
+        let old = Self::read_at(&repo, from_revision, &path)?;
+        let new = match to_revision {
+            Some(revision) => Self::read_at(&repo, revision, &path)?,
+            None => {
+                let full_path = self.project_root.join(&path);
+                if full_path.exists() {
+                    Some(fs::read_to_string(full_path)?)
+                } else {
+                    None
+                }
+            }
+        };
+        if old.is_none() && new.is_none() {
+            return Err(EdtError::EntityNotFound(entity_id.to_string()));
+        }
+
+        let to_json = |content: Option<String>| -> EdtResult<Option<Value>> {
+            content
+                .map(|content| RonStorage::entity_ron_to_json(entity_type, &content))
+                .transpose()
+        };
+
+        let changes = diff_json(to_json(old)?.as_ref(), to_json(new)?.as_ref());
+
+        Ok(EntityRevisionDiff {
+            entity_id: *entity_id,
+            entity_type: entity_type.clone(),
+            from_revision: from_revision.to_string(),
+            to_revision: to_revision.map(str::to_string),
+            changes,
+        })
+    }
+
+    /// Overwrite the entity's working file with its content at `revision`
+    ///
+    /// The entity keeps its current status and status history; a locked entity is refused.
+    pub fn restore_entity(&self, entity_type: &EntityType, entity_id: &Uuid, revision: &str) -> EdtResult<Value> {
+        let repo = self.open()?;
+        let path = self.entity_relative_path(entity_type, entity_id);
+        let content = Self::read_at(&repo, revision, &path)?.ok_or_else(|| {
+            EdtError::EntityNotFound(format!("{} at revision {}", entity_id, revision))
+        })?;
+
+        // Validates the old content (upgrading older schemas) before touching the working tree
+        let mut entity = RonStorage::entity_ron_to_json(entity_type, &content)?;
+        let before = self.workflow.guard_rewrite(entity_type, entity_id, &mut entity)?;
+        let content = RonStorage::entity_json_to_ron(entity_type, &entity)?;
+        self.storage.write_raw(entity_type, entity_id, &content)?;
+        self.audit.record_change(entity_type.clone(), *entity_id, before.as_ref(), Some(&entity))?;
+        Ok(entity)
+    }
+
+    /// Entities with unresolved merge conflicts, merged field by field
+    pub fn merge_conflicts(&self) -> EdtResult<Vec<EntityConflict>> {
+        let repo = self.open()?;
+        let index = repo.index()?;
+
+        let mut result = Vec::new();
+        for conflict in index.conflicts()? {
+            let conflict = conflict?;
+            // Conflicts outside the entity folders are left to git
+            let Some((entity_type, entity_id)) = Self::conflict_entity(&conflict) else { continue };
+            let merge = Self::merge_conflict(&repo, &entity_type, &conflict)?;
+            result.push(EntityConflict {
+                entity_id,
+                entity_type,
+                conflicts: merge.conflicts,
+            });
+        }
+        Ok(result)
+    }
+
+    /// Resolve every conflict of an entity, write the merged file and mark it resolved
+    ///
+    /// Returns the merged entity, or `Null` if the resolution deletes it.
+    pub fn resolve_conflict(
+        &self,
+        entity_type: &EntityType,
+        entity_id: &Uuid,
+        resolutions: &[ConflictResolution],
+    ) -> EdtResult<Value> {
+        let repo = self.open()?;
+        let mut index = repo.index()?;
+        let path = self.entity_relative_path(entity_type, entity_id);
+
+        let mut conflict = None;
+        for entry in index.conflicts()? {
+            let entry = entry?;
+            if Self::conflict_path(&entry).as_deref() == Some(path.as_path()) {
+                conflict = Some(entry);
+                break;
+            }
+        }
+        let conflict = conflict.ok_or_else(|| {
+            EdtError::ValidationError(format!("Entity {} has no merge conflict", entity_id))
+        })?;
+
+        let mut merge = Self::merge_conflict(&repo, entity_type, &conflict)?;
+        merge.resolve(resolutions)?;
+
+        let side = |entry: Option<&IndexEntry>| -> EdtResult<Option<Value>> {
+            Self::entry_content(&repo, entry)?
+                .map(|content| RonStorage::entity_ron_to_json(entity_type, &content))
+                .transpose()
+        };
+        let ours = side(conflict.our.as_ref())?;
+        let theirs = side(conflict.their.as_ref())?;
+        self.workflow.check_merge(ours.as_ref(), theirs.as_ref(), &merge.merged)?;
+
+        if merge.merged.is_null() {
+            self.storage.delete(entity_type, entity_id)?;
+            index.remove_path(&path)?;
+        } else {
+            self.storage.write_raw(entity_type, entity_id, &merge.to_ron()?)?;
+            index.add_path(&path)?;
+        }
+        index.write()?;
+        // Recorded against our side, the version the project had before the merge
+        let after = Some(&merge.merged).filter(|merged| !merged.is_null());
+        self.audit.record_change(entity_type.clone(), *entity_id, ours.as_ref(), after)?;
+        Ok(merge.merged)
+    }
+
+    fn open(&self) -> EdtResult<Repository> {
+        Repository::open(&self.project_root).map_err(|_| {
+            EdtError::VersionControlError(
+                "Project is not under version control; initialize a repository first".to_string(),
+            )
+        })
+    }
+
+    fn ensure_gitignore(&self) -> EdtResult<()> {
+        let path = self.project_root.join(".gitignore");
+        let existing = if path.exists() {
+            fs::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+
+        let missing: Vec<&str> = GITIGNORE_ENTRIES
+            .iter()
+            .copied()
+            .filter(|entry| !existing.lines().any(|line| line.trim() == *entry))
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        let mut content = existing;
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        for entry in missing {
+            content.push_str(entry);
+            content.push('\n');
+        }
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    fn ensure_merge_driver(&self) -> EdtResult<()> {
+        let path = self.project_root.join(".gitattributes");
+        let existing = if path.exists() {
+            fs::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+        if !existing.lines().any(|line| line.trim() == GITATTRIBUTES_ENTRY) {
+            let mut content = existing;
+            if !content.is_empty() && !content.ends_with('\n') {
+                content.push('\n');
+            }
+            content.push_str(GITATTRIBUTES_ENTRY);
+            content.push('\n');
+            fs::write(path, content)?;
+        }
+
+        // The driver command lives in the repository config, which is not shared by clones
+        let mut config = self.open()?.config()?;
+        config.set_str("merge.tessera.name", "Tessera entity merge")?;
+        config.set_str("merge.tessera.driver", MERGE_DRIVER)?;
+        Ok(())
+    }
+
+    fn conflict_path(conflict: &IndexConflict) -> Option<PathBuf> {
+        conflict
+            .our
+            .as_ref()
+            .or(conflict.their.as_ref())
+            .or(conflict.ancestor.as_ref())
+            .map(|entry| PathBuf::from(String::from_utf8_lossy(&entry.path).into_owned()))
+    }
+
+    /// Entity stored at a conflicted path such as `entities/requirements/<id>.ron`
+    fn conflict_entity(conflict: &IndexConflict) -> Option<(EntityType, Uuid)> {
+        let path = Self::conflict_path(conflict)?;
+        let relative = path.strip_prefix("entities").ok()?;
+        let folder = relative.parent()?.to_str()?;
+        if relative.extension()? != "ron" {
+            return None;
+        }
+        let entity_type = EntityType::from_folder_name(folder)?;
+        let entity_id = Uuid::parse_str(relative.file_stem()?.to_str()?).ok()?;
+        Some((entity_type, entity_id))
+    }
+
+    fn merge_conflict(repo: &Repository, entity_type: &EntityType, conflict: &IndexConflict) -> EdtResult<EntityMerge> {
+        let base = Self::entry_content(repo, conflict.ancestor.as_ref())?;
+        let ours = Self::entry_content(repo, conflict.our.as_ref())?;
+        let theirs = Self::entry_content(repo, conflict.their.as_ref())?;
+        merge_entity(entity_type, base.as_deref(), ours.as_deref(), theirs.as_deref())
+    }
+
+    fn entry_content(repo: &Repository, entry: Option<&IndexEntry>) -> EdtResult<Option<String>> {
+        let Some(entry) = entry else { return Ok(None) };
+        let blob = repo.find_blob(entry.id)?;
+        Self::blob_text(blob.content()).map(Some)
+    }
+
+    fn blob_text(content: &[u8]) -> EdtResult<String> {
+        String::from_utf8(content.to_vec())
+            .map_err(|e| EdtError::ValidationError(format!("Entity file is not UTF-8: {}", e)))
+    }
+
+    /// Entity file path relative to the repository root, as git stores it
+    fn entity_relative_path(&self, entity_type: &EntityType, entity_id: &Uuid) -> PathBuf {
+        let full_path = self.storage.get_entity_path(entity_type, entity_id);
+        full_path
+            .strip_prefix(&self.project_root)
+            .map(Path::to_path_buf)
+            .unwrap_or(full_path)
+    }
+
+    /// File content at a revision, or `None` if the file did not exist there
+    fn read_at(repo: &Repository, revision: &str, path: &Path) -> EdtResult<Option<String>> {
+        let tree = repo.revparse_single(revision)?.peel_to_commit()?.tree()?;
+        let entry = match tree.get_path(path) {
+            Ok(entry) => entry,
+            Err(_) => return Ok(None),
+        };
+        let blob = entry.to_object(repo)?.peel_to_blob()?;
+        Self::blob_text(blob.content()).map(Some)
+    }
+
+    fn commit_info(commit: &Commit) -> CommitInfo {
+        CommitInfo {
+            id: commit.id().to_string(),
+            message: commit.message().unwrap_or_default().trim_end().to_string(),
+            author: commit.author().name().unwrap_or_default().to_string(),
+            timestamp: Utc
+                .timestamp_opt(commit.time().seconds(), 0)
+                .single()
+                .unwrap_or_else(Utc::now),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{EntityManager, LinkManager};
+    use crate::models::{EntityMetadata, EntityStatus, ProjectConfig, Requirement, SignatureMeaning};
+    use std::sync::{Mutex, RwLock};
+    use tempfile::TempDir;
+
+    fn create_test_setup() -> (TempDir, Arc<RonStorage>, EntityManager, VersionControl) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(RonStorage::new(temp_dir.path()).unwrap());
+        let link_manager = Arc::new(Mutex::new(LinkManager::with_storage(Arc::clone(&storage)).unwrap()));
+        let config = Arc::new(RwLock::new(ProjectConfig::default()));
+        let manager = EntityManager::new(Arc::clone(&storage), link_manager, config).unwrap();
+        let vcs = VersionControl::new(
+            temp_dir.path().to_path_buf(),
+            Arc::clone(&storage),
+            manager.workflow(),
+            manager.audit_log(),
+        );
+        (temp_dir, storage, manager, vcs)
+    }
+
+    fn create_test_vcs() -> (TempDir, Arc<RonStorage>, VersionControl) {
+        let (temp_dir, storage, _manager, vcs) = create_test_setup();
+        vcs.init().unwrap();
+        (temp_dir, storage, vcs)
+    }
+
+    fn requirement(name: &str) -> Requirement {
+        Requirement {
+            metadata: EntityMetadata::new(EntityType::Requirement),
+            name: name.to_string(),
+            description: "Description".to_string(),
+            notes: None,
+            requirement_type: "System Requirement".to_string(),
+            rationale: None,
+            source: None,
+            verification_method: None,
+        }
+    }
+
+    #[test]
+    fn test_init_is_idempotent_and_ignores_cache() {
+        let (temp, _storage, vcs) = create_test_vcs();
+
+        assert!(temp.path().join(".git").exists());
+        assert!(!vcs.init().unwrap());
+
+        let gitignore = fs::read_to_string(temp.path().join(".gitignore")).unwrap();
+        assert_eq!(gitignore.matches(".edt/.cache/").count(), 1);
+    }
+
+    #[test]
+    fn test_commit_requires_changes() {
+        let (_temp, storage, vcs) = create_test_vcs();
+        storage.write(&requirement("REQ-001")).unwrap();
+
+        let commit = vcs.commit("Add requirement").unwrap();
+        assert_eq!(commit.message, "Add requirement");
+
+        let result = vcs.commit("Nothing new");
+        assert!(matches!(result, Err(EdtError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_entity_history_lists_changing_commits() {
+        let (_temp, storage, vcs) = create_test_vcs();
+        let mut tracked = requirement("REQ-001");
+        storage.write(&tracked).unwrap();
+        vcs.commit("Add REQ-001").unwrap();
+
+        storage.write(&requirement("REQ-002")).unwrap();
+        vcs.commit("Add REQ-002").unwrap();
+
+        tracked.description = "Changed".to_string();
+        storage.write(&tracked).unwrap();
+        vcs.commit("Edit REQ-001").unwrap();
+
+        let history = vcs
+            .entity_history(&EntityType::Requirement, &tracked.metadata.id)
+            .unwrap();
+        let messages: Vec<&str> = history.iter().map(|c| c.message.as_str()).collect();
+        assert_eq!(messages, vec!["Edit REQ-001", "Add REQ-001"]);
+    }
+
+    #[test]
+    fn test_diff_and_restore_entity() {
+        let (_temp, storage, vcs) = create_test_vcs();
+        let mut tracked = requirement("REQ-001");
+        storage.write(&tracked).unwrap();
+        let first = vcs.commit("Add REQ-001").unwrap();
+
+        tracked.description = "Changed".to_string();
+        tracked.rationale = Some("Because".to_string());
+        storage.write(&tracked).unwrap();
+
+        let id = tracked.metadata.id;
+        let diff = vcs
+            .diff_entity(&EntityType::Requirement, &id, &first.id, None)
+            .unwrap();
+        let paths: Vec<&str> = diff.changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["description", "rationale"]);
+        assert_eq!(diff.changes[0].old_value, Some(Value::from("Description")));
+        assert_eq!(diff.changes[0].new_value, Some(Value::from("Changed")));
+
+        let restored = vcs
+            .restore_entity(&EntityType::Requirement, &id, &first.id)
+            .unwrap();
+        assert_eq!(restored["description"], "Description");
+        assert_eq!(storage.read::<Requirement>(&id).unwrap().description, "Description");
+    }
+
+    #[test]
+    fn test_restore_keeps_status_and_refuses_locked_entities() {
+        let (_temp, _storage, manager, vcs) = create_test_setup();
+        vcs.init().unwrap();
+        let id = manager
+            .create_risk("Overpressure".to_string(), "Line bursts".to_string(), "Safety Risk".to_string(), 3, 4)
+            .unwrap()
+            .metadata
+            .id;
+        let first = vcs.commit("Add risk").unwrap();
+
+        let mut risk = manager.get_risk(&id).unwrap();
+        risk.description = "Line bursts under surge".to_string();
+        manager.update_risk(risk).unwrap();
+        let workflow = manager.workflow();
+        workflow
+            .transition(&EntityType::Risk, &id, EntityStatus::PendingApproval, "Dana Reyes", None)
+            .unwrap();
+
+        // The old content comes back, but the status stays where the workflow put it
+        vcs.restore_entity(&EntityType::Risk, &id, &first.id).unwrap();
+        let restored = manager.get_risk(&id).unwrap();
+        assert_eq!(restored.description, "Line bursts");
+        assert_eq!(restored.metadata.status, EntityStatus::PendingApproval);
+        assert_eq!(restored.metadata.status_history.len(), 1);
+
+        let audit = manager.audit_log().query(&Default::default()).unwrap();
+        let restore = audit.last().unwrap();
+        assert_eq!(restore.entity_id, id);
+        let description = restore.changes.iter().find(|c| c.path == "description").unwrap();
+        assert_eq!(description.new_value, Some(serde_json::json!("Line bursts")));
+
+        manager
+            .signatures()
+            .sign(&EntityType::Risk, &id, "Dana Reyes", "Approver", SignatureMeaning::Approved)
+            .unwrap();
+        workflow
+            .transition(&EntityType::Risk, &id, EntityStatus::Approved, "Dana Reyes", None)
+            .unwrap();
+
+        let result = vcs.restore_entity(&EntityType::Risk, &id, &first.id);
+        assert!(matches!(result, Err(EdtError::EntityLocked(EntityStatus::Approved))));
+        assert_eq!(manager.get_risk(&id).unwrap().metadata.status, EntityStatus::Approved);
+    }
+
+    #[test]
+    fn test_init_registers_merge_driver() {
+        let (temp, _storage, vcs) = create_test_vcs();
+        vcs.init().unwrap();
+
+        let attributes = fs::read_to_string(temp.path().join(".gitattributes")).unwrap();
+        assert_eq!(attributes.matches(GITATTRIBUTES_ENTRY).count(), 1);
+        let config = Repository::open(temp.path()).unwrap().config().unwrap();
+        assert_eq!(config.get_string("merge.tessera.driver").unwrap(), MERGE_DRIVER);
+    }
+
+    #[test]
+    fn test_merge_conflicts_reported_and_resolved() {
+        let (temp, storage, vcs) = create_test_vcs();
+        let base = requirement("REQ-001");
+        let id = base.metadata.id;
+        storage.write(&base).unwrap();
+        let base_commit = vcs.commit("Add REQ-001").unwrap();
+
+        let mut theirs = base.clone();
+        theirs.description = "Theirs".to_string();
+        theirs.rationale = Some("Safety".to_string());
+        storage.write(&theirs).unwrap();
+        let their_commit = vcs.commit("Their edit").unwrap();
+
+        // Rewind to the base and make a competing edit
+        let repo = Repository::open(temp.path()).unwrap();
+        let base_object = repo.revparse_single(&base_commit.id).unwrap();
+        repo.reset(&base_object, git2::ResetType::Hard, None).unwrap();
+        let mut ours = base.clone();
+        ours.description = "Ours".to_string();
+        storage.write(&ours).unwrap();
+        vcs.commit("Our edit").unwrap();
+
+        let their_oid = git2::Oid::from_str(&their_commit.id).unwrap();
+        let annotated = repo.find_annotated_commit(their_oid).unwrap();
+        repo.merge(&[&annotated], None, None).unwrap();
+
+        let conflicts = vcs.merge_conflicts().unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].entity_id, id);
+        let paths: Vec<&str> = conflicts[0].conflicts.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["description"]);
+
+        let incomplete = vcs.resolve_conflict(&EntityType::Requirement, &id, &[]);
+        assert!(matches!(incomplete, Err(EdtError::ValidationError(_))));
+
+        let resolution = ConflictResolution {
+            path: "description".to_string(),
+            value: Some(Value::from("Ours")),
+        };
+        vcs.resolve_conflict(&EntityType::Requirement, &id, &[resolution]).unwrap();
+
+        let merged = storage.read::<Requirement>(&id).unwrap();
+        assert_eq!(merged.description, "Ours");
+        assert_eq!(merged.rationale, Some("Safety".to_string()));
+        assert!(vcs.merge_conflicts().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_operations_require_repository() {
+        let (_temp, _storage, _manager, vcs) = create_test_setup();
+
+        let result = vcs.commit("Message");
+        assert!(matches!(result, Err(EdtError::VersionControlError(_))));
+    }
+}
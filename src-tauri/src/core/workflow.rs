@@ -0,0 +1,424 @@
+use std::sync::{Arc, Mutex, RwLock};
+use chrono::Utc;
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+use crate::core::{AuditLog, EdtResult, EdtError, LinkManager, SignatureManager};
+use crate::core::managers::config_checks::read_config;
+use crate::models::{
+    EntityMetadata, EntityStatus, EntityType, ProjectConfig, StatusChange, StoredEntity,
+    TransitionCondition, TransitionGuard,
+};
+use crate::storage::RonStorage;
+
+/// A status the entity can move to next, and what stands in the way
+#[derive(Debug, Clone, Serialize)]
+pub struct AvailableTransition {
+    pub to: EntityStatus,
+    pub requires_reason: bool,
+    /// Unmet guard conditions; empty when the transition can be made now
+    pub blocked_by: Vec<String>,
+}
+
+/// Enforces the configured status lifecycle
+///
+/// Status only changes through `transition`, which checks the configured rules and
+/// guards and records the change in the entity's status history. Entities in a
+/// locked status cannot be updated or deleted until revised back to an editable one.
+pub struct WorkflowEngine {
+    storage: Arc<RonStorage>,
+    link_manager: Arc<Mutex<LinkManager>>,
+    signatures: Arc<SignatureManager>,
+    audit: Arc<AuditLog>,
+    config: Arc<RwLock<ProjectConfig>>,
+}
+
+impl WorkflowEngine {
+    pub fn new(
+        storage: Arc<RonStorage>,
+        link_manager: Arc<Mutex<LinkManager>>,
+        signatures: Arc<SignatureManager>,
+        audit: Arc<AuditLog>,
+        config: Arc<RwLock<ProjectConfig>>,
+    ) -> Self {
+        Self { storage, link_manager, signatures, audit, config }
+    }
+
+    /// Move an entity to a new status, recording who made the change and why
+    pub fn transition(
+        &self,
+        entity_type: &EntityType,
+        id: &Uuid,
+        to: EntityStatus,
+        changed_by: &str,
+        reason: Option<String>,
+    ) -> EdtResult<StatusChange> {
+        if changed_by.trim().is_empty() {
+            return Err(EdtError::ValidationError("Status changes must name who made them".to_string()));
+        }
+        let reason = reason.filter(|r| !r.trim().is_empty());
+
+        let before = self.storage.read_entity_json(entity_type, id)?;
+        let mut entity = before.clone();
+        let metadata = Self::metadata(&entity)?;
+
+        let config = read_config(&self.config)?.clone();
+        let rule = config.workflow.transition(&metadata.status, &to).ok_or_else(|| {
+            EdtError::InvalidTransition(format!("{:?} cannot move from {:?} to {:?}", entity_type, metadata.status, to))
+        })?;
+        if rule.requires_reason && reason.is_none() {
+            return Err(EdtError::InvalidTransition(format!(
+                "Moving from {:?} to {:?} requires a reason",
+                metadata.status, to
+            )));
+        }
+        let unmet = self.unmet_guards(config.workflow.guards_for(entity_type, &to), entity_type, id)?;
+        if let Some(message) = unmet.first() {
+            return Err(EdtError::InvalidTransition(message.clone()));
+        }
+
+        let change = StatusChange {
+            from: metadata.status.clone(),
+            to,
+            changed_by: changed_by.trim().to_string(),
+            changed_at: Utc::now(),
+            reason,
+        };
+
+        let mut updated = metadata;
+        updated.status = change.to.clone();
+        updated.updated_at = change.changed_at;
+        updated.status_history.push(change.clone());
+        entity["metadata"] = serde_json::to_value(&updated)?;
+
+        let content = RonStorage::entity_json_to_ron(entity_type, &entity)?;
+        self.storage.write_raw(entity_type, id, &content)?;
+        self.audit.record_change(entity_type.clone(), *id, Some(&before), Some(&entity))?;
+        Ok(change)
+    }
+
+    /// Current status of an entity
+    pub fn status(&self, entity_type: &EntityType, id: &Uuid) -> EdtResult<EntityStatus> {
+        Ok(Self::metadata(&self.storage.read_entity_json(entity_type, id)?)?.status)
+    }
+
+    /// Statuses the entity may move to from its current one
+    pub fn available_transitions(&self, entity_type: &EntityType, id: &Uuid) -> EdtResult<Vec<AvailableTransition>> {
+        let metadata = Self::metadata(&self.storage.read_entity_json(entity_type, id)?)?;
+        let config = read_config(&self.config)?.clone();
+
+        config
+            .workflow
+            .transitions
+            .iter()
+            .filter(|rule| rule.from == metadata.status)
+            .map(|rule| {
+                Ok(AvailableTransition {
+                    to: rule.to.clone(),
+                    requires_reason: rule.requires_reason,
+                    blocked_by: self.unmet_guards(config.workflow.guards_for(entity_type, &rule.to), entity_type, id)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Refuse changes to an entity whose stored status is locked
+    pub fn ensure_editable(&self, entity_type: &EntityType, id: &Uuid) -> EdtResult<()> {
+        if !self.storage.exists(entity_type, id) {
+            return Ok(());
+        }
+        let metadata = Self::metadata(&self.storage.read_entity_json(entity_type, id)?)?;
+        self.check_unlocked(&metadata.status)
+    }
+
+    /// Check an update against the stored entity and keep its status and history,
+    /// which only change through `transition`
+    ///
+    /// Returns the stored version, if any.
+    pub fn guard_update<T: StoredEntity>(&self, entity: &mut T) -> EdtResult<Option<T>> {
+        if !self.storage.exists(&T::ENTITY_TYPE, &entity.id()) {
+            return Ok(None);
+        }
+        let stored: T = self.storage.read(&entity.id())?;
+        let current = stored.metadata();
+        self.check_unlocked(&current.status)?;
+
+        let metadata = entity.metadata_mut();
+        metadata.status = current.status.clone();
+        metadata.status_history = current.status_history.clone();
+        Ok(Some(stored))
+    }
+
+    /// Check a rewrite of a whole entity outside its manager, such as restoring an earlier
+    /// revision, and keep its current status and history as `guard_update` does
+    ///
+    /// An entity that no longer exists may only come back in an editable status.
+    /// Returns the stored version, if any.
+    pub fn guard_rewrite(&self, entity_type: &EntityType, id: &Uuid, entity: &mut Value) -> EdtResult<Option<Value>> {
+        if !self.storage.exists(entity_type, id) {
+            self.check_unlocked(&Self::metadata(entity)?.status)?;
+            return Ok(None);
+        }
+        let stored = self.storage.read_entity_json(entity_type, id)?;
+        self.check_unlocked(&Self::metadata(&stored)?.status)?;
+
+        for field in ["status", "status_history"] {
+            entity["metadata"][field] = stored["metadata"][field].clone();
+        }
+        Ok(Some(stored))
+    }
+
+    /// Check an undo or redo of an entity file: the entity may not be locked before or
+    /// after, and its status may not change, since only `transition` changes it
+    pub fn check_file_rewrite(&self, entity_type: &EntityType, current: Option<&str>, target: Option<&str>) -> EdtResult<()> {
+        let status = |content: Option<&str>| -> EdtResult<Option<EntityStatus>> {
+            content
+                .map(|content| Ok(Self::metadata(&RonStorage::entity_ron_to_json(entity_type, content)?)?.status))
+                .transpose()
+        };
+        let (current, target) = (status(current)?, status(target)?);
+
+        for status in current.iter().chain(target.iter()) {
+            self.check_unlocked(status)?;
+        }
+        match (current, target) {
+            (Some(from), Some(to)) if from != to => Err(EdtError::InvalidTransition(format!(
+                "{:?} to {:?} can only be made through the workflow",
+                from, to
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the resolution of a merge conflict against both sides
+    ///
+    /// A side in a locked status must come through unchanged, so a merge never edits or
+    /// deletes locked content, and the merged status must be one of the sides' statuses.
+    /// `merged` is `Null` when the resolution deletes the entity.
+    pub fn check_merge(&self, ours: Option<&Value>, theirs: Option<&Value>, merged: &Value) -> EdtResult<()> {
+        let mut statuses = Vec::new();
+        for side in [ours, theirs].into_iter().flatten() {
+            let status = Self::metadata(side)?.status;
+            if merged.is_null() || Self::merge_content(side) != Self::merge_content(merged) {
+                self.check_unlocked(&status)?;
+            }
+            statuses.push(status);
+        }
+
+        if merged.is_null() {
+            return Ok(());
+        }
+        let status = Self::metadata(merged)?.status;
+        if !statuses.contains(&status) {
+            return Err(EdtError::InvalidTransition(format!(
+                "a merge cannot set the status to {:?}",
+                status
+            )));
+        }
+        Ok(())
+    }
+
+    /// Entity content without the bookkeeping a merge combines from both sides
+    fn merge_content(entity: &Value) -> Value {
+        let mut content = entity.clone();
+        if let Some(metadata) = content["metadata"].as_object_mut() {
+            metadata.remove("updated_at");
+            metadata.remove("status_history");
+        }
+        content
+    }
+
+    fn check_unlocked(&self, status: &EntityStatus) -> EdtResult<()> {
+        if read_config(&self.config)?.workflow.is_locked(status) {
+            return Err(EdtError::EntityLocked(status.clone()));
+        }
+        Ok(())
+    }
+
+    /// Messages for the guards the entity does not yet meet
+    fn unmet_guards<'a>(
+        &self,
+        guards: impl Iterator<Item = &'a TransitionGuard>,
+        entity_type: &EntityType,
+        id: &Uuid,
+    ) -> EdtResult<Vec<String>> {
+        let mut unmet = Vec::new();
+        for guard in guards {
+            let (met, requirement) = match &guard.condition {
+                TransitionCondition::LinkedEntity(required) => {
+                    let link_manager = self.link_manager.lock().map_err(|e| {
+                        EdtError::ValidationError(format!("Failed to lock link manager: {}", e))
+                    })?;
+                    let linked = link_manager.get_all_links(id).into_iter().any(|link| {
+                        (link.from_entity_id == *id && &link.to_entity_type == required)
+                            || (link.to_entity_id == *id && &link.from_entity_type == required)
+                    });
+                    (linked, format!("be linked to a {:?}", required))
+                }
+                TransitionCondition::Signature(meaning) => (
+                    self.signatures.has_valid_signature(entity_type, id, meaning)?,
+                    format!("have a valid {:?} signature", meaning),
+                ),
+            };
+            if !met {
+                unmet.push(format!(
+                    "{:?} must {} before it can be {:?}",
+                    guard.entity_type, requirement, guard.to
+                ));
+            }
+        }
+        Ok(unmet)
+    }
+
+    fn metadata(entity: &Value) -> EdtResult<EntityMetadata> {
+        Ok(serde_json::from_value(entity["metadata"].clone())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::EntityManager;
+    use crate::models::{LinkType, SignatureMeaning};
+    use tempfile::TempDir;
+
+    fn create_test_engine() -> (TempDir, EntityManager, Arc<Mutex<LinkManager>>, Arc<WorkflowEngine>) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(RonStorage::new(temp_dir.path()).unwrap());
+        let link_manager = Arc::new(Mutex::new(LinkManager::with_storage(Arc::clone(&storage)).unwrap()));
+        let config = Arc::new(RwLock::new(ProjectConfig::default()));
+        let manager = EntityManager::new(storage, Arc::clone(&link_manager), config).unwrap();
+        let engine = manager.workflow();
+        (temp_dir, manager, link_manager, engine)
+    }
+
+    fn create_requirement(manager: &EntityManager) -> Uuid {
+        manager
+            .create_requirement(
+                "Flow rate".to_string(),
+                "Pump delivers 5 L/min".to_string(),
+                "System Requirement".to_string(),
+            )
+            .unwrap()
+            .metadata
+            .id
+    }
+
+    #[test]
+    fn test_transition_records_history() {
+        let (_temp, manager, _links, engine) = create_test_engine();
+        let id = create_requirement(&manager);
+
+        let change = engine
+            .transition(&EntityType::Requirement, &id, EntityStatus::PendingApproval, "alice", None)
+            .unwrap();
+        assert_eq!(change.from, EntityStatus::Draft);
+
+        let requirement = manager.get_requirement(&id).unwrap();
+        assert_eq!(requirement.metadata.status, EntityStatus::PendingApproval);
+        assert_eq!(requirement.metadata.status_history.len(), 1);
+        assert_eq!(requirement.metadata.status_history[0].changed_by, "alice");
+    }
+
+    #[test]
+    fn test_unconfigured_transition_rejected() {
+        let (_temp, manager, _links, engine) = create_test_engine();
+        let id = create_requirement(&manager);
+
+        let result = engine.transition(&EntityType::Requirement, &id, EntityStatus::Released, "alice", None);
+        assert!(matches!(result, Err(EdtError::InvalidTransition(_))));
+
+        let result = engine.transition(&EntityType::Requirement, &id, EntityStatus::PendingApproval, " ", None);
+        assert!(matches!(result, Err(EdtError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_approval_guards() {
+        let (_temp, manager, links, engine) = create_test_engine();
+        let id = create_requirement(&manager);
+        engine
+            .transition(&EntityType::Requirement, &id, EntityStatus::PendingApproval, "alice", None)
+            .unwrap();
+
+        let available = engine.available_transitions(&EntityType::Requirement, &id).unwrap();
+        let approve = available.iter().find(|t| t.to == EntityStatus::Approved).unwrap();
+        assert_eq!(approve.blocked_by.len(), 2);
+        let result = engine.transition(&EntityType::Requirement, &id, EntityStatus::Approved, "bob", None);
+        assert!(matches!(result, Err(EdtError::InvalidTransition(_))));
+
+        let verification = manager
+            .create_verification(
+                "Flow test".to_string(),
+                "Measure flow".to_string(),
+                "Test".to_string(),
+                Vec::new(),
+                vec!["5 L/min".to_string()],
+                crate::models::TestPriority::High,
+            )
+            .unwrap();
+        links
+            .lock()
+            .unwrap()
+            .create_link(
+                verification.metadata.id,
+                EntityType::Verification,
+                id,
+                EntityType::Requirement,
+                LinkType::Verifies,
+                None,
+            )
+            .unwrap();
+
+        // Still needs an approval signature
+        let result = engine.transition(&EntityType::Requirement, &id, EntityStatus::Approved, "bob", None);
+        assert!(matches!(result, Err(EdtError::InvalidTransition(_))));
+
+        manager
+            .signatures()
+            .sign(&EntityType::Requirement, &id, "bob", "Approver", SignatureMeaning::Approved)
+            .unwrap();
+        engine
+            .transition(&EntityType::Requirement, &id, EntityStatus::Approved, "bob", None)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_locked_entity_is_read_only_until_revised() {
+        let (_temp, manager, _links, engine) = create_test_engine();
+        let risk = manager
+            .create_risk(
+                "Overpressure".to_string(),
+                "Line bursts".to_string(),
+                "Safety Risk".to_string(),
+                3,
+                4,
+            )
+            .unwrap();
+        let id = risk.metadata.id;
+        manager
+            .signatures()
+            .sign(&EntityType::Risk, &id, "alice", "Approver", SignatureMeaning::Approved)
+            .unwrap();
+        for status in [EntityStatus::PendingApproval, EntityStatus::Approved] {
+            engine.transition(&EntityType::Risk, &id, status, "alice", None).unwrap();
+        }
+
+        let mut edited = manager.get_risk(&id).unwrap();
+        edited.description = "Changed".to_string();
+        assert!(matches!(manager.update_risk(edited.clone()), Err(EdtError::EntityLocked(_))));
+        assert!(matches!(manager.delete_risk(&id), Err(EdtError::EntityLocked(_))));
+
+        // Revising needs a reason
+        let result = engine.transition(&EntityType::Risk, &id, EntityStatus::Draft, "alice", None);
+        assert!(matches!(result, Err(EdtError::InvalidTransition(_))));
+        engine
+            .transition(&EntityType::Risk, &id, EntityStatus::Draft, "alice", Some("Field feedback".to_string()))
+            .unwrap();
+
+        // Updates cannot change the status directly
+        edited.metadata.status = EntityStatus::Released;
+        let updated = manager.update_risk(edited).unwrap();
+        assert_eq!(updated.metadata.status, EntityStatus::Draft);
+        assert_eq!(updated.metadata.status_history.len(), 3);
+    }
+}
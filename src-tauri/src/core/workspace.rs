@@ -0,0 +1,242 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::core::{AppState, EdtResult, EdtError, EntityChange};
+use crate::storage::config_storage::CONFIG_FILE_NAME;
+use crate::storage::journal::atomic_write;
+
+/// File in the settings directory listing recently opened projects
+pub const RECENT_PROJECTS_FILE: &str = "recent_projects.toml";
+
+/// Number of projects kept in the recent list
+const MAX_RECENT_PROJECTS: usize = 10;
+
+/// Called with entity files changed on disk in the open project
+pub type ChangeListener = Arc<dyn Fn(&[EntityChange]) + Send + Sync>;
+
+/// A project shown in the recent-projects list
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProjectInfo {
+    pub path: PathBuf,
+    /// Folder name of the project
+    pub name: String,
+    pub last_opened: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RecentProjects {
+    #[serde(default)]
+    projects: Vec<ProjectInfo>,
+}
+
+/// The project open in this session, if any, and the recent-projects list
+///
+/// Commands take the workspace as managed state and get the open project's
+/// `AppState` from `project()`, so projects can be switched at runtime.
+pub struct Workspace {
+    /// Directory holding app-wide settings such as the recent-projects list
+    settings_dir: PathBuf,
+    project: RwLock<Option<Arc<AppState>>>,
+    listener: RwLock<Option<ChangeListener>>,
+}
+
+impl Workspace {
+    pub fn new(settings_dir: PathBuf) -> Self {
+        Self {
+            settings_dir,
+            project: RwLock::new(None),
+            listener: RwLock::new(None),
+        }
+    }
+
+    /// True if the folder holds a project configuration file
+    pub fn is_project(path: &Path) -> bool {
+        path.join(CONFIG_FILE_NAME).exists()
+    }
+
+    /// The open project, or `ProjectNotInitialized` if none is open
+    pub fn project(&self) -> EdtResult<Arc<AppState>> {
+        self.read_project()?
+            .as_ref()
+            .map(Arc::clone)
+            .ok_or(EdtError::ProjectNotInitialized)
+    }
+
+    /// Create a new project in `path` (creating the folder if needed) and open it
+    pub fn create_project(&self, path: &Path) -> EdtResult<ProjectInfo> {
+        if Self::is_project(path) {
+            return Err(EdtError::ProjectAlreadyExists);
+        }
+        fs::create_dir_all(path)?;
+        self.open(path)
+    }
+
+    /// Open an existing project, closing the current one
+    pub fn open_project(&self, path: &Path) -> EdtResult<ProjectInfo> {
+        if !Self::is_project(path) {
+            return Err(EdtError::ProjectNotInitialized);
+        }
+        self.open(path)
+    }
+
+    /// Close the open project; returns false if none was open
+    pub fn close_project(&self) -> EdtResult<bool> {
+        Ok(self.write_project()?.take().is_some())
+    }
+
+    /// Recently opened projects, most recent first, skipping folders that no longer hold a project
+    pub fn recent_projects(&self) -> EdtResult<Vec<ProjectInfo>> {
+        Ok(self
+            .load_recent()?
+            .projects
+            .into_iter()
+            .filter(|project| Self::is_project(&project.path))
+            .collect())
+    }
+
+    /// Forward file changes in whichever project is open (e.g. to the frontend)
+    pub fn set_change_listener(&self, listener: ChangeListener) -> EdtResult<()> {
+        let mut current = self.listener.write().map_err(|e| {
+            EdtError::ValidationError(format!("Failed to write change listener: {}", e))
+        })?;
+        *current = Some(listener);
+        Ok(())
+    }
+
+    fn open(&self, path: &Path) -> EdtResult<ProjectInfo> {
+        let path = fs::canonicalize(path)?;
+        let state = AppState::new(path.clone())?;
+
+        let listener = self
+            .listener
+            .read()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to read change listener: {}", e)))?
+            .clone();
+        if let Some(listener) = listener {
+            state.watch_files(move |changes| listener(changes))?;
+        }
+
+        // Replacing the state drops the previous project's file watcher
+        *self.write_project()? = Some(Arc::new(state));
+        self.remember(&path)
+    }
+
+    /// Move the project to the top of the recent list
+    fn remember(&self, path: &Path) -> EdtResult<ProjectInfo> {
+        let info = ProjectInfo {
+            path: path.to_path_buf(),
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string()),
+            last_opened: Utc::now(),
+        };
+
+        let mut recent = self.load_recent()?;
+        recent.projects.retain(|project| project.path != info.path);
+        recent.projects.insert(0, info.clone());
+        recent.projects.truncate(MAX_RECENT_PROJECTS);
+
+        fs::create_dir_all(&self.settings_dir)?;
+        let serialized = toml::to_string_pretty(&recent)?;
+        atomic_write(&self.settings_dir.join(RECENT_PROJECTS_FILE), serialized.as_bytes())?;
+        Ok(info)
+    }
+
+    fn load_recent(&self) -> EdtResult<RecentProjects> {
+        let path = self.settings_dir.join(RECENT_PROJECTS_FILE);
+        if !path.exists() {
+            return Ok(RecentProjects::default());
+        }
+        Ok(toml::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    fn read_project(&self) -> EdtResult<RwLockReadGuard<'_, Option<Arc<AppState>>>> {
+        self.project
+            .read()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to read open project: {}", e)))
+    }
+
+    fn write_project(&self) -> EdtResult<RwLockWriteGuard<'_, Option<Arc<AppState>>>> {
+        self.project
+            .write()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to write open project: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_test_workspace() -> (TempDir, Workspace) {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = Workspace::new(temp_dir.path().join("settings"));
+        (temp_dir, workspace)
+    }
+
+    #[test]
+    fn test_no_project_open_initially() {
+        let (_temp, workspace) = create_test_workspace();
+
+        assert!(matches!(workspace.project(), Err(EdtError::ProjectNotInitialized)));
+        assert!(!workspace.close_project().unwrap());
+    }
+
+    #[test]
+    fn test_create_open_and_close_project() {
+        let (temp, workspace) = create_test_workspace();
+        let path = temp.path().join("pump");
+
+        let info = workspace.create_project(&path).unwrap();
+        assert_eq!(info.name, "pump");
+        assert!(Workspace::is_project(&path));
+        assert!(workspace.project().unwrap().entity_manager.list_task_ids().unwrap().is_empty());
+
+        let result = workspace.create_project(&path);
+        assert!(matches!(result, Err(EdtError::ProjectAlreadyExists)));
+
+        assert!(workspace.close_project().unwrap());
+        assert!(matches!(workspace.project(), Err(EdtError::ProjectNotInitialized)));
+
+        workspace.open_project(&path).unwrap();
+        assert_eq!(workspace.project().unwrap().project_root(), &info.path);
+    }
+
+    #[test]
+    fn test_open_requires_project_config() {
+        let (temp, workspace) = create_test_workspace();
+
+        let result = workspace.open_project(temp.path());
+        assert!(matches!(result, Err(EdtError::ProjectNotInitialized)));
+    }
+
+    #[test]
+    fn test_switching_projects_updates_recent_list() {
+        let (temp, workspace) = create_test_workspace();
+        let first = temp.path().join("first");
+        let second = temp.path().join("second");
+
+        workspace.create_project(&first).unwrap();
+        workspace.create_project(&second).unwrap();
+        workspace.open_project(&first).unwrap();
+
+        let names: Vec<String> = workspace
+            .recent_projects()
+            .unwrap()
+            .into_iter()
+            .map(|project| project.name)
+            .collect();
+        assert_eq!(names, vec!["first", "second"]);
+
+        // Projects deleted from disk drop out of the list
+        fs::remove_file(second.join(CONFIG_FILE_NAME)).unwrap();
+        assert_eq!(workspace.recent_projects().unwrap().len(), 1);
+
+        // The list survives a restart
+        let reopened = Workspace::new(temp.path().join("settings"));
+        assert_eq!(reopened.recent_projects().unwrap()[0].name, "first");
+    }
+}
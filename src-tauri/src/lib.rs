@@ -6,13 +6,17 @@ mod commands;
 mod utils;
 
 // Re-exports
-pub use core::{EdtError, EdtResult, AppState};
+pub use core::{EdtError, EdtResult, AppState, Workspace};
+pub use core::merge::run_merge_driver;
+use std::sync::Arc;
+use core::ENTITY_CHANGED_EVENT;
+use tauri::{Emitter, Manager};
 use commands::{
     create_task, get_task, update_task, delete_task, list_tasks,
     create_milestone, get_milestone, update_milestone, delete_milestone, list_milestones,
     create_resource, get_resource, update_resource, delete_resource, list_resources,
     create_calendar, get_calendar, update_calendar, delete_calendar, list_calendars,
-    create_baseline, get_baseline, update_baseline, delete_baseline, list_baselines,
+    create_baseline, get_baseline, update_baseline, delete_baseline, list_baselines, diff_task_baseline,
     calculate_critical_path, calculate_evm,
     calculate_worst_case, calculate_rss, calculate_monte_carlo,
     generate_bom,
@@ -30,6 +34,21 @@ use commands::{
     create_risk, get_risk, update_risk, delete_risk, list_risks,
     create_hazard, get_hazard, update_hazard, delete_hazard, list_hazards,
     create_risk_control, get_risk_control, update_risk_control, delete_risk_control, list_risk_controls,
+    create_link, delete_link, get_links_from, get_links_to, get_all_links, get_impacted_entities,
+    preview_delete,
+    get_config, update_config, revalidate_entities,
+    run_health_check,
+    query_entities, search_entities, refresh_cache, resolve_entity_id,
+    migrate_project,
+    init_repository, commit_changes, get_entity_history, diff_entity_revisions, restore_entity_revision,
+    get_merge_conflicts, resolve_merge_conflict,
+    undo, redo, get_undo_history, begin_undo_group, end_undo_group, reset_undo_group,
+    create_project, open_project, close_project, get_current_project, get_recent_projects,
+    transition_entity_status, get_available_transitions,
+    sign_entity, get_entity_signatures,
+    query_audit_log, get_entity_audit_history, verify_audit_log, get_audit_user, set_audit_user,
+    create_comment, reply_to_comment, get_comment, edit_comment, resolve_comment, delete_comment,
+    get_comment_threads, get_unresolved_comment_count, mark_comments_seen, get_unseen_comments,
 };
 
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
@@ -40,23 +59,30 @@ fn greet(name: &str) -> String {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    // Initialize app state with a default project directory
-    // In a real app, this would be selected by the user
-    let project_root = std::env::current_dir()
-        .unwrap()
-        .join("edt_project");
-
-    let app_state = match AppState::new(project_root) {
-        Ok(state) => state,
-        Err(e) => {
-            eprintln!("Failed to initialize app state: {}", e);
-            std::process::exit(1);
-        }
-    };
-
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
-        .manage(app_state)
+        .setup(|app| {
+            let settings_dir = app.path().app_config_dir()?;
+            let workspace = Workspace::new(settings_dir);
+
+            // Live reload for files edited outside the app or pulled from git
+            let handle = app.handle().clone();
+            workspace.set_change_listener(Arc::new(move |changes| {
+                for change in changes {
+                    let _ = handle.emit(ENTITY_CHANGED_EVENT, change.clone());
+                }
+            }))?;
+
+            // Reopen the last project; otherwise the frontend asks for one
+            if let Some(last) = workspace.recent_projects()?.first() {
+                if let Err(e) = workspace.open_project(&last.path) {
+                    eprintln!("Failed to open {}: {}", last.path.display(), e);
+                }
+            }
+
+            app.manage(workspace);
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             greet,
             // Task commands
@@ -89,6 +115,7 @@ pub fn run() {
             update_baseline,
             delete_baseline,
             list_baselines,
+            diff_task_baseline,
             // Calculation commands
             calculate_critical_path,
             calculate_evm,
@@ -182,6 +209,72 @@ pub fn run() {
             update_risk_control,
             delete_risk_control,
             list_risk_controls,
+            // Link commands
+            create_link,
+            delete_link,
+            get_links_from,
+            get_links_to,
+            get_all_links,
+            get_impacted_entities,
+            // Delete commands
+            preview_delete,
+            // Config commands
+            get_config,
+            update_config,
+            revalidate_entities,
+            // Health check commands
+            run_health_check,
+            // Query cache commands
+            query_entities,
+            search_entities,
+            refresh_cache,
+            resolve_entity_id,
+            // Migration commands
+            migrate_project,
+            // Version control commands
+            init_repository,
+            commit_changes,
+            get_entity_history,
+            diff_entity_revisions,
+            restore_entity_revision,
+            get_merge_conflicts,
+            resolve_merge_conflict,
+            // Undo/redo commands
+            undo,
+            redo,
+            get_undo_history,
+            begin_undo_group,
+            end_undo_group,
+            reset_undo_group,
+            // Project commands
+            create_project,
+            open_project,
+            close_project,
+            get_current_project,
+            get_recent_projects,
+            // Workflow commands
+            transition_entity_status,
+            get_available_transitions,
+            // Signature commands
+            sign_entity,
+            get_entity_signatures,
+            // Audit commands
+            query_audit_log,
+            get_entity_audit_history,
+            verify_audit_log,
+            get_audit_user,
+            set_audit_user,
+            // Comment commands
+            create_comment,
+            reply_to_comment,
+            get_comment,
+            edit_comment,
+            resolve_comment,
+            delete_comment,
+            get_comment_threads,
+            get_unresolved_comment_count,
+            mark_comments_seen,
+            get_unseen_comments,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use crate::models::EntityMetadata;
+use crate::models::entity::impl_stored_entity;
+
+/// A comment in a discussion on an entity
+///
+/// Comments attach to the entity they discuss through a `Comments` link, and
+/// replies to the comment they answer through a `Replies` link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    pub metadata: EntityMetadata,
+    pub author: String,
+    pub body: String,
+    /// Last time the body was edited; `None` if it never was
+    pub edited_at: Option<DateTime<Utc>>,
+    pub resolved: bool,
+}
+
+impl_stored_entity!(Comment => Comment);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EntityType;
+
+    #[test]
+    fn test_comment_serialization() {
+        let comment = Comment {
+            metadata: EntityMetadata::new(EntityType::Comment),
+            author: "Dana Reyes".to_string(),
+            body: "Is 5 seconds measured from button press or from power good?".to_string(),
+            edited_at: None,
+            resolved: false,
+        };
+
+        let serialized = ron::to_string(&comment).expect("Failed to serialize");
+        assert!(serialized.contains("Dana Reyes"));
+
+        let deserialized: Comment = ron::from_str(&serialized).expect("Failed to deserialize");
+        assert_eq!(deserialized.body, comment.body);
+        assert_eq!(deserialized.metadata.entity_type, EntityType::Comment);
+        assert!(!deserialized.resolved);
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use std::collections::HashMap;
+use crate::models::{EntityStatus, EntityType, LinkType, SignatureMeaning};
 
 /// Project-wide configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,8 +25,217 @@ pub struct ProjectConfig {
     pub warn_risk_without_control: bool,
     pub warn_risk_without_verification: bool,
 
+    // Requirements traceability
+    #[serde(default = "default_true")]
+    pub warn_requirement_without_verification: bool,
+    #[serde(default = "default_true")]
+    pub warn_requirement_without_design: bool,
+
     // General entities
     pub general_entity_types: Vec<String>,
+
+    // Roles a signer may sign under
+    #[serde(default = "default_signature_roles")]
+    pub signature_roles: Vec<String>,
+
+    // Links allowed in addition to the built-in link rules
+    #[serde(default)]
+    pub link_rules: Vec<LinkRuleConfig>,
+
+    // What happens to links (and owned children) when an entity is deleted
+    #[serde(default = "default_delete_policies")]
+    pub delete_policies: HashMap<EntityType, DeletePolicy>,
+
+    // Prefixes of the human-readable IDs assigned at creation, e.g. REQ for REQ-0001
+    #[serde(default = "default_id_prefixes")]
+    pub id_prefixes: HashMap<EntityType, String>,
+
+    // Allowed status transitions, their guards and which statuses are read-only
+    #[serde(default)]
+    pub workflow: WorkflowConfig,
+}
+
+/// An extra allowed (from type, link type, to type) combination
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LinkRuleConfig {
+    pub from_entity_type: EntityType,
+    pub link_type: LinkType,
+    pub to_entity_type: EntityType,
+}
+
+/// Referential integrity policy applied when deleting an entity
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DeletePolicy {
+    /// Refuse to delete while any link references the entity
+    Block,
+    /// Delete the entity together with every link that references it
+    CascadeLinks,
+    /// Like `CascadeLinks`, and also delete owned children recursively (e.g. a Component's Features)
+    CascadeChildren,
+    /// Delete only the entity and leave its links dangling
+    Orphan,
+}
+
+/// Status lifecycle rules applied to every entity type
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowConfig {
+    pub transitions: Vec<StatusTransition>,
+    #[serde(default)]
+    pub guards: Vec<TransitionGuard>,
+    /// Entities in these statuses cannot be edited or deleted until revised back to an editable status
+    pub locked_statuses: Vec<EntityStatus>,
+}
+
+/// An allowed move from one status to another
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StatusTransition {
+    pub from: EntityStatus,
+    pub to: EntityStatus,
+    /// The transition must give a reason, e.g. when rejecting or revising
+    #[serde(default)]
+    pub requires_reason: bool,
+}
+
+/// A condition an entity type must meet before it may enter a status
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TransitionGuard {
+    pub entity_type: EntityType,
+    pub to: EntityStatus,
+    pub condition: TransitionCondition,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum TransitionCondition {
+    /// Linked (in either direction) to at least one entity of this type
+    LinkedEntity(EntityType),
+    /// Holds a valid signature with this meaning on its current content
+    Signature(SignatureMeaning),
+}
+
+impl Default for WorkflowConfig {
+    fn default() -> Self {
+        let transition = |from, to, requires_reason| StatusTransition { from, to, requires_reason };
+        Self {
+            transitions: vec![
+                transition(EntityStatus::Draft, EntityStatus::PendingApproval, false),
+                transition(EntityStatus::PendingApproval, EntityStatus::Approved, false),
+                transition(EntityStatus::PendingApproval, EntityStatus::Draft, true),
+                transition(EntityStatus::Approved, EntityStatus::Released, false),
+                // Revisions reopen approved or released entities for editing
+                transition(EntityStatus::Approved, EntityStatus::Draft, true),
+                transition(EntityStatus::Released, EntityStatus::Draft, true),
+            ],
+            guards: vec![
+                TransitionGuard {
+                    entity_type: EntityType::Requirement,
+                    to: EntityStatus::Approved,
+                    condition: TransitionCondition::LinkedEntity(EntityType::Verification),
+                },
+                // Regulated records need an approval signature
+                TransitionGuard {
+                    entity_type: EntityType::Requirement,
+                    to: EntityStatus::Approved,
+                    condition: TransitionCondition::Signature(SignatureMeaning::Approved),
+                },
+                TransitionGuard {
+                    entity_type: EntityType::Risk,
+                    to: EntityStatus::Approved,
+                    condition: TransitionCondition::Signature(SignatureMeaning::Approved),
+                },
+                TransitionGuard {
+                    entity_type: EntityType::Validation,
+                    to: EntityStatus::Approved,
+                    condition: TransitionCondition::Signature(SignatureMeaning::Approved),
+                },
+            ],
+            locked_statuses: vec![EntityStatus::Approved, EntityStatus::Released],
+        }
+    }
+}
+
+impl WorkflowConfig {
+    /// The transition rule from one status to another, if allowed
+    pub fn transition(&self, from: &EntityStatus, to: &EntityStatus) -> Option<&StatusTransition> {
+        self.transitions.iter().find(|t| &t.from == from && &t.to == to)
+    }
+
+    /// Guards an entity type must pass to enter a status
+    pub fn guards_for<'a>(
+        &'a self,
+        entity_type: &'a EntityType,
+        to: &'a EntityStatus,
+    ) -> impl Iterator<Item = &'a TransitionGuard> {
+        self.guards
+            .iter()
+            .filter(move |guard| &guard.entity_type == entity_type && &guard.to == to)
+    }
+
+    pub fn is_locked(&self, status: &EntityStatus) -> bool {
+        self.locked_statuses.contains(status)
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_signature_roles() -> Vec<String> {
+    vec![
+        "Author".to_string(),
+        "Reviewer".to_string(),
+        "Approver".to_string(),
+        "Quality Assurance".to_string(),
+    ]
+}
+
+fn default_delete_policies() -> HashMap<EntityType, DeletePolicy> {
+    let mut policies = HashMap::new();
+
+    // Traceability records must be unlinked explicitly before they can go
+    for entity_type in [
+        EntityType::Requirement,
+        EntityType::Hazard,
+        EntityType::Risk,
+        EntityType::RiskControl,
+    ] {
+        policies.insert(entity_type, DeletePolicy::Block);
+    }
+    policies.insert(EntityType::Component, DeletePolicy::CascadeChildren);
+
+    policies
+}
+
+fn default_id_prefix(entity_type: &EntityType) -> &'static str {
+    match entity_type {
+        EntityType::Task => "TSK",
+        EntityType::Milestone => "MS",
+        EntityType::Resource => "RES",
+        EntityType::Calendar => "CAL",
+        EntityType::Baseline => "BL",
+        EntityType::Requirement => "REQ",
+        EntityType::Hazard => "HAZ",
+        EntityType::Risk => "RSK",
+        EntityType::RiskControl => "RC",
+        EntityType::Assembly => "ASM",
+        EntityType::Component => "CMP",
+        EntityType::Feature => "FEA",
+        EntityType::Mate => "MATE",
+        EntityType::Stackup => "STK",
+        EntityType::Supplier => "SUP",
+        EntityType::Quote => "QUO",
+        EntityType::Verification => "VER",
+        EntityType::Validation => "VAL",
+        EntityType::Manufacturing => "MFG",
+        EntityType::Comment => "CMT",
+        EntityType::General => "GEN",
+    }
+}
+
+fn default_id_prefixes() -> HashMap<EntityType, String> {
+    EntityType::all()
+        .iter()
+        .map(|entity_type| (entity_type.clone(), default_id_prefix(entity_type).to_string()))
+        .collect()
 }
 
 impl Default for ProjectConfig {
@@ -67,11 +277,18 @@ impl Default for ProjectConfig {
             warn_hazard_without_risk: true,
             warn_risk_without_control: true,
             warn_risk_without_verification: true,
+            warn_requirement_without_verification: true,
+            warn_requirement_without_design: true,
             general_entity_types: vec![
                 "Test Equipment".to_string(),
                 "Software Module".to_string(),
                 "Standard Operating Procedure".to_string(),
             ],
+            signature_roles: default_signature_roles(),
+            link_rules: vec![],
+            delete_policies: default_delete_policies(),
+            id_prefixes: default_id_prefixes(),
+            workflow: WorkflowConfig::default(),
         }
     }
 }
@@ -87,6 +304,22 @@ impl ProjectConfig {
     pub fn is_risk_acceptable(&self, risk_score: u32) -> bool {
         risk_score <= self.acceptable_risk_threshold
     }
+
+    /// Get the delete policy for an entity type (defaults to cascading links)
+    pub fn delete_policy(&self, entity_type: &EntityType) -> DeletePolicy {
+        self.delete_policies
+            .get(entity_type)
+            .copied()
+            .unwrap_or(DeletePolicy::CascadeLinks)
+    }
+
+    /// Get the human-readable ID prefix for an entity type (falls back to the built-in prefix)
+    pub fn id_prefix(&self, entity_type: &EntityType) -> String {
+        self.id_prefixes
+            .get(entity_type)
+            .cloned()
+            .unwrap_or_else(|| default_id_prefix(entity_type).to_string())
+    }
 }
 
 #[cfg(test)]
@@ -181,6 +414,83 @@ mod tests {
         assert!(!config.warn_hazard_without_risk);
     }
 
+    #[test]
+    fn test_link_rules_serialization_toml() {
+        let mut config = ProjectConfig::default();
+        config.link_rules.push(LinkRuleConfig {
+            from_entity_type: EntityType::Manufacturing,
+            link_type: LinkType::Verifies,
+            to_entity_type: EntityType::Requirement,
+        });
+
+        let serialized = toml::to_string(&config).expect("Failed to serialize");
+        assert!(serialized.contains("[[link_rules]]"));
+
+        let deserialized: ProjectConfig = toml::from_str(&serialized)
+            .expect("Failed to deserialize");
+        assert_eq!(deserialized.link_rules, config.link_rules);
+    }
+
+    #[test]
+    fn test_link_rules_default_when_missing() {
+        let mut value = toml::Value::try_from(ProjectConfig::default()).unwrap();
+        value.as_table_mut().unwrap().remove("link_rules");
+
+        let deserialized: ProjectConfig = value.try_into().expect("Failed to deserialize");
+        assert!(deserialized.link_rules.is_empty());
+    }
+
+    #[test]
+    fn test_requirement_warnings_default_when_missing() {
+        let mut value = toml::Value::try_from(ProjectConfig::default()).unwrap();
+        let table = value.as_table_mut().unwrap();
+        table.remove("warn_requirement_without_verification");
+        table.remove("warn_requirement_without_design");
+
+        let deserialized: ProjectConfig = value.try_into().expect("Failed to deserialize");
+        assert!(deserialized.warn_requirement_without_verification);
+        assert!(deserialized.warn_requirement_without_design);
+    }
+
+    #[test]
+    fn test_delete_policies() {
+        let mut config = ProjectConfig::default();
+
+        assert_eq!(config.delete_policy(&EntityType::Requirement), DeletePolicy::Block);
+        assert_eq!(config.delete_policy(&EntityType::Component), DeletePolicy::CascadeChildren);
+        assert_eq!(config.delete_policy(&EntityType::Task), DeletePolicy::CascadeLinks);
+
+        config.delete_policies.insert(EntityType::Task, DeletePolicy::Block);
+        assert_eq!(config.delete_policy(&EntityType::Task), DeletePolicy::Block);
+
+        // Round-trips through TOML with entity types as keys
+        let serialized = toml::to_string(&config).expect("Failed to serialize");
+        let deserialized: ProjectConfig = toml::from_str(&serialized)
+            .expect("Failed to deserialize");
+        assert_eq!(deserialized.delete_policy(&EntityType::Task), DeletePolicy::Block);
+    }
+
+    #[test]
+    fn test_id_prefixes() {
+        let mut config = ProjectConfig::default();
+
+        assert_eq!(config.id_prefix(&EntityType::Requirement), "REQ");
+        assert_eq!(config.id_prefix(&EntityType::Risk), "RSK");
+        assert_eq!(config.id_prefix(&EntityType::Verification), "VER");
+
+        // A type removed from the table falls back to its built-in prefix
+        config.id_prefixes.remove(&EntityType::Risk);
+        config.id_prefixes.insert(EntityType::Requirement, "SYS".to_string());
+        assert_eq!(config.id_prefix(&EntityType::Risk), "RSK");
+        assert_eq!(config.id_prefix(&EntityType::Requirement), "SYS");
+
+        // Older config files without the table get the defaults
+        let mut value = toml::Value::try_from(ProjectConfig::default()).unwrap();
+        value.as_table_mut().unwrap().remove("id_prefixes");
+        let deserialized: ProjectConfig = value.try_into().expect("Failed to deserialize");
+        assert_eq!(deserialized.id_prefixes.len(), EntityType::all().len());
+    }
+
     #[test]
     fn test_risk_levels() {
         let config = ProjectConfig::default();
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::NaiveDate;
 use crate::models::EntityMetadata;
+use crate::models::entity::impl_stored_entity;
 
 /// Assembly entity - represents a collection of components
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -214,6 +215,16 @@ pub struct CostEstimate {
     pub r_squared: Option<f64>,
 }
 
+impl_stored_entity!(
+    Assembly => Assembly,
+    Component => Component,
+    Feature => Feature,
+    Mate => Mate,
+    Stackup => Stackup,
+    Supplier => Supplier,
+    Quote => Quote,
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,16 +1,26 @@
 use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 
+/// Schema version written to new and migrated entity files
+pub const CURRENT_SCHEMA_VERSION: &str = "1.0.0";
+
 /// Entity metadata shared by all entity types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct EntityMetadata {
     pub id: Uuid,
+    /// Human-readable ID such as `REQ-0001`; absent on entities created before IDs were assigned
+    #[serde(default)]
+    pub human_id: Option<String>,
     pub entity_type: EntityType,
     pub schema_version: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub status: EntityStatus,
+    /// Status transitions, oldest first
+    #[serde(default)]
+    pub status_history: Vec<StatusChange>,
 }
 
 impl EntityMetadata {
@@ -18,15 +28,92 @@ impl EntityMetadata {
         let now = Utc::now();
         Self {
             id: Uuid::new_v4(),
+            human_id: None,
             entity_type,
-            schema_version: "1.0.0".to_string(),
+            schema_version: CURRENT_SCHEMA_VERSION.to_string(),
             created_at: now,
             updated_at: now,
             status: EntityStatus::Draft,
+            status_history: Vec::new(),
         }
     }
 }
 
+/// Reference to an entity by ID and type
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct EntityRef {
+    pub entity_id: Uuid,
+    pub entity_type: EntityType,
+}
+
+/// An entity persisted as one RON file per instance in its type's folder
+pub trait StoredEntity: Serialize + DeserializeOwned + Send {
+    /// Entity type, which selects the storage folder
+    const ENTITY_TYPE: EntityType;
+
+    fn metadata(&self) -> &EntityMetadata;
+
+    fn metadata_mut(&mut self) -> &mut EntityMetadata;
+
+    fn id(&self) -> Uuid {
+        self.metadata().id
+    }
+}
+
+/// Implement `StoredEntity` for model structs with a `metadata: EntityMetadata` field
+macro_rules! impl_stored_entity {
+    ($($entity:ty => $entity_type:ident),* $(,)?) => {
+        $(
+            impl $crate::models::StoredEntity for $entity {
+                const ENTITY_TYPE: $crate::models::EntityType = $crate::models::EntityType::$entity_type;
+
+                fn metadata(&self) -> &$crate::models::EntityMetadata {
+                    &self.metadata
+                }
+
+                fn metadata_mut(&mut self) -> &mut $crate::models::EntityMetadata {
+                    &mut self.metadata
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_stored_entity;
+
+/// Invoke `$callback!` with the list of every stored model and its `EntityType` variant
+///
+/// This is the one place entity types with a model file are enumerated; type-keyed
+/// helpers such as `RonStorage::entity_ron_to_json` are generated from it.
+macro_rules! for_each_stored_entity {
+    ($callback:ident) => {
+        $callback! {
+            $crate::models::Task => Task,
+            $crate::models::Milestone => Milestone,
+            $crate::models::Resource => Resource,
+            $crate::models::Calendar => Calendar,
+            $crate::models::Baseline => Baseline,
+            $crate::models::Requirement => Requirement,
+            $crate::models::Hazard => Hazard,
+            $crate::models::Risk => Risk,
+            $crate::models::RiskControl => RiskControl,
+            $crate::models::Assembly => Assembly,
+            $crate::models::Component => Component,
+            $crate::models::Feature => Feature,
+            $crate::models::Mate => Mate,
+            $crate::models::Stackup => Stackup,
+            $crate::models::Supplier => Supplier,
+            $crate::models::Quote => Quote,
+            $crate::models::Verification => Verification,
+            $crate::models::Validation => Validation,
+            $crate::models::Manufacturing => Manufacturing,
+            $crate::models::Comment => Comment,
+        }
+    };
+}
+
+pub(crate) use for_each_stored_entity;
+
 /// Entity status workflow
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum EntityStatus {
@@ -36,6 +123,16 @@ pub enum EntityStatus {
     Released,
 }
 
+/// A recorded status transition: who moved the entity, when and why
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StatusChange {
+    pub from: EntityStatus,
+    pub to: EntityStatus,
+    pub changed_by: String,
+    pub changed_at: DateTime<Utc>,
+    pub reason: Option<String>,
+}
+
 /// All supported entity types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum EntityType {
@@ -78,6 +175,38 @@ pub enum EntityType {
 }
 
 impl EntityType {
+    /// All entity types, in declaration order
+    pub fn all() -> &'static [EntityType] {
+        &[
+            EntityType::Task,
+            EntityType::Milestone,
+            EntityType::Resource,
+            EntityType::Calendar,
+            EntityType::Baseline,
+            EntityType::Requirement,
+            EntityType::Hazard,
+            EntityType::Risk,
+            EntityType::RiskControl,
+            EntityType::Assembly,
+            EntityType::Component,
+            EntityType::Feature,
+            EntityType::Mate,
+            EntityType::Stackup,
+            EntityType::Supplier,
+            EntityType::Quote,
+            EntityType::Verification,
+            EntityType::Validation,
+            EntityType::Manufacturing,
+            EntityType::Comment,
+            EntityType::General,
+        ]
+    }
+
+    /// Entity type stored in the given folder
+    pub fn from_folder_name(folder: &str) -> Option<EntityType> {
+        Self::all().iter().find(|t| t.folder_name() == folder).cloned()
+    }
+
     /// Returns the folder name for this entity type
     pub fn folder_name(&self) -> &'static str {
         match self {
@@ -147,6 +276,22 @@ mod tests {
         assert_eq!(EntityType::Comment.folder_name(), "comments");
     }
 
+    #[test]
+    fn test_entity_type_from_folder_name() {
+        for entity_type in EntityType::all() {
+            assert_eq!(EntityType::from_folder_name(entity_type.folder_name()).as_ref(), Some(entity_type));
+        }
+        assert_eq!(EntityType::from_folder_name("unknown"), None);
+    }
+
+    #[test]
+    fn test_entity_type_all() {
+        let all = EntityType::all();
+        assert_eq!(all.len(), 21);
+        assert!(all.contains(&EntityType::Task));
+        assert!(all.contains(&EntityType::General));
+    }
+
     #[test]
     fn test_entity_type_equality() {
         assert_eq!(EntityType::Task, EntityType::Task);
@@ -76,6 +76,37 @@ pub enum LinkType {
     Manufactures,   // Manufacturing → Component/Assembly
 }
 
+impl LinkType {
+    /// Hierarchical link types must stay acyclic; associative ones may form loops
+    pub fn is_hierarchical(&self) -> bool {
+        matches!(
+            self,
+            LinkType::Parent
+                | LinkType::Child
+                | LinkType::Contains
+                | LinkType::PartOf
+                | LinkType::Derives
+                | LinkType::Replies
+        )
+    }
+
+    /// The type this link reads as in its hierarchy's forward direction, and whether it is reversed
+    ///
+    /// `A PartOf B` is the same relation as `B Contains A`, and `Child` likewise mirrors `Parent`.
+    pub fn canonical(&self) -> (LinkType, bool) {
+        match self {
+            LinkType::PartOf => (LinkType::Contains, true),
+            LinkType::Child => (LinkType::Parent, true),
+            other => (other.clone(), false),
+        }
+    }
+
+    /// Owning link types: the target only exists as part of the source
+    pub fn is_owning(&self) -> bool {
+        matches!(self, LinkType::HasFeature)
+    }
+}
+
 /// Optional metadata for links
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinkMetadata {
@@ -145,6 +176,31 @@ mod tests {
         assert_ne!(LinkType::Mitigates, LinkType::Hazardous);
     }
 
+    #[test]
+    fn test_link_type_is_hierarchical() {
+        assert!(LinkType::Contains.is_hierarchical());
+        assert!(LinkType::Derives.is_hierarchical());
+        assert!(LinkType::Parent.is_hierarchical());
+        assert!(LinkType::Replies.is_hierarchical());
+        assert!(!LinkType::Related.is_hierarchical());
+        assert!(!LinkType::Satisfies.is_hierarchical());
+    }
+
+    #[test]
+    fn test_link_type_canonical() {
+        assert_eq!(LinkType::PartOf.canonical(), (LinkType::Contains, true));
+        assert_eq!(LinkType::Contains.canonical(), (LinkType::Contains, false));
+        assert_eq!(LinkType::Child.canonical(), (LinkType::Parent, true));
+        assert_eq!(LinkType::Derives.canonical(), (LinkType::Derives, false));
+    }
+
+    #[test]
+    fn test_link_type_is_owning() {
+        assert!(LinkType::HasFeature.is_owning());
+        assert!(!LinkType::Contains.is_owning());
+        assert!(!LinkType::Mates.is_owning());
+    }
+
     #[test]
     fn test_link_serialization() {
         let from_id = Uuid::new_v4();
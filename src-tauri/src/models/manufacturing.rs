@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use crate::models::EntityMetadata;
+use crate::models::entity::impl_stored_entity;
 
 /// Manufacturing process status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -101,6 +102,8 @@ pub struct Manufacturing {
     pub nonconformances: Vec<String>,
 }
 
+impl_stored_entity!(Manufacturing => Manufacturing);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,8 +8,10 @@ pub mod config;
 pub mod design;
 pub mod testing;
 pub mod manufacturing;
+pub mod signature;
+pub mod comment;
 
-pub use entity::{EntityMetadata, EntityStatus, EntityType};
+pub use entity::{EntityMetadata, EntityRef, EntityStatus, EntityType, StatusChange, StoredEntity, CURRENT_SCHEMA_VERSION};
 pub use task::{
     Task, TaskType, SchedulingMode, ResourceAssignment, TaskDependency,
     DependencyType, TaskBaseline, Milestone, Resource, ResourceType,
@@ -18,7 +20,7 @@ pub use task::{
 pub use requirement::Requirement;
 pub use risk::{Hazard, Risk, RiskControl};
 pub use link::{Link, LinkType, LinkMetadata};
-pub use config::ProjectConfig;
+pub use config::{ProjectConfig, DeletePolicy, TransitionGuard, TransitionCondition};
 pub use design::{
     Assembly, Component, Feature, FeatureType, DistributionType,
     Mate, MateType, MateAnalysisResult,
@@ -34,3 +36,5 @@ pub use manufacturing::{
     Manufacturing, ProcessStatus, QualityStatus,
     WorkInstructionStep, QualityCheckpoint, ProductionBatch,
 };
+pub use signature::{Signature, SignatureMeaning};
+pub use comment::Comment;
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::models::EntityMetadata;
+use crate::models::entity::impl_stored_entity;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Requirement {
@@ -13,6 +14,8 @@ pub struct Requirement {
     pub verification_method: Option<String>,
 }
 
+impl_stored_entity!(Requirement => Requirement);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::models::EntityMetadata;
+use crate::models::entity::impl_stored_entity;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hazard {
@@ -23,11 +24,15 @@ pub struct Risk {
     pub probability: u32,
     pub severity: u32,
     pub risk_score: u32, // Calculated from matrix
+    #[serde(default)]
+    pub is_acceptable: bool, // risk_score within the configured threshold
 
     // Residual risk (after controls)
     pub residual_probability: Option<u32>,
     pub residual_severity: Option<u32>,
     pub residual_risk_score: Option<u32>,
+    #[serde(default)]
+    pub residual_is_acceptable: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,6 +44,12 @@ pub struct RiskControl {
     pub control_type: String, // From config
 }
 
+impl_stored_entity!(
+    Hazard => Hazard,
+    Risk => Risk,
+    RiskControl => RiskControl,
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,9 +92,11 @@ mod tests {
             probability: 2,
             severity: 5,
             risk_score: 10, // 2 * 5 from matrix
+            is_acceptable: true,
             residual_probability: Some(1),
             residual_severity: Some(5),
             residual_risk_score: Some(5),
+            residual_is_acceptable: Some(true),
         };
 
         assert_eq!(risk.probability, 2);
@@ -145,9 +158,11 @@ mod tests {
             probability: 3,
             severity: 4,
             risk_score: 12,
+            is_acceptable: false,
             residual_probability: None,
             residual_severity: None,
             residual_risk_score: None,
+            residual_is_acceptable: None,
         };
 
         // Serialize to RON
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::models::EntityType;
+
+/// What the signer attests to by signing
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SignatureMeaning {
+    Authored,
+    Reviewed,
+    Approved,
+}
+
+/// An electronic signature on one revision of an entity
+///
+/// The revision is identified by the blake3 hash of the entity's normalized RON
+/// content, so the signature stops being valid as soon as that content changes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Signature {
+    pub id: Uuid,
+    pub entity_id: Uuid,
+    pub entity_type: EntityType,
+    /// Printed name of the signer
+    pub signer: String,
+    pub role: String, // From config
+    pub meaning: SignatureMeaning,
+    pub content_hash: String,
+    pub signed_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature_serialization_ron() {
+        let signature = Signature {
+            id: Uuid::new_v4(),
+            entity_id: Uuid::new_v4(),
+            entity_type: EntityType::Requirement,
+            signer: "Dana Reyes".to_string(),
+            role: "Approver".to_string(),
+            meaning: SignatureMeaning::Approved,
+            content_hash: blake3::hash(b"content").to_hex().to_string(),
+            signed_at: Utc::now(),
+        };
+
+        let serialized = ron::ser::to_string_pretty(&signature, ron::ser::PrettyConfig::default()).unwrap();
+        assert!(serialized.contains("meaning: Approved"));
+
+        let deserialized: Signature = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, signature);
+    }
+}
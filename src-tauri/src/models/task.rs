@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use crate::models::EntityMetadata;
+use crate::models::entity::impl_stored_entity;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
@@ -86,6 +87,21 @@ pub struct TaskBaseline {
     pub dependencies: Vec<TaskDependency>,
 }
 
+impl Task {
+    /// Capture the task's current plan for a baseline
+    pub fn baseline_snapshot(&self, baseline_id: Uuid) -> TaskBaseline {
+        TaskBaseline {
+            baseline_id,
+            start: self.scheduled_start,
+            end: self.deadline,
+            effort: self.estimated_effort.unwrap_or(0.0),
+            cost: self.calculated_cost.or(self.actual_cost).unwrap_or(0.0),
+            percent_complete: self.percent_complete,
+            dependencies: self.dependencies.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Milestone {
     pub metadata: EntityMetadata,
@@ -131,6 +147,14 @@ pub struct Baseline {
     pub task_ids: Vec<Uuid>,  // Tasks included in baseline
 }
 
+impl_stored_entity!(
+    Task => Task,
+    Milestone => Milestone,
+    Resource => Resource,
+    Calendar => Calendar,
+    Baseline => Baseline,
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use crate::models::EntityMetadata;
+use crate::models::entity::impl_stored_entity;
 
 /// Test status for verification and validation activities
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -89,6 +90,11 @@ pub struct Validation {
     pub approved_at: Option<DateTime<Utc>>,
 }
 
+impl_stored_entity!(
+    Verification => Verification,
+    Validation => Validation,
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
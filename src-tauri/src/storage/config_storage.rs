@@ -0,0 +1,180 @@
+use std::path::{Path, PathBuf};
+use std::fs;
+use crate::core::{EdtResult, EdtError};
+use crate::models::{EntityType, ProjectConfig};
+use crate::storage::journal::atomic_write;
+
+/// Name of the project configuration file in the project root
+pub const CONFIG_FILE_NAME: &str = "project.toml";
+
+/// TOML file storage for the project configuration
+pub struct ConfigStorage {
+    config_path: PathBuf,
+}
+
+impl ConfigStorage {
+    /// Create a config storage for a project root
+    pub fn new<P: AsRef<Path>>(project_root: P) -> Self {
+        Self {
+            config_path: project_root.as_ref().join(CONFIG_FILE_NAME),
+        }
+    }
+
+    /// Load `project.toml`, writing the default configuration first if it is missing
+    pub fn load_or_create(&self) -> EdtResult<ProjectConfig> {
+        if !self.config_path.exists() {
+            let config = ProjectConfig::default();
+            self.save(&config)?;
+            return Ok(config);
+        }
+
+        let content = fs::read_to_string(&self.config_path)?;
+        let config: ProjectConfig = toml::from_str(&content)?;
+        Self::validate(&config)?;
+        Ok(config)
+    }
+
+    /// Validate and write the configuration to `project.toml`
+    pub fn save(&self, config: &ProjectConfig) -> EdtResult<()> {
+        Self::validate(config)?;
+        let serialized = toml::to_string_pretty(config)?;
+        atomic_write(&self.config_path, serialized.as_bytes())
+    }
+
+    /// Check that the configuration is internally consistent
+    pub fn validate(config: &ProjectConfig) -> EdtResult<()> {
+        if config.schema_version.trim().is_empty() {
+            return Err(EdtError::ValidationError(
+                "Config schema_version cannot be empty".to_string(),
+            ));
+        }
+
+        if config.severity_levels.is_empty() || config.probability_levels.is_empty() {
+            return Err(EdtError::ValidationError(
+                "Config must define at least one severity and one probability level".to_string(),
+            ));
+        }
+
+        // Every probability/severity combination needs a score
+        for probability in &config.probability_levels {
+            for severity in &config.severity_levels {
+                if config.get_risk_score(*probability, *severity).is_none() {
+                    return Err(EdtError::ValidationError(format!(
+                        "Risk matrix is missing a score for probability {} and severity {}",
+                        probability, severity
+                    )));
+                }
+            }
+        }
+
+        let type_lists = [
+            ("requirement_types", &config.requirement_types),
+            ("risk_types", &config.risk_types),
+            ("risk_control_types", &config.risk_control_types),
+            ("general_entity_types", &config.general_entity_types),
+            ("signature_roles", &config.signature_roles),
+        ];
+        for (field, types) in type_lists {
+            if types.iter().any(|t| t.trim().is_empty()) {
+                return Err(EdtError::ValidationError(format!(
+                    "Config {} cannot contain empty names",
+                    field
+                )));
+            }
+        }
+
+        // Human-readable IDs must be unique across the project, so prefixes cannot be shared
+        let mut prefixes: Vec<String> = Vec::new();
+        for entity_type in EntityType::all() {
+            let prefix = config.id_prefix(entity_type);
+            if prefix.is_empty() || !prefix.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(EdtError::ValidationError(format!(
+                    "ID prefix '{}' for {:?} must be non-empty and alphanumeric",
+                    prefix, entity_type
+                )));
+            }
+            if prefixes.iter().any(|p| p.eq_ignore_ascii_case(&prefix)) {
+                return Err(EdtError::ValidationError(format!(
+                    "ID prefix '{}' is used by more than one entity type",
+                    prefix
+                )));
+            }
+            prefixes.push(prefix);
+        }
+
+        if let Some(rule) = config.workflow.transitions.iter().find(|t| t.from == t.to) {
+            return Err(EdtError::ValidationError(format!(
+                "Workflow transition from {:?} to itself is not allowed",
+                rule.from
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_creates_default_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = ConfigStorage::new(temp_dir.path());
+
+        let config = storage.load_or_create().unwrap();
+
+        assert_eq!(config.schema_version, "1.0.0");
+        assert!(temp_dir.path().join(CONFIG_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn test_save_and_reload_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = ConfigStorage::new(temp_dir.path());
+
+        let mut config = storage.load_or_create().unwrap();
+        config.acceptable_risk_threshold = 6;
+        config.requirement_types.push("Regulatory Requirement".to_string());
+        storage.save(&config).unwrap();
+
+        let reloaded = ConfigStorage::new(temp_dir.path()).load_or_create().unwrap();
+        assert_eq!(reloaded.acceptable_risk_threshold, 6);
+        assert!(reloaded.requirement_types.contains(&"Regulatory Requirement".to_string()));
+    }
+
+    #[test]
+    fn test_save_rejects_incomplete_risk_matrix() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = ConfigStorage::new(temp_dir.path());
+
+        let mut config = ProjectConfig::default();
+        config.severity_levels.push(6);
+
+        let result = storage.save(&config);
+        assert!(matches!(result.unwrap_err(), EdtError::ValidationError(_)));
+        assert!(!temp_dir.path().join(CONFIG_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn test_save_rejects_duplicate_id_prefix() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = ConfigStorage::new(temp_dir.path());
+
+        let mut config = ProjectConfig::default();
+        config.id_prefixes.insert(EntityType::Risk, "req".to_string());
+
+        let result = storage.save(&config);
+        assert!(matches!(result.unwrap_err(), EdtError::ValidationError(_)));
+    }
+
+    #[test]
+    fn test_load_invalid_toml() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join(CONFIG_FILE_NAME), "schema_version = ").unwrap();
+
+        let result = ConfigStorage::new(temp_dir.path()).load_or_create();
+        assert!(matches!(result.unwrap_err(), EdtError::TomlError(_)));
+    }
+}
@@ -0,0 +1,343 @@
+use std::path::{Path, PathBuf};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+use crate::models::{EntityRef, EntityType};
+
+/// Oldest steps are dropped beyond this many
+const MAX_UNDO_STEPS: usize = 100;
+
+/// Content of one file before and after a change; `None` means the file did not exist
+#[derive(Debug, Clone)]
+pub struct FileEdit {
+    /// Path relative to the project root
+    pub path: PathBuf,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// One undoable step: every file changed by a single user action
+#[derive(Debug, Clone)]
+pub struct Operation {
+    pub label: String,
+    pub timestamp: DateTime<Utc>,
+    pub edits: Vec<FileEdit>,
+}
+
+/// Summary of an undo or redo step for display
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryEntry {
+    pub label: String,
+    pub timestamp: DateTime<Utc>,
+    /// Entities whose files the step changes
+    pub entities: Vec<EntityRef>,
+    /// True if the step adds or removes links
+    pub links_changed: bool,
+}
+
+/// An undo or redo step as applied, with each file's content before and after it ran
+#[derive(Debug, Clone)]
+pub struct AppliedStep {
+    pub entry: HistoryEntry,
+    pub edits: Vec<FileEdit>,
+}
+
+/// Steps available to undo and redo, most recent first
+#[derive(Debug, Clone, Serialize)]
+pub struct UndoHistory {
+    pub undo: Vec<HistoryEntry>,
+    pub redo: Vec<HistoryEntry>,
+}
+
+/// Undo and redo stacks for the current session
+///
+/// History is kept in memory only; the RON files are the durable record.
+#[derive(Debug, Default)]
+pub struct OperationLog {
+    undo: Vec<Operation>,
+    redo: Vec<Operation>,
+    /// Open group and the token that closes it
+    group: Option<(Operation, Uuid)>,
+}
+
+impl OperationLog {
+    /// Record committed edits as a new step, or add them to the open group
+    pub fn record(&mut self, edits: Vec<FileEdit>) {
+        self.record_as(edits, None);
+    }
+
+    /// Record committed edits under a label instead of one derived from the edits
+    pub fn record_as(&mut self, edits: Vec<FileEdit>, label: Option<&str>) {
+        let edits: Vec<FileEdit> = edits.into_iter().filter(|e| e.before != e.after).collect();
+        if edits.is_empty() {
+            return;
+        }
+
+        if let Some((group, _)) = self.group.as_mut() {
+            merge_edits(&mut group.edits, edits);
+            return;
+        }
+
+        let label = label.map(str::to_string).unwrap_or_else(|| describe(&edits));
+        self.push(Operation {
+            label,
+            timestamp: Utc::now(),
+            edits,
+        });
+    }
+
+    /// Start collecting the following changes into one step
+    ///
+    /// Returns the token that closes the group, or `None` if a group is already open.
+    pub fn begin_group(&mut self, label: &str) -> Option<Uuid> {
+        if self.group.is_some() {
+            return None;
+        }
+        let token = Uuid::new_v4();
+        let operation = Operation {
+            label: label.to_string(),
+            timestamp: Utc::now(),
+            edits: Vec::new(),
+        };
+        self.group = Some((operation, token));
+        Some(token)
+    }
+
+    /// Close the open group and record its step; returns false if `token` does not open it
+    pub fn end_group(&mut self, token: Uuid) -> bool {
+        if self.group.as_ref().is_none_or(|(_, open)| *open != token) {
+            return false;
+        }
+        self.reset_group();
+        true
+    }
+
+    /// Close any open group, keeping the changes it collected as one step
+    pub fn reset_group(&mut self) {
+        if let Some((operation, _)) = self.group.take() {
+            if !operation.edits.is_empty() {
+                self.push(operation);
+            }
+        }
+    }
+
+    pub fn in_group(&self) -> bool {
+        self.group.is_some()
+    }
+
+    pub fn pop_undo(&mut self) -> Option<Operation> {
+        self.undo.pop()
+    }
+
+    pub fn pop_redo(&mut self) -> Option<Operation> {
+        self.redo.pop()
+    }
+
+    /// Return an undone step to the redo stack
+    pub fn push_redo(&mut self, operation: Operation) {
+        self.redo.push(operation);
+    }
+
+    /// Put a step back on the undo stack, keeping the redo stack
+    pub fn push_undo(&mut self, operation: Operation) {
+        self.undo.push(operation);
+    }
+
+    pub fn entries(&self) -> UndoHistory {
+        UndoHistory {
+            undo: self.undo_entries(),
+            redo: self.redo_entries(),
+        }
+    }
+
+    fn undo_entries(&self) -> Vec<HistoryEntry> {
+        self.undo.iter().rev().map(Operation::entry).collect()
+    }
+
+    fn redo_entries(&self) -> Vec<HistoryEntry> {
+        self.redo.iter().rev().map(Operation::entry).collect()
+    }
+
+    fn push(&mut self, operation: Operation) {
+        self.redo.clear();
+        self.undo.push(operation);
+        if self.undo.len() > MAX_UNDO_STEPS {
+            self.undo.remove(0);
+        }
+    }
+}
+
+impl FileEdit {
+    /// The entity stored in the edited file, if it is an entity file
+    pub fn entity(&self) -> Option<EntityRef> {
+        match file_target(&self.path)? {
+            FileTarget::Entity(entity) => Some(entity),
+            FileTarget::Link => None,
+        }
+    }
+
+    /// True if the edited file stores a link
+    pub fn is_link(&self) -> bool {
+        matches!(file_target(&self.path), Some(FileTarget::Link))
+    }
+}
+
+impl Operation {
+    pub fn entry(&self) -> HistoryEntry {
+        let mut entities = Vec::new();
+        let mut links_changed = false;
+        for edit in &self.edits {
+            match file_target(&edit.path) {
+                Some(FileTarget::Entity(entity)) => entities.push(entity),
+                Some(FileTarget::Link) => links_changed = true,
+                None => {}
+            }
+        }
+        HistoryEntry {
+            label: self.label.clone(),
+            timestamp: self.timestamp,
+            entities,
+            links_changed,
+        }
+    }
+}
+
+/// Add edits to a step, keeping the first before-image and last after-image of each file
+fn merge_edits(existing: &mut Vec<FileEdit>, edits: Vec<FileEdit>) {
+    for edit in edits {
+        match existing.iter_mut().find(|e| e.path == edit.path) {
+            Some(previous) => previous.after = edit.after,
+            None => existing.push(edit),
+        }
+    }
+    existing.retain(|e| e.before != e.after);
+}
+
+enum FileTarget {
+    Entity(EntityRef),
+    Link,
+}
+
+/// What a project-relative path such as `entities/tasks/<id>.ron` or `links/<id>.ron` stores
+fn file_target(path: &Path) -> Option<FileTarget> {
+    let folder = path.parent()?;
+    if folder == Path::new("links") {
+        return Some(FileTarget::Link);
+    }
+    let entity_type = EntityType::from_folder_name(folder.strip_prefix("entities").ok()?.to_str()?)?;
+    let entity_id = Uuid::parse_str(path.file_stem()?.to_str()?).ok()?;
+    Some(FileTarget::Entity(EntityRef {
+        entity_id,
+        entity_type,
+    }))
+}
+
+/// Default label for a step, e.g. "Update Task" or "Delete Component (+3 files)"
+///
+/// Named after the last entity changed, since owned children are written before the
+/// entity the user acted on.
+fn describe(edits: &[FileEdit]) -> String {
+    let main = edits
+        .iter()
+        .rev()
+        .find(|edit| matches!(file_target(&edit.path), Some(FileTarget::Entity(_))))
+        .unwrap_or(&edits[0]);
+    let action = match (&main.before, &main.after) {
+        (None, _) => "Create",
+        (_, None) => "Delete",
+        _ => "Update",
+    };
+    let subject = match file_target(&main.path) {
+        Some(FileTarget::Entity(entity)) => format!("{:?}", entity.entity_type),
+        Some(FileTarget::Link) => "Link".to_string(),
+        None => "file".to_string(),
+    };
+
+    match edits.len() {
+        1 => format!("{} {}", action, subject),
+        n => format!("{} {} (+{} files)", action, subject, n - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(path: &str, before: Option<&str>, after: Option<&str>) -> FileEdit {
+        FileEdit {
+            path: PathBuf::from(path),
+            before: before.map(str::to_string),
+            after: after.map(str::to_string),
+        }
+    }
+
+    fn task_path() -> String {
+        format!("entities/tasks/{}.ron", Uuid::new_v4())
+    }
+
+    #[test]
+    fn test_record_labels_and_clears_redo() {
+        let mut log = OperationLog::default();
+        log.record(vec![edit(&task_path(), None, Some("a"))]);
+        log.record(vec![edit(&task_path(), Some("a"), Some("b"))]);
+
+        let entries = log.undo_entries();
+        assert_eq!(entries[0].label, "Update Task");
+        assert_eq!(entries[1].label, "Create Task");
+        assert_eq!(entries[0].entities[0].entity_type, EntityType::Task);
+
+        let undone = log.pop_undo().unwrap();
+        log.push_redo(undone);
+        assert_eq!(log.redo_entries().len(), 1);
+
+        log.record(vec![edit(&task_path(), None, Some("c"))]);
+        assert!(log.redo_entries().is_empty());
+    }
+
+    #[test]
+    fn test_no_op_edits_ignored() {
+        let mut log = OperationLog::default();
+        log.record(vec![edit(&task_path(), Some("a"), Some("a"))]);
+        assert!(log.undo_entries().is_empty());
+    }
+
+    #[test]
+    fn test_group_merges_into_one_step() {
+        let mut log = OperationLog::default();
+        let path = task_path();
+
+        let token = log.begin_group("Plan schedule").unwrap();
+        log.record(vec![edit(&path, None, Some("a"))]);
+        assert!(log.begin_group("Another").is_none());
+        log.record(vec![edit(&path, Some("a"), Some("b"))]);
+        log.record(vec![edit("links/x.ron", None, Some("l"))]);
+        assert!(!log.end_group(Uuid::new_v4()));
+        assert!(log.undo_entries().is_empty());
+        assert!(log.end_group(token));
+
+        let entries = log.undo_entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].label, "Plan schedule");
+        assert!(entries[0].links_changed);
+
+        let operation = log.pop_undo().unwrap();
+        assert_eq!(operation.edits.len(), 2);
+        assert_eq!(operation.edits[0].before, None);
+        assert_eq!(operation.edits[0].after.as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn test_reset_closes_abandoned_group() {
+        let mut log = OperationLog::default();
+        log.begin_group("Abandoned").unwrap();
+        log.record(vec![edit(&task_path(), None, Some("a"))]);
+
+        log.reset_group();
+        assert!(!log.in_group());
+        assert_eq!(log.undo_entries()[0].label, "Abandoned");
+
+        log.record_as(vec![edit(&task_path(), None, Some("b"))], Some("Import tasks"));
+        assert_eq!(log.undo_entries()[0].label, "Import tasks");
+        assert!(log.begin_group("Next").is_some());
+    }
+}
@@ -0,0 +1,197 @@
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::core::EdtResult;
+
+/// Location of the transaction journal, relative to the project root
+pub const JOURNAL_PATH: &str = ".edt/journal.ron";
+
+/// Write a file so that readers see either the old or the new content, never a partial one
+///
+/// The content goes to a sibling temp file, is fsynced, and is then renamed over the target.
+pub fn atomic_write(path: &Path, contents: &[u8]) -> EdtResult<()> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let temp_path = path.with_file_name(format!(".{}.tmp", file_name));
+
+    {
+        let mut file = File::create(&temp_path)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+    }
+
+    if let Err(e) = fs::rename(&temp_path, path) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e.into());
+    }
+
+    // Persist the rename itself; directories can't be opened for syncing on every platform
+    if let Some(parent) = path.parent() {
+        if let Ok(dir) = File::open(parent) {
+            let _ = dir.sync_all();
+        }
+    }
+
+    Ok(())
+}
+
+/// Before-image of one file touched by a transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JournalEntry {
+    /// Path relative to the project root
+    path: PathBuf,
+    /// File content before the transaction, or `None` if the file did not exist
+    before: Option<String>,
+}
+
+/// Undo log for a multi-file operation
+///
+/// Before a file is first modified inside a transaction its previous content is appended
+/// here and the journal is flushed to disk. Committing deletes the journal; if the process
+/// dies first, the journal found on the next start is used to roll the files back.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Journal {
+    entries: Vec<JournalEntry>,
+}
+
+impl Journal {
+    /// Record the current content of `path` (absolute) unless it was already recorded
+    pub fn record(&mut self, project_root: &Path, path: &Path) -> EdtResult<()> {
+        let relative = path.strip_prefix(project_root).unwrap_or(path).to_path_buf();
+        if self.entries.iter().any(|entry| entry.path == relative) {
+            return Ok(());
+        }
+
+        let before = if path.exists() {
+            Some(fs::read_to_string(path)?)
+        } else {
+            None
+        };
+        self.entries.push(JournalEntry { path: relative, before });
+
+        // The before-image must be durable before the file itself changes
+        let journal_path = project_root.join(JOURNAL_PATH);
+        if let Some(parent) = journal_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let serialized = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        atomic_write(&journal_path, serialized.as_bytes())
+    }
+
+    /// Recorded files (relative to the project root) with their before-images
+    pub fn before_images(&self) -> impl Iterator<Item = (&Path, Option<&str>)> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.path.as_path(), entry.before.as_deref()))
+    }
+
+    /// Discard the journal after a successful transaction
+    pub fn commit(self, project_root: &Path) -> EdtResult<()> {
+        Self::remove(project_root)
+    }
+
+    /// Restore every recorded file to its before-image, newest first, then discard the journal
+    pub fn rollback(self, project_root: &Path) -> EdtResult<()> {
+        for entry in self.entries.iter().rev() {
+            let path = project_root.join(&entry.path);
+            match &entry.before {
+                Some(content) => {
+                    if let Some(parent) = path.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    atomic_write(&path, content.as_bytes())?;
+                }
+                None => {
+                    if path.exists() {
+                        fs::remove_file(&path)?;
+                    }
+                }
+            }
+        }
+
+        Self::remove(project_root)
+    }
+
+    /// Roll back a transaction left behind by a crash; returns true if one was found
+    pub fn recover(project_root: &Path) -> EdtResult<bool> {
+        let journal_path = project_root.join(JOURNAL_PATH);
+        if !journal_path.exists() {
+            return Ok(false);
+        }
+
+        let content = fs::read_to_string(&journal_path)?;
+        let journal: Journal = ron::from_str(&content)?;
+        journal.rollback(project_root)?;
+        Ok(true)
+    }
+
+    fn remove(project_root: &Path) -> EdtResult<()> {
+        let journal_path = project_root.join(JOURNAL_PATH);
+        if journal_path.exists() {
+            fs::remove_file(journal_path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_atomic_write_replaces_content() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("entity.ron");
+
+        atomic_write(&path, b"first").unwrap();
+        atomic_write(&path, b"second").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        // No temp files left behind
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_rollback_restores_before_images() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let existing = root.join("existing.ron");
+        let created = root.join("created.ron");
+        fs::write(&existing, "original").unwrap();
+
+        let mut journal = Journal::default();
+        journal.record(root, &existing).unwrap();
+        fs::write(&existing, "modified").unwrap();
+        journal.record(root, &created).unwrap();
+        fs::write(&created, "new").unwrap();
+
+        assert!(root.join(JOURNAL_PATH).exists());
+        journal.rollback(root).unwrap();
+
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "original");
+        assert!(!created.exists());
+        assert!(!root.join(JOURNAL_PATH).exists());
+    }
+
+    #[test]
+    fn test_recover_rolls_back_interrupted_transaction() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let path = root.join("entity.ron");
+        fs::write(&path, "original").unwrap();
+
+        // Simulate a crash: journal flushed, file modified, no commit
+        let mut journal = Journal::default();
+        journal.record(root, &path).unwrap();
+        fs::write(&path, "half-writt").unwrap();
+        drop(journal);
+
+        assert!(Journal::recover(root).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "original");
+        assert!(!Journal::recover(root).unwrap());
+    }
+}
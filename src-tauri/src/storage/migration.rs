@@ -0,0 +1,378 @@
+use std::cmp::Ordering;
+use std::fs;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::core::{AuditLog, EdtResult, EdtError};
+use crate::models::{EntityType, CURRENT_SCHEMA_VERSION};
+use crate::storage::raw_ron::RawValue;
+use crate::storage::RonStorage;
+
+/// One upgrade step between two schema versions
+pub struct Migration {
+    pub from: &'static str,
+    pub to: &'static str,
+    pub description: &'static str,
+    /// Rewrites one entity in place; `metadata.schema_version` is updated by the registry
+    pub apply: fn(&EntityType, &mut RawValue) -> EdtResult<()>,
+}
+
+/// Entity file upgraded by `migrate_project`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigratedEntity {
+    pub entity_id: Uuid,
+    pub entity_type: EntityType,
+    pub from_version: String,
+}
+
+/// Entity file `migrate_project` could not upgrade
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationFailure {
+    pub entity_id: Uuid,
+    pub entity_type: EntityType,
+    pub message: String,
+}
+
+/// Result of migrating every entity file in a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationReport {
+    pub target_version: String,
+    pub migrated: Vec<MigratedEntity>,
+    pub up_to_date: usize,
+    pub failed: Vec<MigrationFailure>,
+}
+
+/// Ordered chain of migrations ending at `CURRENT_SCHEMA_VERSION`
+pub struct MigrationRegistry {
+    migrations: Vec<Migration>,
+}
+
+/// Only the metadata version is needed to decide whether a file must be upgraded
+#[derive(Deserialize)]
+struct VersionProbe {
+    metadata: MetadataProbe,
+}
+
+#[derive(Deserialize)]
+struct MetadataProbe {
+    schema_version: String,
+}
+
+impl MigrationRegistry {
+    /// Registry with the given steps, which must chain in order
+    pub fn new(migrations: Vec<Migration>) -> Self {
+        Self { migrations }
+    }
+
+    /// Migrations shipped with this version of the application
+    pub fn builtin() -> Self {
+        // Add a step here whenever CURRENT_SCHEMA_VERSION is bumped
+        Self::new(Vec::new())
+    }
+
+    /// Schema version recorded in an entity file
+    pub fn schema_version(content: &str) -> EdtResult<String> {
+        let probe: VersionProbe = ron::from_str(content)?;
+        Ok(probe.metadata.schema_version)
+    }
+
+    /// Bring entity RON up to the current schema
+    ///
+    /// Returns `None` when the content is already current. Files from a newer schema, or
+    /// whose version is not a `major.minor.patch` number and so cannot be shown to be older,
+    /// are rejected with `SchemaVersionMismatch`.
+    pub fn upgrade(&self, entity_type: &EntityType, content: &str) -> EdtResult<Option<String>> {
+        let found = Self::schema_version(content)?;
+        match compare_versions(&found, CURRENT_SCHEMA_VERSION) {
+            Some(Ordering::Equal) => return Ok(None),
+            None | Some(Ordering::Greater) => {
+                return Err(EdtError::SchemaVersionMismatch {
+                    expected: CURRENT_SCHEMA_VERSION.to_string(),
+                    found,
+                })
+            }
+            Some(Ordering::Less) => {}
+        }
+
+        let mut raw = RawValue::parse(content)?;
+        let mut version = found;
+        while version != CURRENT_SCHEMA_VERSION {
+            let step = self
+                .migrations
+                .iter()
+                .find(|m| m.from == version)
+                .ok_or_else(|| {
+                    EdtError::ValidationError(format!(
+                        "No migration from schema version {} to {}",
+                        version, CURRENT_SCHEMA_VERSION
+                    ))
+                })?;
+
+            (step.apply)(entity_type, &mut raw).map_err(|e| {
+                EdtError::ValidationError(format!(
+                    "Migration {} -> {} ({}) failed: {}",
+                    step.from, step.to, step.description, e
+                ))
+            })?;
+            raw.get_mut("metadata")
+                .map(|metadata| metadata.set("schema_version", RawValue::string(step.to)))
+                .filter(|&set| set)
+                .ok_or_else(|| EdtError::ValidationError("Entity has no metadata".to_string()))?;
+            version = step.to.to_string();
+        }
+
+        Ok(Some(raw.to_ron()))
+    }
+
+    /// Rewrite every outdated entity file in the project to the current schema
+    ///
+    /// Files that fail are left untouched and listed in the report. Each upgraded
+    /// file is recorded in the audit log.
+    pub fn migrate_project(&self, storage: &RonStorage, audit: &AuditLog) -> EdtResult<MigrationReport> {
+        let mut report = MigrationReport {
+            target_version: CURRENT_SCHEMA_VERSION.to_string(),
+            migrated: Vec::new(),
+            up_to_date: 0,
+            failed: Vec::new(),
+        };
+
+        for entity_type in EntityType::all() {
+            for entity_id in storage.list_ids(entity_type)? {
+                let path = storage.get_entity_path(entity_type, &entity_id);
+                let result = fs::read_to_string(&path)
+                    .map_err(EdtError::from)
+                    .and_then(|content| {
+                        let from_version = Self::schema_version(&content)?;
+                        let upgraded = self.upgrade(entity_type, &content)?;
+                        Ok((from_version, upgraded))
+                    });
+
+                let (from_version, upgraded) = match result {
+                    Ok(outcome) => outcome,
+                    Err(e) => {
+                        report.failed.push(MigrationFailure {
+                            entity_id,
+                            entity_type: entity_type.clone(),
+                            message: e.to_string(),
+                        });
+                        continue;
+                    }
+                };
+
+                let Some(upgraded) = upgraded else {
+                    report.up_to_date += 1;
+                    continue;
+                };
+
+                // Only write files the current models can actually load
+                let written = RonStorage::entity_ron_to_json(entity_type, &upgraded)
+                    .and_then(|_| storage.write_raw(entity_type, &entity_id, &upgraded))
+                    .and_then(|()| {
+                        audit.record_migration(entity_type.clone(), entity_id, &from_version, CURRENT_SCHEMA_VERSION)
+                    });
+                match written {
+                    Ok(()) => report.migrated.push(MigratedEntity {
+                        entity_id,
+                        entity_type: entity_type.clone(),
+                        from_version,
+                    }),
+                    Err(e) => report.failed.push(MigrationFailure {
+                        entity_id,
+                        entity_type: entity_type.clone(),
+                        message: e.to_string(),
+                    }),
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+fn parse_version(version: &str) -> Option<Vec<u32>> {
+    version.split('.').map(|part| part.parse::<u32>().ok()).collect()
+}
+
+/// Compare two `major.minor.patch` version strings; `None` if either is not numeric
+fn compare_versions(a: &str, b: &str) -> Option<Ordering> {
+    Some(parse_version(a)?.cmp(&parse_version(b)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{EntityMetadata, Requirement};
+    use tempfile::TempDir;
+
+    fn rename_title(entity_type: &EntityType, raw: &mut RawValue) -> EdtResult<()> {
+        if *entity_type == EntityType::Requirement {
+            raw.rename("title", "name");
+        }
+        Ok(())
+    }
+
+    fn add_notes(_: &EntityType, raw: &mut RawValue) -> EdtResult<()> {
+        raw.set("notes", RawValue::Tuple(Some("Some".to_string()), vec![RawValue::string("Migrated")]));
+        Ok(())
+    }
+
+    fn test_registry() -> MigrationRegistry {
+        MigrationRegistry::new(vec![
+            Migration {
+                from: "0.8.0",
+                to: "0.9.0",
+                description: "Rename requirement title to name",
+                apply: rename_title,
+            },
+            Migration {
+                from: "0.9.0",
+                to: CURRENT_SCHEMA_VERSION,
+                description: "Add notes",
+                apply: add_notes,
+            },
+        ])
+    }
+
+    /// A requirement as an older schema would have written it
+    fn legacy_requirement(id: Uuid, version: &str) -> String {
+        let requirement = Requirement {
+            metadata: EntityMetadata::new(EntityType::Requirement),
+            name: "REQ-001".to_string(),
+            description: "Description".to_string(),
+            notes: None,
+            requirement_type: "System Requirement".to_string(),
+            rationale: None,
+            source: None,
+            verification_method: None,
+        };
+        let current = ron::ser::to_string_pretty(&requirement, ron::ser::PrettyConfig::default()).unwrap();
+
+        let mut raw = RawValue::parse(&current).unwrap();
+        raw.rename("name", "title");
+        raw.remove("notes");
+        let metadata = raw.get_mut("metadata").unwrap();
+        metadata.set("id", RawValue::string(&id.to_string()));
+        metadata.set("schema_version", RawValue::string(version));
+        raw.to_ron()
+    }
+
+    fn write_legacy(temp: &TempDir, id: Uuid, version: &str) {
+        let dir = temp.path().join("entities").join("requirements");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(format!("{}.ron", id)), legacy_requirement(id, version)).unwrap();
+    }
+
+    #[test]
+    fn test_current_version_needs_no_upgrade() {
+        let requirement = ron::ser::to_string(&EntityMetadata::new(EntityType::Requirement)).unwrap();
+        let content = format!("(metadata: {})", requirement);
+
+        assert!(test_registry().upgrade(&EntityType::Requirement, &content).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_upgrade_applies_steps_in_order() {
+        let id = Uuid::new_v4();
+        let content = legacy_requirement(id, "0.8.0");
+
+        let upgraded = test_registry()
+            .upgrade(&EntityType::Requirement, &content)
+            .unwrap()
+            .unwrap();
+        let requirement: Requirement = ron::from_str(&upgraded).unwrap();
+
+        assert_eq!(requirement.name, "REQ-001");
+        assert_eq!(requirement.notes, Some("Migrated".to_string()));
+        assert_eq!(requirement.metadata.schema_version, CURRENT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_newer_schema_rejected() {
+        let content = legacy_requirement(Uuid::new_v4(), "99.0.0");
+
+        let result = test_registry().upgrade(&EntityType::Requirement, &content);
+        assert!(matches!(result, Err(EdtError::SchemaVersionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_unparseable_version_rejected() {
+        for version in ["2.0.0-beta", "v2", "draft"] {
+            let content = legacy_requirement(Uuid::new_v4(), version);
+            let result = test_registry().upgrade(&EntityType::Requirement, &content);
+            assert!(matches!(
+                result,
+                Err(EdtError::SchemaVersionMismatch { ref found, .. }) if found == version
+            ));
+        }
+
+        let temp = TempDir::new().unwrap();
+        let storage = RonStorage::new(temp.path()).unwrap();
+        let mut requirement = Requirement {
+            metadata: EntityMetadata::new(EntityType::Requirement),
+            name: "REQ-003".to_string(),
+            description: "Description".to_string(),
+            notes: None,
+            requirement_type: "System Requirement".to_string(),
+            rationale: None,
+            source: None,
+            verification_method: None,
+        };
+        requirement.metadata.schema_version = "draft".to_string();
+        storage.write(&requirement).unwrap();
+        assert!(matches!(
+            storage.read::<Requirement>(&requirement.metadata.id),
+            Err(EdtError::SchemaVersionMismatch { .. })
+        ));
+
+        // Migrating leaves the file alone and reports it
+        let report = test_registry().migrate_project(&storage, &AuditLog::new(temp.path())).unwrap();
+        assert_eq!(report.failed.len(), 1);
+        assert!(report.failed[0].message.contains("draft"));
+    }
+
+    #[test]
+    fn test_migrate_project_rewrites_files_and_reports() {
+        let temp = TempDir::new().unwrap();
+        let legacy_id = Uuid::new_v4();
+        let future_id = Uuid::new_v4();
+        write_legacy(&temp, legacy_id, "0.9.0");
+        write_legacy(&temp, future_id, "2.0.0");
+
+        let storage = RonStorage::new(temp.path()).unwrap();
+        let current = Requirement {
+            metadata: EntityMetadata::new(EntityType::Requirement),
+            name: "REQ-002".to_string(),
+            description: "Description".to_string(),
+            notes: None,
+            requirement_type: "System Requirement".to_string(),
+            rationale: None,
+            source: None,
+            verification_method: None,
+        };
+        storage.write(&current).unwrap();
+        let audit = AuditLog::new(temp.path());
+
+        let report = MigrationRegistry::new(vec![Migration {
+            from: "0.9.0",
+            to: CURRENT_SCHEMA_VERSION,
+            description: "Rename requirement title to name",
+            apply: rename_title,
+        }])
+        .migrate_project(&storage, &audit)
+        .unwrap();
+
+        assert_eq!(report.migrated.len(), 1);
+        assert_eq!(report.migrated[0].entity_id, legacy_id);
+        assert_eq!(report.migrated[0].from_version, "0.9.0");
+        assert_eq!(report.up_to_date, 1);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].entity_id, future_id);
+
+        let migrated: Requirement = storage.read(&legacy_id).unwrap();
+        assert_eq!(migrated.name, "REQ-001");
+
+        let records = audit.query(&Default::default()).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].entity_id, legacy_id);
+        assert_eq!(records[0].changes[0].old_value, Some(serde_json::json!("0.9.0")));
+    }
+}
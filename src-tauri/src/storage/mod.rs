@@ -1,4 +1,16 @@
 // Storage layer (RON files, SQLite cache)
 pub mod ron_storage;
+pub mod config_storage;
+pub mod sqlite_cache;
+pub mod journal;
+pub mod history;
+pub mod raw_ron;
+pub mod migration;
+pub mod seen_comments;
 
 pub use ron_storage::RonStorage;
+pub use config_storage::ConfigStorage;
+pub use sqlite_cache::SqliteCache;
+pub use migration::{MigrationRegistry, MigrationReport};
+pub use seen_comments::SeenComments;
+pub use history::{AppliedStep, FileEdit, HistoryEntry, UndoHistory};
@@ -0,0 +1,441 @@
+use std::fmt::Write;
+use crate::core::{EdtResult, EdtError};
+
+/// Untyped RON tree that keeps enum variant and struct names
+///
+/// `ron::Value` drops identifiers (a unit variant becomes `()`), so it cannot be
+/// written back in a form the typed models accept. Migrations edit this instead.
+/// Scalars keep their source text verbatim.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RawValue {
+    /// Number, string, char, bool, or bare identifier such as a unit variant or `None`
+    Scalar(String),
+    /// `Name(a, b)`, `Some(x)`, `(a, b)` or `()`
+    Tuple(Option<String>, Vec<RawValue>),
+    /// `Name(field: value, ...)` or `(field: value, ...)`
+    Struct(Option<String>, Vec<(String, RawValue)>),
+    List(Vec<RawValue>),
+    Map(Vec<(RawValue, RawValue)>),
+}
+
+impl RawValue {
+    /// Parse RON text
+    pub fn parse(input: &str) -> EdtResult<Self> {
+        let mut parser = Parser { src: input, pos: 0 };
+        let value = parser.value()?;
+        parser.skip_ws();
+        if parser.pos != input.len() {
+            return Err(parser.error("trailing characters"));
+        }
+        Ok(value)
+    }
+
+    /// Render as pretty-printed RON
+    pub fn to_ron(&self) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, 0);
+        out
+    }
+
+    /// A string scalar, escaped as RON expects
+    pub fn string(value: &str) -> Self {
+        RawValue::Scalar(ron::to_string(value).unwrap_or_else(|_| format!("{:?}", value)))
+    }
+
+    /// Decode a string scalar
+    #[allow(dead_code)] // For migration steps; none are registered yet
+    pub fn as_str(&self) -> Option<String> {
+        match self {
+            RawValue::Scalar(raw) => ron::from_str::<String>(raw).ok(),
+            _ => None,
+        }
+    }
+
+    /// Get a struct field
+    #[allow(dead_code)] // For migration steps; none are registered yet
+    pub fn get(&self, field: &str) -> Option<&RawValue> {
+        match self {
+            RawValue::Struct(_, fields) => fields.iter().find(|(name, _)| name == field).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Get a struct field mutably
+    pub fn get_mut(&mut self, field: &str) -> Option<&mut RawValue> {
+        match self {
+            RawValue::Struct(_, fields) => fields
+                .iter_mut()
+                .find(|(name, _)| name == field)
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Set a struct field, appending it if missing; returns false if this is not a struct
+    pub fn set(&mut self, field: &str, value: RawValue) -> bool {
+        match self {
+            RawValue::Struct(_, fields) => {
+                match fields.iter_mut().find(|(name, _)| name == field) {
+                    Some((_, existing)) => *existing = value,
+                    None => fields.push((field.to_string(), value)),
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Remove a struct field
+    #[allow(dead_code)] // For migration steps; none are registered yet
+    pub fn remove(&mut self, field: &str) -> Option<RawValue> {
+        match self {
+            RawValue::Struct(_, fields) => {
+                let index = fields.iter().position(|(name, _)| name == field)?;
+                Some(fields.remove(index).1)
+            }
+            _ => None,
+        }
+    }
+
+    /// Rename a struct field; returns false if the field was not present
+    #[allow(dead_code)] // For migration steps; none are registered yet
+    pub fn rename(&mut self, from: &str, to: &str) -> bool {
+        match self {
+            RawValue::Struct(_, fields) => match fields.iter_mut().find(|(name, _)| name == from) {
+                Some((name, _)) => {
+                    *name = to.to_string();
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn write_pretty(&self, out: &mut String, indent: usize) {
+        let pad = "    ".repeat(indent + 1);
+        let close = "    ".repeat(indent);
+        match self {
+            RawValue::Scalar(raw) => out.push_str(raw),
+            RawValue::Tuple(name, items) => {
+                out.push_str(name.as_deref().unwrap_or(""));
+                out.push('(');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    item.write_pretty(out, indent);
+                }
+                out.push(')');
+            }
+            RawValue::Struct(name, fields) => {
+                out.push_str(name.as_deref().unwrap_or(""));
+                out.push('(');
+                if !fields.is_empty() {
+                    out.push('\n');
+                    for (field, value) in fields {
+                        let _ = write!(out, "{}{}: ", pad, field);
+                        value.write_pretty(out, indent + 1);
+                        out.push_str(",\n");
+                    }
+                    out.push_str(&close);
+                }
+                out.push(')');
+            }
+            RawValue::List(items) => {
+                out.push('[');
+                if !items.is_empty() {
+                    out.push('\n');
+                    for item in items {
+                        out.push_str(&pad);
+                        item.write_pretty(out, indent + 1);
+                        out.push_str(",\n");
+                    }
+                    out.push_str(&close);
+                }
+                out.push(']');
+            }
+            RawValue::Map(entries) => {
+                out.push('{');
+                if !entries.is_empty() {
+                    out.push('\n');
+                    for (key, value) in entries {
+                        out.push_str(&pad);
+                        key.write_pretty(out, indent + 1);
+                        out.push_str(": ");
+                        value.write_pretty(out, indent + 1);
+                        out.push_str(",\n");
+                    }
+                    out.push_str(&close);
+                }
+                out.push('}');
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> EdtError {
+        EdtError::ValidationError(format!("Invalid RON at byte {}: {}", self.pos, message))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_ws(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+
+            if trimmed.starts_with("//") {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if trimmed.starts_with("/*") {
+                self.pos += trimmed.find("*/").map(|i| i + 2).unwrap_or(trimmed.len());
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn expect(&mut self, c: char) -> EdtResult<()> {
+        self.skip_ws();
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", c)))
+        }
+    }
+
+    /// Consume `c` if it is the next non-whitespace character
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_ws();
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self) -> EdtResult<RawValue> {
+        self.skip_ws();
+        match self.peek() {
+            Some('(') => self.parenthesized(None),
+            Some('[') => self.list(),
+            Some('{') => self.map(),
+            Some('"') => self.quoted('"'),
+            Some('\'') => self.quoted('\''),
+            Some('r') if matches!(self.rest()[1..].chars().next(), Some('"') | Some('#')) => self.raw_string(),
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                let ident = self.word();
+                self.skip_ws();
+                if self.peek() == Some('(') {
+                    self.parenthesized(Some(ident))
+                } else {
+                    Ok(RawValue::Scalar(ident))
+                }
+            }
+            Some(_) => {
+                let word = self.word();
+                if word.is_empty() {
+                    return Err(self.error("unexpected character"));
+                }
+                Ok(RawValue::Scalar(word))
+            }
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    /// Identifier, number, or keyword
+    fn word(&mut self) -> String {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '.' | '+' | '-')))
+            .unwrap_or(rest.len());
+        self.pos += len;
+        rest[..len].to_string()
+    }
+
+    fn quoted(&mut self, quote: char) -> EdtResult<RawValue> {
+        let start = self.pos;
+        let mut escaped = false;
+        for (i, c) in self.rest().char_indices().skip(1) {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == quote {
+                self.pos += i + 1;
+                return Ok(RawValue::Scalar(self.src[start..self.pos].to_string()));
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    fn raw_string(&mut self) -> EdtResult<RawValue> {
+        let start = self.pos;
+        let hashes = self.rest()[1..].chars().take_while(|&c| c == '#').count();
+        let terminator = format!("\"{}", "#".repeat(hashes));
+        let body_start = 1 + hashes + 1;
+        match self.rest().get(body_start..).and_then(|body| body.find(&terminator)) {
+            Some(i) => {
+                self.pos += body_start + i + terminator.len();
+                Ok(RawValue::Scalar(self.src[start..self.pos].to_string()))
+            }
+            None => Err(self.error("unterminated raw string")),
+        }
+    }
+
+    /// Struct if the first item is `ident:` (but not `ident::`), otherwise tuple
+    fn parenthesized(&mut self, name: Option<String>) -> EdtResult<RawValue> {
+        self.expect('(')?;
+        self.skip_ws();
+
+        let checkpoint = self.pos;
+        let is_struct = match self.peek() {
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                self.word();
+                self.skip_ws();
+                let is_field = self.rest().starts_with(':') && !self.rest().starts_with("::");
+                self.pos = checkpoint;
+                is_field
+            }
+            _ => false,
+        };
+
+        if is_struct {
+            let mut fields = Vec::new();
+            while !self.eat(')') {
+                self.skip_ws();
+                let field = self.word();
+                self.expect(':')?;
+                fields.push((field, self.value()?));
+                if !self.eat(',') {
+                    self.expect(')')?;
+                    break;
+                }
+            }
+            Ok(RawValue::Struct(name, fields))
+        } else {
+            let mut items = Vec::new();
+            while !self.eat(')') {
+                items.push(self.value()?);
+                if !self.eat(',') {
+                    self.expect(')')?;
+                    break;
+                }
+            }
+            Ok(RawValue::Tuple(name, items))
+        }
+    }
+
+    fn list(&mut self) -> EdtResult<RawValue> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        while !self.eat(']') {
+            items.push(self.value()?);
+            if !self.eat(',') {
+                self.expect(']')?;
+                break;
+            }
+        }
+        Ok(RawValue::List(items))
+    }
+
+    fn map(&mut self) -> EdtResult<RawValue> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        while !self.eat('}') {
+            let key = self.value()?;
+            self.expect(':')?;
+            entries.push((key, self.value()?));
+            if !self.eat(',') {
+                self.expect('}')?;
+                break;
+            }
+        }
+        Ok(RawValue::Map(entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{EntityMetadata, EntityType, Task, TaskType, SchedulingMode};
+    use chrono::Utc;
+
+    #[test]
+    fn test_round_trip_preserves_typed_model() {
+        let start = Utc::now();
+        let task = Task {
+            metadata: EntityMetadata::new(EntityType::Task),
+            name: "Design \"review\"".to_string(),
+            description: "Line one\nline two".to_string(),
+            notes: Some("Notes".to_string()),
+            scheduled_start: start,
+            deadline: start,
+            actual_start: None,
+            actual_end: None,
+            task_type: TaskType::DurationDriven,
+            scheduling_mode: SchedulingMode::Manual,
+            percent_complete: 0.25,
+            percent_complete_history: vec![],
+            assigned_resources: vec![],
+            estimated_effort: Some(-1.5e3),
+            actual_cost: None,
+            calculated_cost: None,
+            dependencies: vec![],
+            is_critical_path: true,
+            slack: None,
+            baseline_data: None,
+        };
+        let original = ron::ser::to_string_pretty(&task, ron::ser::PrettyConfig::default()).unwrap();
+
+        let raw = RawValue::parse(&original).unwrap();
+        let restored: Task = ron::from_str(&raw.to_ron()).unwrap();
+
+        assert_eq!(restored.name, task.name);
+        assert_eq!(restored.description, task.description);
+        assert_eq!(restored.task_type, TaskType::DurationDriven);
+        assert_eq!(restored.estimated_effort, Some(-1.5e3));
+        assert!(restored.is_critical_path);
+    }
+
+    #[test]
+    fn test_field_edits() {
+        let mut raw = RawValue::parse("(metadata: (schema_version: \"0.9.0\"), title: \"A\", kind: Big, items: [1, 2], tags: {\"a\": Some(1)})").unwrap();
+
+        assert!(raw.rename("title", "name"));
+        assert_eq!(raw.get("name").unwrap().as_str(), Some("A".to_string()));
+        assert_eq!(raw.get("kind"), Some(&RawValue::Scalar("Big".to_string())));
+
+        raw.get_mut("metadata").unwrap().set("schema_version", RawValue::string("1.0.0"));
+        assert_eq!(
+            raw.get("metadata").unwrap().get("schema_version").unwrap().as_str(),
+            Some("1.0.0".to_string())
+        );
+
+        assert!(raw.remove("items").is_some());
+        assert!(raw.get("items").is_none());
+        assert!(!raw.rename("missing", "other"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(RawValue::parse("(name: \"unterminated)").is_err());
+        assert!(RawValue::parse("(a: 1) extra").is_err());
+        assert!(RawValue::parse("[1, 2").is_err());
+    }
+}
@@ -1,16 +1,54 @@
 use std::path::{Path, PathBuf};
 use std::fs;
+use std::sync::{Mutex, MutexGuard};
+use std::thread::{self, ThreadId};
+use rayon::prelude::*;
 use uuid::Uuid;
-use crate::core::EdtResult;
-use crate::models::{
-    EntityType, Task, Requirement, Risk, Hazard, RiskControl, Milestone, Resource, Calendar, Baseline,
-    Assembly, Component, Feature, Mate, Stackup, Supplier, Quote,
-    Verification, Validation, Manufacturing,
-};
+use crate::core::{EdtResult, EdtError};
+use crate::storage::journal::{atomic_write, Journal};
+use crate::storage::history::{AppliedStep, FileEdit, HistoryEntry, Operation, OperationLog, UndoHistory};
+use crate::storage::MigrationRegistry;
+use crate::models::{EntityRef, EntityType, Link, Signature, StoredEntity, CURRENT_SCHEMA_VERSION};
+use crate::models::entity::for_each_stored_entity;
+
+/// Conversions between RON and JSON for one stored model
+struct EntityCodec {
+    entity_type: EntityType,
+    ron_to_json: fn(&str) -> EdtResult<serde_json::Value>,
+    json_to_ron: fn(&serde_json::Value) -> EdtResult<String>,
+}
+
+macro_rules! entity_codecs {
+    ($($entity:ty => $entity_type:ident),* $(,)?) => {
+        const ENTITY_CODECS: &[EntityCodec] = &[
+            $(EntityCodec {
+                entity_type: EntityType::$entity_type,
+                ron_to_json: RonStorage::ron_to_json::<$entity>,
+                json_to_ron: RonStorage::json_to_ron::<$entity>,
+            }),*
+        ];
+    };
+}
+
+for_each_stored_entity!(entity_codecs);
+
+/// A transaction in progress and the thread running it
+struct OpenTransaction {
+    owner: ThreadId,
+    journal: Journal,
+    /// Undo step label set by `with_undo_group`
+    label: Option<String>,
+}
 
 /// RON file storage for entities
 pub struct RonStorage {
     project_root: PathBuf,
+    /// Held for the whole of an outermost transaction, so transactions run one at a time
+    transaction_lock: Mutex<()>,
+    /// Journal of the transaction in progress, if any
+    journal: Mutex<Option<OpenTransaction>>,
+    /// Undo/redo steps for changes made in this session
+    history: Mutex<OperationLog>,
 }
 
 impl RonStorage {
@@ -22,370 +60,524 @@ impl RonStorage {
         let entities_dir = project_root.join("entities");
         fs::create_dir_all(&entities_dir)?;
 
-        Ok(Self { project_root })
+        // Roll back any transaction interrupted by a crash
+        Journal::recover(&project_root)?;
+
+        Ok(Self {
+            project_root,
+            transaction_lock: Mutex::new(()),
+            journal: Mutex::new(None),
+            history: Mutex::new(OperationLog::default()),
+        })
+    }
+
+    /// Root folder of the project
+    pub fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+
+    /// Run `f` as a single transaction: if it fails, every file it wrote or deleted is restored
+    ///
+    /// Calls nested inside an open transaction on the same thread join it rather than
+    /// starting a new one; transactions on other threads wait for it to finish. Writes made
+    /// outside any transaction are never journaled into another thread's transaction.
+    /// A committed transaction is one undo step.
+    pub fn with_transaction<T, F>(&self, f: F) -> EdtResult<T>
+    where
+        F: FnOnce() -> EdtResult<T>,
+    {
+        self.run_transaction(true, f)
+    }
+
+    fn run_transaction<T, F>(&self, record: bool, f: F) -> EdtResult<T>
+    where
+        F: FnOnce() -> EdtResult<T>,
+    {
+        if self.in_transaction()? {
+            return f();
+        }
+
+        let _transaction = self
+            .transaction_lock
+            .lock()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to lock transaction: {}", e)))?;
+        *self.lock_journal()? = Some(OpenTransaction {
+            owner: thread::current().id(),
+            journal: Journal::default(),
+            label: None,
+        });
+
+        let result = f();
+
+        let (journal, label) = self
+            .lock_journal()?
+            .take()
+            .map(|open| (open.journal, open.label))
+            .unwrap_or_default();
+        match result {
+            Ok(value) => {
+                let edits: Vec<FileEdit> = journal
+                    .before_images()
+                    .map(|(path, before)| FileEdit {
+                        path: path.to_path_buf(),
+                        before: before.map(str::to_string),
+                        after: fs::read_to_string(self.project_root.join(path)).ok(),
+                    })
+                    .collect();
+                journal.commit(&self.project_root)?;
+                if record {
+                    self.lock_history()?.record_as(edits, label.as_deref());
+                }
+                Ok(value)
+            }
+            Err(e) => {
+                journal.rollback(&self.project_root)?;
+                Err(e)
+            }
+        }
     }
 
-    /// Get the directory path for an entity type
-    fn get_entity_dir(&self, entity_type: &EntityType) -> PathBuf {
-        self.project_root
-            .join("entities")
-            .join(entity_type.folder_name())
+    /// True if the calling thread is inside a transaction
+    fn in_transaction(&self) -> EdtResult<bool> {
+        Ok(self
+            .lock_journal()?
+            .as_ref()
+            .is_some_and(|open| open.owner == thread::current().id()))
     }
 
-    /// Get the file path for an entity
-    fn get_entity_path(&self, entity_type: &EntityType, entity_id: &Uuid) -> PathBuf {
-        self.get_entity_dir(entity_type)
-            .join(format!("{}.ron", entity_id))
+    fn lock_journal(&self) -> EdtResult<MutexGuard<'_, Option<OpenTransaction>>> {
+        self.journal
+            .lock()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to lock journal: {}", e)))
     }
 
-    /// Ensure directory exists for entity type
-    fn ensure_entity_dir(&self, entity_type: &EntityType) -> EdtResult<()> {
-        let dir = self.get_entity_dir(entity_type);
-        fs::create_dir_all(dir)?;
-        Ok(())
+    fn lock_history(&self) -> EdtResult<MutexGuard<'_, OperationLog>> {
+        self.history
+            .lock()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to lock undo history: {}", e)))
     }
 
-    /// Write a Task entity to RON file
-    pub fn write_task(&self, task: &Task) -> EdtResult<()> {
-        self.ensure_entity_dir(&task.metadata.entity_type)?;
-        let path = self.get_entity_path(&task.metadata.entity_type, &task.metadata.id);
-        let serialized = ron::ser::to_string_pretty(task, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
+    /// Record the file before it is modified: in this thread's open transaction's journal,
+    /// or otherwise by returning its current content for the undo history
+    fn begin_edit(&self, path: &Path) -> EdtResult<Option<Option<String>>> {
+        let current = thread::current().id();
+        if let Some(open) = self.lock_journal()?.as_mut().filter(|open| open.owner == current) {
+            open.journal.record(&self.project_root, path)?;
+            return Ok(None);
+        }
+        Ok(Some(fs::read_to_string(path).ok()))
+    }
+
+    /// Add a change made outside a transaction to the undo history as its own step
+    fn finish_edit(&self, path: &Path, before: Option<Option<String>>, after: Option<&str>) -> EdtResult<()> {
+        if let Some(before) = before {
+            let relative = path.strip_prefix(&self.project_root).unwrap_or(path);
+            self.lock_history()?.record(vec![FileEdit {
+                path: relative.to_path_buf(),
+                before,
+                after: after.map(str::to_string),
+            }]);
+        }
         Ok(())
     }
 
-    /// Read a Task entity from RON file
-    pub fn read_task(&self, entity_id: &Uuid) -> EdtResult<Task> {
-        let path = self.get_entity_path(&EntityType::Task, entity_id);
-        let content = fs::read_to_string(path)?;
-        let task: Task = ron::from_str(&content)?;
-        Ok(task)
+    /// Atomically write a file, recording its previous content for rollback and undo
+    fn write_file(&self, path: &Path, contents: &str) -> EdtResult<()> {
+        let before = self.begin_edit(path)?;
+        atomic_write(path, contents.as_bytes())?;
+        self.finish_edit(path, before, Some(contents))
+    }
+
+    /// Remove a file, recording its previous content for rollback and undo
+    fn remove_file(&self, path: &Path) -> EdtResult<()> {
+        let before = self.begin_edit(path)?;
+        fs::remove_file(path)?;
+        self.finish_edit(path, before, None)
+    }
+
+    // ============================================================================
+    // Undo / Redo
+    // ============================================================================
+
+    /// Run `f` as one transaction recorded as a single undo step named `label`
+    ///
+    /// Unlike `begin_undo_group`, nothing is left open if `f` fails, and changes made by
+    /// other threads meanwhile wait for it rather than joining the step.
+    pub fn with_undo_group<T, F>(&self, label: &str, f: F) -> EdtResult<T>
+    where
+        F: FnOnce() -> EdtResult<T>,
+    {
+        self.with_transaction(|| {
+            // The outermost group names the step
+            if let Some(open) = self.lock_journal()?.as_mut() {
+                open.label.get_or_insert_with(|| label.to_string());
+            }
+            f()
+        })
     }
 
-    /// Write a Requirement entity
-    pub fn write_requirement(&self, requirement: &Requirement) -> EdtResult<()> {
-        self.ensure_entity_dir(&requirement.metadata.entity_type)?;
-        let path = self.get_entity_path(&requirement.metadata.entity_type, &requirement.metadata.id);
-        let serialized = ron::ser::to_string_pretty(requirement, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+    /// Collect all following changes into one undo step until `end_undo_group` is called
+    /// with the returned token
+    ///
+    /// For steps spanning several commands; only one group can be open at a time.
+    pub fn begin_undo_group(&self, label: &str) -> EdtResult<Uuid> {
+        self.lock_history()?.begin_group(label).ok_or_else(|| {
+            EdtError::ValidationError("Another undo group is already open".to_string())
+        })
     }
 
-    /// Read a Requirement entity
-    pub fn read_requirement(&self, entity_id: &Uuid) -> EdtResult<Requirement> {
-        let path = self.get_entity_path(&EntityType::Requirement, entity_id);
-        let content = fs::read_to_string(path)?;
-        let requirement: Requirement = ron::from_str(&content)?;
-        Ok(requirement)
+    pub fn end_undo_group(&self, token: Uuid) -> EdtResult<()> {
+        if !self.lock_history()?.end_group(token) {
+            return Err(EdtError::ValidationError("No open undo group matches this token".to_string()));
+        }
+        Ok(())
     }
 
-    /// Write a Risk entity
-    pub fn write_risk(&self, risk: &Risk) -> EdtResult<()> {
-        self.ensure_entity_dir(&risk.metadata.entity_type)?;
-        let path = self.get_entity_path(&risk.metadata.entity_type, &risk.metadata.id);
-        let serialized = ron::ser::to_string_pretty(risk, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
+    /// Close an undo group left open, e.g. by a view that was closed mid-operation
+    pub fn reset_undo_group(&self) -> EdtResult<()> {
+        self.lock_history()?.reset_group();
         Ok(())
     }
 
-    /// Read a Risk entity
-    pub fn read_risk(&self, entity_id: &Uuid) -> EdtResult<Risk> {
-        let path = self.get_entity_path(&EntityType::Risk, entity_id);
-        let content = fs::read_to_string(path)?;
-        let risk: Risk = ron::from_str(&content)?;
-        Ok(risk)
+    /// Revert the most recent step; returns `None` if there is nothing to undo
+    pub fn undo(&self) -> EdtResult<Option<HistoryEntry>> {
+        Ok(self.undo_checked(|_, _, _| Ok(()))?.map(|step| step.entry))
     }
 
-    /// Write a Hazard entity
-    pub fn write_hazard(&self, hazard: &Hazard) -> EdtResult<()> {
-        self.ensure_entity_dir(&hazard.metadata.entity_type)?;
-        let path = self.get_entity_path(&hazard.metadata.entity_type, &hazard.metadata.id);
-        let serialized = ron::ser::to_string_pretty(hazard, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+    /// Re-apply the most recently undone step; returns `None` if there is nothing to redo
+    pub fn redo(&self) -> EdtResult<Option<HistoryEntry>> {
+        Ok(self.redo_checked(|_, _, _| Ok(()))?.map(|step| step.entry))
     }
 
-    /// Read a Hazard entity
-    pub fn read_hazard(&self, entity_id: &Uuid) -> EdtResult<Hazard> {
-        let path = self.get_entity_path(&EntityType::Hazard, entity_id);
-        let content = fs::read_to_string(path)?;
-        let hazard: Hazard = ron::from_str(&content)?;
-        Ok(hazard)
+    /// Undo, first passing each entity file's current and restored content to `check`,
+    /// which can refuse the step
+    pub fn undo_checked<F>(&self, check: F) -> EdtResult<Option<AppliedStep>>
+    where
+        F: Fn(&EntityRef, Option<&str>, Option<&str>) -> EdtResult<()>,
+    {
+        self.step_history(true, &check)
     }
 
-    /// Write a RiskControl entity
-    pub fn write_risk_control(&self, control: &RiskControl) -> EdtResult<()> {
-        self.ensure_entity_dir(&control.metadata.entity_type)?;
-        let path = self.get_entity_path(&control.metadata.entity_type, &control.metadata.id);
-        let serialized = ron::ser::to_string_pretty(control, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+    /// Redo, first passing each entity file's current and restored content to `check`
+    pub fn redo_checked<F>(&self, check: F) -> EdtResult<Option<AppliedStep>>
+    where
+        F: Fn(&EntityRef, Option<&str>, Option<&str>) -> EdtResult<()>,
+    {
+        self.step_history(false, &check)
     }
 
-    /// Read a RiskControl entity
-    pub fn read_risk_control(&self, entity_id: &Uuid) -> EdtResult<RiskControl> {
-        let path = self.get_entity_path(&EntityType::RiskControl, entity_id);
-        let content = fs::read_to_string(path)?;
-        let control: RiskControl = ron::from_str(&content)?;
-        Ok(control)
-    }
+    fn step_history<F>(&self, undo: bool, check: &F) -> EdtResult<Option<AppliedStep>>
+    where
+        F: Fn(&EntityRef, Option<&str>, Option<&str>) -> EdtResult<()>,
+    {
+        // Enter the transaction before locking the history, the order commits lock them in
+        self.run_transaction(false, || {
+            let mut history = self.lock_history()?;
+            if history.in_group() {
+                return Err(EdtError::ValidationError(format!(
+                    "Cannot {} while an undo group is open",
+                    if undo { "undo" } else { "redo" }
+                )));
+            }
+            let popped = if undo { history.pop_undo() } else { history.pop_redo() };
+            let Some(operation) = popped else { return Ok(None) };
+
+            let applied = AppliedStep {
+                entry: operation.entry(),
+                edits: operation
+                    .edits
+                    .iter()
+                    .map(|edit| FileEdit {
+                        path: edit.path.clone(),
+                        before: if undo { edit.after.clone() } else { edit.before.clone() },
+                        after: if undo { edit.before.clone() } else { edit.after.clone() },
+                    })
+                    .collect(),
+            };
+            let result = self.apply_operation(&operation, undo, check);
+            // An applied step moves to the other stack; a failed one goes back where it was
+            if result.is_ok() == undo {
+                history.push_redo(operation);
+            } else {
+                history.push_undo(operation);
+            }
+            result.map(|()| Some(applied))
+        })
+    }
+
+    /// Steps available to undo and redo
+    pub fn undo_history(&self) -> EdtResult<UndoHistory> {
+        Ok(self.lock_history()?.entries())
+    }
+
+    /// Restore each file of a step to its before-image (undo) or after-image (redo)
+    fn apply_operation<F>(&self, operation: &Operation, undo: bool, check: &F) -> EdtResult<()>
+    where
+        F: Fn(&EntityRef, Option<&str>, Option<&str>) -> EdtResult<()>,
+    {
+        // Files edited by hand or pulled from git since the step must not be overwritten
+        for edit in &operation.edits {
+            let (expected, target) = if undo { (&edit.after, &edit.before) } else { (&edit.before, &edit.after) };
+            let current = fs::read_to_string(self.project_root.join(&edit.path)).ok();
+            if &current != expected {
+                return Err(EdtError::ValidationError(format!(
+                    "{} has changed since '{}'; it can no longer be {}",
+                    edit.path.display(),
+                    operation.label,
+                    if undo { "undone" } else { "redone" }
+                )));
+            }
+            if let Some(entity) = edit.entity() {
+                check(&entity, current.as_deref(), target.as_deref())?;
+            }
+        }
 
-    /// Write a Milestone entity
-    pub fn write_milestone(&self, milestone: &Milestone) -> EdtResult<()> {
-        self.ensure_entity_dir(&milestone.metadata.entity_type)?;
-        let path = self.get_entity_path(&milestone.metadata.entity_type, &milestone.metadata.id);
-        let serialized = ron::ser::to_string_pretty(milestone, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+        let edits: Vec<&FileEdit> = if undo {
+            operation.edits.iter().rev().collect()
+        } else {
+            operation.edits.iter().collect()
+        };
+        self.run_transaction(false, || {
+            for edit in edits {
+                let path = self.project_root.join(&edit.path);
+                match if undo { &edit.before } else { &edit.after } {
+                    Some(content) => {
+                        if let Some(parent) = path.parent() {
+                            fs::create_dir_all(parent)?;
+                        }
+                        self.write_file(&path, content)?;
+                    }
+                    None => {
+                        if path.exists() {
+                            self.remove_file(&path)?;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
     }
 
-    /// Read a Milestone entity
-    pub fn read_milestone(&self, entity_id: &Uuid) -> EdtResult<Milestone> {
-        let path = self.get_entity_path(&EntityType::Milestone, entity_id);
-        let content = fs::read_to_string(path)?;
-        let milestone: Milestone = ron::from_str(&content)?;
-        Ok(milestone)
+    /// Get the directory path for an entity type
+    fn get_entity_dir(&self, entity_type: &EntityType) -> PathBuf {
+        self.project_root
+            .join("entities")
+            .join(entity_type.folder_name())
     }
 
-    /// Write a Resource entity
-    pub fn write_resource(&self, resource: &Resource) -> EdtResult<()> {
-        self.ensure_entity_dir(&resource.metadata.entity_type)?;
-        let path = self.get_entity_path(&resource.metadata.entity_type, &resource.metadata.id);
-        let serialized = ron::ser::to_string_pretty(resource, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+    /// Get the file path for an entity
+    pub(crate) fn get_entity_path(&self, entity_type: &EntityType, entity_id: &Uuid) -> PathBuf {
+        self.get_entity_dir(entity_type)
+            .join(format!("{}.ron", entity_id))
     }
 
-    /// Read a Resource entity
-    pub fn read_resource(&self, entity_id: &Uuid) -> EdtResult<Resource> {
-        let path = self.get_entity_path(&EntityType::Resource, entity_id);
-        let content = fs::read_to_string(path)?;
-        let resource: Resource = ron::from_str(&content)?;
-        Ok(resource)
+    /// Ensure directory exists for entity type
+    fn ensure_entity_dir(&self, entity_type: &EntityType) -> EdtResult<()> {
+        let dir = self.get_entity_dir(entity_type);
+        fs::create_dir_all(dir)?;
+        Ok(())
     }
 
-    /// Write a Calendar entity
-    pub fn write_calendar(&self, calendar: &Calendar) -> EdtResult<()> {
-        self.ensure_entity_dir(&calendar.metadata.entity_type)?;
-        let path = self.get_entity_path(&calendar.metadata.entity_type, &calendar.metadata.id);
-        let serialized = ron::ser::to_string_pretty(calendar, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
+    /// Write an entity to its RON file
+    pub fn write<T: StoredEntity>(&self, entity: &T) -> EdtResult<()> {
+        self.ensure_entity_dir(&T::ENTITY_TYPE)?;
+        let path = self.get_entity_path(&T::ENTITY_TYPE, &entity.id());
+        let serialized = ron::ser::to_string_pretty(entity, ron::ser::PrettyConfig::default())?;
+        self.write_file(&path, &serialized)?;
         Ok(())
     }
 
-    /// Read a Calendar entity
-    pub fn read_calendar(&self, entity_id: &Uuid) -> EdtResult<Calendar> {
-        let path = self.get_entity_path(&EntityType::Calendar, entity_id);
-        let content = fs::read_to_string(path)?;
-        let calendar: Calendar = ron::from_str(&content)?;
-        Ok(calendar)
+    /// Read an entity from its RON file
+    pub fn read<T: StoredEntity>(&self, entity_id: &Uuid) -> EdtResult<T> {
+        let path = self.get_entity_path(&T::ENTITY_TYPE, entity_id);
+        if !path.exists() {
+            return Err(EdtError::EntityNotFound(entity_id.to_string()));
+        }
+        Self::read_ron(&path)
     }
 
-    /// Write a Baseline entity
-    pub fn write_baseline(&self, baseline: &Baseline) -> EdtResult<()> {
-        self.ensure_entity_dir(&baseline.metadata.entity_type)?;
-        let path = self.get_entity_path(&baseline.metadata.entity_type, &baseline.metadata.id);
-        let serialized = ron::ser::to_string_pretty(baseline, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+    /// List the IDs of all stored entities of type `T`
+    pub fn list<T: StoredEntity>(&self) -> EdtResult<Vec<Uuid>> {
+        self.list_ids(&T::ENTITY_TYPE)
     }
 
-    /// Read a Baseline entity
-    pub fn read_baseline(&self, entity_id: &Uuid) -> EdtResult<Baseline> {
-        let path = self.get_entity_path(&EntityType::Baseline, entity_id);
-        let content = fs::read_to_string(path)?;
-        let baseline: Baseline = ron::from_str(&content)?;
-        Ok(baseline)
+    /// Read every stored entity of type `T`, parsing files in parallel
+    pub fn read_all<T: StoredEntity>(&self) -> EdtResult<Vec<T>> {
+        let dir = self.get_entity_dir(&T::ENTITY_TYPE);
+        Self::list_ron_ids(&dir)?
+            .par_iter()
+            .map(|id| Self::read_ron(&dir.join(format!("{}.ron", id))))
+            .collect()
     }
 
-    /// Write an Assembly entity
-    pub fn write_assembly(&self, assembly: &Assembly) -> EdtResult<()> {
-        self.ensure_entity_dir(&assembly.metadata.entity_type)?;
-        let path = self.get_entity_path(&assembly.metadata.entity_type, &assembly.metadata.id);
-        let serialized = ron::ser::to_string_pretty(assembly, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+    /// Write already-serialized entity RON, e.g. after a schema migration
+    pub(crate) fn write_raw(&self, entity_type: &EntityType, entity_id: &Uuid, content: &str) -> EdtResult<()> {
+        self.ensure_entity_dir(entity_type)?;
+        let path = self.get_entity_path(entity_type, entity_id);
+        self.write_file(&path, content)
     }
 
-    /// Read an Assembly entity
-    pub fn read_assembly(&self, entity_id: &Uuid) -> EdtResult<Assembly> {
-        let path = self.get_entity_path(&EntityType::Assembly, entity_id);
+    /// Parse an entity file, upgrading older schema versions in memory
+    fn read_ron<T: StoredEntity>(path: &Path) -> EdtResult<T> {
         let content = fs::read_to_string(path)?;
-        let assembly: Assembly = ron::from_str(&content)?;
-        Ok(assembly)
+        // Current files, the common case, are parsed once without probing their version
+        let parsed = ron::from_str::<T>(&content);
+        if matches!(&parsed, Ok(entity) if entity.metadata().schema_version == CURRENT_SCHEMA_VERSION) {
+            return Ok(parsed?);
+        }
+        match MigrationRegistry::builtin().upgrade(&T::ENTITY_TYPE, &content)? {
+            Some(upgraded) => Ok(ron::from_str(&upgraded)?),
+            None => Ok(parsed?),
+        }
     }
 
-    /// Write a Component entity
-    pub fn write_component(&self, component: &Component) -> EdtResult<()> {
-        self.ensure_entity_dir(&component.metadata.entity_type)?;
-        let path = self.get_entity_path(&component.metadata.entity_type, &component.metadata.id);
-        let serialized = ron::ser::to_string_pretty(component, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
+    /// Delete an entity file
+    pub fn delete(&self, entity_type: &EntityType, entity_id: &Uuid) -> EdtResult<()> {
+        let path = self.get_entity_path(entity_type, entity_id);
+        if path.exists() {
+            self.remove_file(&path)?;
+        }
         Ok(())
     }
 
-    /// Read a Component entity
-    pub fn read_component(&self, entity_id: &Uuid) -> EdtResult<Component> {
-        let path = self.get_entity_path(&EntityType::Component, entity_id);
-        let content = fs::read_to_string(path)?;
-        let component: Component = ron::from_str(&content)?;
-        Ok(component)
+    /// Check if entity exists
+    pub fn exists(&self, entity_type: &EntityType, entity_id: &Uuid) -> bool {
+        self.get_entity_path(entity_type, entity_id).exists()
     }
 
-    /// Write a Feature entity
-    pub fn write_feature(&self, feature: &Feature) -> EdtResult<()> {
-        self.ensure_entity_dir(&feature.metadata.entity_type)?;
-        let path = self.get_entity_path(&feature.metadata.entity_type, &feature.metadata.id);
-        let serialized = ron::ser::to_string_pretty(feature, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+    /// List all entity IDs of a given type
+    pub fn list_ids(&self, entity_type: &EntityType) -> EdtResult<Vec<Uuid>> {
+        Self::list_ron_ids(&self.get_entity_dir(entity_type))
     }
 
-    /// Read a Feature entity
-    pub fn read_feature(&self, entity_id: &Uuid) -> EdtResult<Feature> {
-        let path = self.get_entity_path(&EntityType::Feature, entity_id);
-        let content = fs::read_to_string(path)?;
-        let feature: Feature = ron::from_str(&content)?;
-        Ok(feature)
-    }
+    // ============================================================================
+    // Link Storage
+    // ============================================================================
 
-    /// Write a Mate entity
-    pub fn write_mate(&self, mate: &Mate) -> EdtResult<()> {
-        self.ensure_entity_dir(&mate.metadata.entity_type)?;
-        let path = self.get_entity_path(&mate.metadata.entity_type, &mate.metadata.id);
-        let serialized = ron::ser::to_string_pretty(mate, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+    /// Get the directory path for links
+    fn get_links_dir(&self) -> PathBuf {
+        self.project_root.join("links")
     }
 
-    /// Read a Mate entity
-    pub fn read_mate(&self, entity_id: &Uuid) -> EdtResult<Mate> {
-        let path = self.get_entity_path(&EntityType::Mate, entity_id);
-        let content = fs::read_to_string(path)?;
-        let mate: Mate = ron::from_str(&content)?;
-        Ok(mate)
+    /// Get the file path for a link
+    fn get_link_path(&self, link_id: &Uuid) -> PathBuf {
+        self.get_links_dir().join(format!("{}.ron", link_id))
     }
 
-    /// Write a Stackup entity
-    pub fn write_stackup(&self, stackup: &Stackup) -> EdtResult<()> {
-        self.ensure_entity_dir(&stackup.metadata.entity_type)?;
-        let path = self.get_entity_path(&stackup.metadata.entity_type, &stackup.metadata.id);
-        let serialized = ron::ser::to_string_pretty(stackup, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
+    /// Write a Link to RON file
+    pub fn write_link(&self, link: &Link) -> EdtResult<()> {
+        fs::create_dir_all(self.get_links_dir())?;
+        let path = self.get_link_path(&link.id);
+        let serialized = ron::ser::to_string_pretty(link, ron::ser::PrettyConfig::default())?;
+        self.write_file(&path, &serialized)?;
         Ok(())
     }
 
-    /// Read a Stackup entity
-    pub fn read_stackup(&self, entity_id: &Uuid) -> EdtResult<Stackup> {
-        let path = self.get_entity_path(&EntityType::Stackup, entity_id);
+    /// Read a Link from RON file
+    pub fn read_link(&self, link_id: &Uuid) -> EdtResult<Link> {
+        let path = self.get_link_path(link_id);
         let content = fs::read_to_string(path)?;
-        let stackup: Stackup = ron::from_str(&content)?;
-        Ok(stackup)
+        let link: Link = ron::from_str(&content)?;
+        Ok(link)
     }
 
-    /// Write a Supplier entity
-    pub fn write_supplier(&self, supplier: &Supplier) -> EdtResult<()> {
-        self.ensure_entity_dir(&supplier.metadata.entity_type)?;
-        let path = self.get_entity_path(&supplier.metadata.entity_type, &supplier.metadata.id);
-        let serialized = ron::ser::to_string_pretty(supplier, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
+    /// Delete a link file
+    pub fn delete_link(&self, link_id: &Uuid) -> EdtResult<()> {
+        let path = self.get_link_path(link_id);
+        if path.exists() {
+            self.remove_file(&path)?;
+        }
         Ok(())
     }
 
-    /// Read a Supplier entity
-    pub fn read_supplier(&self, entity_id: &Uuid) -> EdtResult<Supplier> {
-        let path = self.get_entity_path(&EntityType::Supplier, entity_id);
-        let content = fs::read_to_string(path)?;
-        let supplier: Supplier = ron::from_str(&content)?;
-        Ok(supplier)
+    /// List all link IDs
+    pub fn list_link_ids(&self) -> EdtResult<Vec<Uuid>> {
+        Self::list_ron_ids(&self.get_links_dir())
     }
 
-    /// Write a Quote entity
-    pub fn write_quote(&self, quote: &Quote) -> EdtResult<()> {
-        self.ensure_entity_dir(&quote.metadata.entity_type)?;
-        let path = self.get_entity_path(&quote.metadata.entity_type, &quote.metadata.id);
-        let serialized = ron::ser::to_string_pretty(quote, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
-    }
+    // ============================================================================
+    // Signature Storage
+    // ============================================================================
 
-    /// Read a Quote entity
-    pub fn read_quote(&self, entity_id: &Uuid) -> EdtResult<Quote> {
-        let path = self.get_entity_path(&EntityType::Quote, entity_id);
-        let content = fs::read_to_string(path)?;
-        let quote: Quote = ron::from_str(&content)?;
-        Ok(quote)
+    /// Get the directory holding an entity's signatures
+    fn get_signatures_dir(&self, entity_id: &Uuid) -> PathBuf {
+        self.project_root.join("signatures").join(entity_id.to_string())
     }
 
-    /// Write a Verification entity
-    pub fn write_verification(&self, verification: &Verification) -> EdtResult<()> {
-        self.ensure_entity_dir(&verification.metadata.entity_type)?;
-        let path = self.get_entity_path(&verification.metadata.entity_type, &verification.metadata.id);
-        let serialized = ron::ser::to_string_pretty(verification, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+    /// Write a signature to its RON file
+    ///
+    /// Signatures are permanent records, so they bypass the undo history.
+    pub fn write_signature(&self, signature: &Signature) -> EdtResult<()> {
+        let dir = self.get_signatures_dir(&signature.entity_id);
+        fs::create_dir_all(&dir)?;
+        let serialized = ron::ser::to_string_pretty(signature, ron::ser::PrettyConfig::default())?;
+        atomic_write(&dir.join(format!("{}.ron", signature.id)), serialized.as_bytes())
     }
 
-    /// Read a Verification entity
-    pub fn read_verification(&self, entity_id: &Uuid) -> EdtResult<Verification> {
-        let path = self.get_entity_path(&EntityType::Verification, entity_id);
-        let content = fs::read_to_string(path)?;
-        let verification: Verification = ron::from_str(&content)?;
-        Ok(verification)
+    /// Read every signature applied to an entity, oldest first
+    pub fn read_signatures(&self, entity_id: &Uuid) -> EdtResult<Vec<Signature>> {
+        let dir = self.get_signatures_dir(entity_id);
+        let mut signatures = Self::list_ron_ids(&dir)?
+            .iter()
+            .map(|id| Ok(ron::from_str(&fs::read_to_string(dir.join(format!("{}.ron", id)))?)?))
+            .collect::<EdtResult<Vec<Signature>>>()?;
+        signatures.sort_by_key(|signature| signature.signed_at);
+        Ok(signatures)
     }
 
-    /// Write a Validation entity
-    pub fn write_validation(&self, validation: &Validation) -> EdtResult<()> {
-        self.ensure_entity_dir(&validation.metadata.entity_type)?;
-        let path = self.get_entity_path(&validation.metadata.entity_type, &validation.metadata.id);
-        let serialized = ron::ser::to_string_pretty(validation, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+    /// Read an entity file as JSON, via its concrete type
+    pub fn read_entity_json(&self, entity_type: &EntityType, entity_id: &Uuid) -> EdtResult<serde_json::Value> {
+        let path = self.get_entity_path(entity_type, entity_id);
+        if !path.exists() {
+            return Err(EdtError::EntityNotFound(entity_id.to_string()));
+        }
+        Self::entity_ron_to_json(entity_type, &fs::read_to_string(path)?)
+    }
+
+    /// Parse an entity's RON content into JSON, using the concrete type for `entity_type`
+    ///
+    /// RON cannot be read into a generic JSON value directly because enum variants are
+    /// only resolvable against the typed model.
+    pub fn entity_ron_to_json(entity_type: &EntityType, content: &str) -> EdtResult<serde_json::Value> {
+        let codec = Self::codec(entity_type)?;
+        let parsed = (codec.ron_to_json)(content);
+        if matches!(&parsed, Ok(value) if value["metadata"]["schema_version"] == CURRENT_SCHEMA_VERSION) {
+            return parsed;
+        }
+        match MigrationRegistry::builtin().upgrade(entity_type, content)? {
+            Some(upgraded) => (codec.ron_to_json)(&upgraded),
+            None => parsed,
+        }
     }
 
-    /// Read a Validation entity
-    pub fn read_validation(&self, entity_id: &Uuid) -> EdtResult<Validation> {
-        let path = self.get_entity_path(&EntityType::Validation, entity_id);
-        let content = fs::read_to_string(path)?;
-        let validation: Validation = ron::from_str(&content)?;
-        Ok(validation)
+    /// Serialize an entity's JSON form back to RON, validating it against the concrete type
+    pub fn entity_json_to_ron(entity_type: &EntityType, value: &serde_json::Value) -> EdtResult<String> {
+        (Self::codec(entity_type)?.json_to_ron)(value)
     }
 
-    /// Write a Manufacturing entity
-    pub fn write_manufacturing(&self, manufacturing: &Manufacturing) -> EdtResult<()> {
-        self.ensure_entity_dir(&manufacturing.metadata.entity_type)?;
-        let path = self.get_entity_path(&manufacturing.metadata.entity_type, &manufacturing.metadata.id);
-        let serialized = ron::ser::to_string_pretty(manufacturing, ron::ser::PrettyConfig::default())?;
-        fs::write(path, serialized)?;
-        Ok(())
+    /// True if entities of this type are stored as RON files with a model struct
+    pub fn has_model(entity_type: &EntityType) -> bool {
+        ENTITY_CODECS.iter().any(|codec| codec.entity_type == *entity_type)
     }
 
-    /// Read a Manufacturing entity
-    pub fn read_manufacturing(&self, entity_id: &Uuid) -> EdtResult<Manufacturing> {
-        let path = self.get_entity_path(&EntityType::Manufacturing, entity_id);
-        let content = fs::read_to_string(path)?;
-        let manufacturing: Manufacturing = ron::from_str(&content)?;
-        Ok(manufacturing)
+    fn codec(entity_type: &EntityType) -> EdtResult<&'static EntityCodec> {
+        ENTITY_CODECS
+            .iter()
+            .find(|codec| codec.entity_type == *entity_type)
+            .ok_or_else(|| EdtError::ValidationError(format!("{:?} entities have no stored model", entity_type)))
     }
 
-    /// Delete an entity file
-    pub fn delete(&self, entity_type: &EntityType, entity_id: &Uuid) -> EdtResult<()> {
-        let path = self.get_entity_path(entity_type, entity_id);
-        if path.exists() {
-            fs::remove_file(path)?;
-        }
-        Ok(())
+    fn ron_to_json<T: StoredEntity>(content: &str) -> EdtResult<serde_json::Value> {
+        Ok(serde_json::to_value(ron::from_str::<T>(content)?)?)
     }
 
-    /// Check if entity exists
-    pub fn exists(&self, entity_type: &EntityType, entity_id: &Uuid) -> bool {
-        self.get_entity_path(entity_type, entity_id).exists()
+    fn json_to_ron<T: StoredEntity>(value: &serde_json::Value) -> EdtResult<String> {
+        let entity: T = serde_json::from_value(value.clone())?;
+        Ok(ron::ser::to_string_pretty(&entity, ron::ser::PrettyConfig::default())?)
     }
 
-    /// List all entity IDs of a given type
-    pub fn list_ids(&self, entity_type: &EntityType) -> EdtResult<Vec<Uuid>> {
-        let dir = self.get_entity_dir(entity_type);
-
+    /// List the UUIDs of all `<uuid>.ron` files in a directory
+    fn list_ron_ids(dir: &Path) -> EdtResult<Vec<Uuid>> {
         if !dir.exists() {
             return Ok(vec![]);
         }
@@ -412,7 +604,10 @@ impl RonStorage {
 mod tests {
     use super::*;
     use tempfile::TempDir;
-    use crate::models::{EntityMetadata, EntityType, TaskType, SchedulingMode, ResourceType};
+    use crate::models::{
+        EntityMetadata, EntityType, TaskType, SchedulingMode, ResourceType, Task, Requirement, Risk, Hazard,
+        Milestone, Resource, Calendar, Baseline,
+    };
     use chrono::Utc;
 
     fn create_test_storage() -> (TempDir, RonStorage) {
@@ -463,13 +658,13 @@ mod tests {
         };
 
         // Write task
-        storage.write_task(&task).unwrap();
+        storage.write(&task).unwrap();
 
         // Verify file exists
         assert!(storage.exists(&EntityType::Task, &task_id));
 
         // Read task back
-        let read_task = storage.read_task(&task_id).unwrap();
+        let read_task = storage.read::<Task>(&task_id).unwrap();
         assert_eq!(read_task.name, "Test Task");
         assert_eq!(read_task.percent_complete, 0.5);
         assert_eq!(read_task.task_type, TaskType::EffortDriven);
@@ -493,10 +688,10 @@ mod tests {
             verification_method: None,
         };
 
-        storage.write_requirement(&requirement).unwrap();
+        storage.write(&requirement).unwrap();
         assert!(storage.exists(&EntityType::Requirement, &req_id));
 
-        let read_req = storage.read_requirement(&req_id).unwrap();
+        let read_req = storage.read::<Requirement>(&req_id).unwrap();
         assert_eq!(read_req.name, "REQ-001");
         assert_eq!(read_req.requirement_type, "System Requirement");
     }
@@ -517,15 +712,17 @@ mod tests {
             probability: 3,
             severity: 4,
             risk_score: 12,
+            is_acceptable: false,
             residual_probability: Some(1),
             residual_severity: Some(4),
             residual_risk_score: Some(4),
+            residual_is_acceptable: Some(true),
         };
 
-        storage.write_risk(&risk).unwrap();
+        storage.write(&risk).unwrap();
         assert!(storage.exists(&EntityType::Risk, &risk_id));
 
-        let read_risk = storage.read_risk(&risk_id).unwrap();
+        let read_risk = storage.read::<Risk>(&risk_id).unwrap();
         assert_eq!(read_risk.name, "RISK-001");
         assert_eq!(read_risk.risk_score, 12);
     }
@@ -546,10 +743,10 @@ mod tests {
             harms: vec!["Harm 1".to_string()],
         };
 
-        storage.write_hazard(&hazard).unwrap();
+        storage.write(&hazard).unwrap();
         assert!(storage.exists(&EntityType::Hazard, &hazard_id));
 
-        let read_hazard = storage.read_hazard(&hazard_id).unwrap();
+        let read_hazard = storage.read::<Hazard>(&hazard_id).unwrap();
         assert_eq!(read_hazard.name, "HAZ-001");
         assert_eq!(read_hazard.causes.len(), 1);
     }
@@ -570,10 +767,10 @@ mod tests {
             is_critical_path: true,
         };
 
-        storage.write_milestone(&milestone).unwrap();
+        storage.write(&milestone).unwrap();
         assert!(storage.exists(&EntityType::Milestone, &milestone_id));
 
-        let read_milestone = storage.read_milestone(&milestone_id).unwrap();
+        let read_milestone = storage.read::<Milestone>(&milestone_id).unwrap();
         assert_eq!(read_milestone.name, "M1: Project Start");
         assert!(read_milestone.is_critical_path);
     }
@@ -595,10 +792,10 @@ mod tests {
             calendar_id: None,
         };
 
-        storage.write_resource(&resource).unwrap();
+        storage.write(&resource).unwrap();
         assert!(storage.exists(&EntityType::Resource, &resource_id));
 
-        let read_resource = storage.read_resource(&resource_id).unwrap();
+        let read_resource = storage.read::<Resource>(&resource_id).unwrap();
         assert_eq!(read_resource.name, "John Doe");
         assert_eq!(read_resource.resource_type, ResourceType::Labor);
     }
@@ -633,7 +830,7 @@ mod tests {
             baseline_data: None,
         };
 
-        storage.write_task(&task).unwrap();
+        storage.write(&task).unwrap();
         assert!(storage.exists(&EntityType::Task, &task_id));
 
         storage.delete(&EntityType::Task, &task_id).unwrap();
@@ -669,20 +866,264 @@ mod tests {
                 slack: None,
                 baseline_data: None,
             };
-            storage.write_task(&task).unwrap();
+            storage.write(&task).unwrap();
         }
 
         let task_ids = storage.list_ids(&EntityType::Task).unwrap();
         assert_eq!(task_ids.len(), 3);
     }
 
+    #[test]
+    fn test_list_and_read_all_generic() {
+        let (_temp, storage) = create_test_storage();
+
+        for i in 0..3 {
+            let requirement = Requirement {
+                metadata: EntityMetadata::new(EntityType::Requirement),
+                name: format!("REQ-00{}", i),
+                description: "Description".to_string(),
+                notes: None,
+                requirement_type: "System Requirement".to_string(),
+                rationale: None,
+                source: None,
+                verification_method: None,
+            };
+            storage.write(&requirement).unwrap();
+        }
+
+        assert_eq!(storage.list::<Requirement>().unwrap().len(), 3);
+        assert!(storage.list::<Task>().unwrap().is_empty());
+
+        let mut names: Vec<String> = storage
+            .read_all::<Requirement>()
+            .unwrap()
+            .into_iter()
+            .map(|r| r.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["REQ-000", "REQ-001", "REQ-002"]);
+    }
+
+    #[test]
+    fn test_write_read_and_delete_link() {
+        use crate::models::{LinkType, LinkMetadata};
+
+        let (_temp, storage) = create_test_storage();
+
+        let link = Link::new(
+            Uuid::new_v4(),
+            EntityType::Assembly,
+            Uuid::new_v4(),
+            EntityType::Component,
+            LinkType::Contains,
+            Some(LinkMetadata {
+                quantity: Some(4),
+                notes: None,
+            }),
+        );
+
+        storage.write_link(&link).unwrap();
+        assert_eq!(storage.list_link_ids().unwrap(), vec![link.id]);
+
+        let read_link = storage.read_link(&link.id).unwrap();
+        assert_eq!(read_link.link_type, LinkType::Contains);
+        assert_eq!(read_link.to_entity_id, link.to_entity_id);
+        assert_eq!(read_link.metadata.unwrap().quantity, Some(4));
+
+        storage.delete_link(&link.id).unwrap();
+        assert!(storage.list_link_ids().unwrap().is_empty());
+    }
+
+    fn contains_link() -> Link {
+        use crate::models::LinkType;
+
+        Link::new(
+            Uuid::new_v4(),
+            EntityType::Assembly,
+            Uuid::new_v4(),
+            EntityType::Component,
+            LinkType::Contains,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_transaction_commits_on_success() {
+        let (temp, storage) = create_test_storage();
+        let first = contains_link();
+        let second = contains_link();
+
+        storage
+            .with_transaction(|| {
+                storage.write_link(&first)?;
+                storage.write_link(&second)
+            })
+            .unwrap();
+
+        assert_eq!(storage.list_link_ids().unwrap().len(), 2);
+        assert!(!temp.path().join(crate::storage::journal::JOURNAL_PATH).exists());
+    }
+
+    #[test]
+    fn test_transaction_rolls_back_on_error() {
+        let (temp, storage) = create_test_storage();
+        let kept = contains_link();
+        let added = contains_link();
+        storage.write_link(&kept).unwrap();
+
+        let result: EdtResult<()> = storage.with_transaction(|| {
+            storage.delete_link(&kept.id)?;
+            storage.write_link(&added)?;
+            // Nested calls join the outer transaction
+            storage.with_transaction(|| Err(EdtError::ValidationError("boom".to_string())))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(storage.list_link_ids().unwrap(), vec![kept.id]);
+        assert!(!temp.path().join(crate::storage::journal::JOURNAL_PATH).exists());
+    }
+
+    #[test]
+    fn test_failed_transaction_keeps_other_thread_writes() {
+        use std::sync::{mpsc, Arc};
+
+        let (_temp, storage) = create_test_storage();
+        let storage = Arc::new(storage);
+        let rolled_back = contains_link();
+        let concurrent = contains_link();
+
+        let (opened_tx, opened_rx) = mpsc::channel();
+        let (written_tx, written_rx) = mpsc::channel();
+
+        let writer = {
+            let storage = Arc::clone(&storage);
+            let concurrent = concurrent.clone();
+            std::thread::spawn(move || {
+                opened_rx.recv().unwrap();
+                storage.write_link(&concurrent).unwrap();
+                written_tx.send(()).unwrap();
+            })
+        };
+
+        let result: EdtResult<()> = storage.with_transaction(|| {
+            storage.write_link(&rolled_back)?;
+            opened_tx.send(()).unwrap();
+            written_rx.recv().unwrap();
+            Err(EdtError::ValidationError("boom".to_string()))
+        });
+        writer.join().unwrap();
+
+        assert!(result.is_err());
+        assert_eq!(storage.list_link_ids().unwrap(), vec![concurrent.id]);
+        // The other thread's write is its own undo step
+        let history = storage.undo_history().unwrap();
+        assert_eq!(history.undo.len(), 1);
+    }
+
+    #[test]
+    fn test_interrupted_transaction_recovered_on_open() {
+        let (temp, storage) = create_test_storage();
+        let link = contains_link();
+
+        // Simulate a crash after the journal was flushed but before commit
+        let mut journal = Journal::default();
+        journal.record(temp.path(), &storage.get_link_path(&link.id)).unwrap();
+        storage.write_link(&link).unwrap();
+        drop(storage);
+
+        let storage = RonStorage::new(temp.path()).unwrap();
+        assert!(storage.list_link_ids().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_undo_and_redo_transaction() {
+        let (_temp, storage) = create_test_storage();
+        let kept = contains_link();
+        let added = contains_link();
+        storage.write_link(&kept).unwrap();
+
+        storage
+            .with_transaction(|| {
+                storage.delete_link(&kept.id)?;
+                storage.write_link(&added)
+            })
+            .unwrap();
+        assert_eq!(storage.undo_history().unwrap().undo.len(), 2);
+
+        // The whole transaction is one step
+        let undone = storage.undo().unwrap().unwrap();
+        assert!(undone.links_changed);
+        assert_eq!(storage.list_link_ids().unwrap(), vec![kept.id]);
+
+        storage.redo().unwrap().unwrap();
+        assert_eq!(storage.list_link_ids().unwrap(), vec![added.id]);
+
+        storage.undo().unwrap();
+        storage.undo().unwrap();
+        assert!(storage.list_link_ids().unwrap().is_empty());
+        assert!(storage.undo().unwrap().is_none());
+        assert_eq!(storage.undo_history().unwrap().redo.len(), 2);
+    }
+
+    #[test]
+    fn test_undo_group_and_external_edit() {
+        let (_temp, storage) = create_test_storage();
+        let first = contains_link();
+        let second = contains_link();
+
+        let token = storage.begin_undo_group("Link both").unwrap();
+        assert!(storage.begin_undo_group("Other").is_err());
+        storage.write_link(&first).unwrap();
+        storage.write_link(&second).unwrap();
+        assert!(storage.undo().is_err());
+        assert!(storage.end_undo_group(Uuid::new_v4()).is_err());
+        storage.end_undo_group(token).unwrap();
+
+        let history = storage.undo_history().unwrap();
+        assert_eq!(history.undo.len(), 1);
+        assert_eq!(history.undo[0].label, "Link both");
+
+        // A file changed outside the app since the step blocks the undo
+        fs::write(storage.get_link_path(&first.id), "edited").unwrap();
+        assert!(matches!(storage.undo(), Err(EdtError::ValidationError(_))));
+        assert_eq!(storage.undo_history().unwrap().undo.len(), 1);
+    }
+
+    #[test]
+    fn test_with_undo_group_records_one_step_and_closes_on_failure() {
+        let (_temp, storage) = create_test_storage();
+        let first = contains_link();
+        let second = contains_link();
+
+        storage
+            .with_undo_group("Link both", || {
+                storage.write_link(&first)?;
+                storage.with_undo_group("Inner", || storage.write_link(&second))
+            })
+            .unwrap();
+        let history = storage.undo_history().unwrap();
+        assert_eq!(history.undo.len(), 1);
+        assert_eq!(history.undo[0].label, "Link both");
+
+        let result: EdtResult<()> = storage.with_undo_group("Fails", || {
+            storage.delete_link(&first.id)?;
+            Err(EdtError::ValidationError("stop".to_string()))
+        });
+        assert!(result.is_err());
+        assert!(storage.get_link_path(&first.id).exists());
+
+        // Nothing was left open, so undo still works
+        storage.undo().unwrap().unwrap();
+        assert!(!storage.get_link_path(&second.id).exists());
+    }
+
     #[test]
     fn test_read_nonexistent_entity() {
         let (_temp, storage) = create_test_storage();
 
         let fake_id = Uuid::new_v4();
-        let result = storage.read_task(&fake_id);
-        assert!(result.is_err());
+        let result = storage.read::<Task>(&fake_id);
+        assert!(matches!(result, Err(EdtError::EntityNotFound(_))));
     }
 
     #[test]
@@ -708,10 +1149,10 @@ mod tests {
             holidays: vec![],
         };
 
-        storage.write_calendar(&calendar).unwrap();
+        storage.write(&calendar).unwrap();
         assert!(storage.exists(&EntityType::Calendar, &calendar_id));
 
-        let read_calendar = storage.read_calendar(&calendar_id).unwrap();
+        let read_calendar = storage.read::<Calendar>(&calendar_id).unwrap();
         assert_eq!(read_calendar.name, "Standard Work Week");
         assert_eq!(read_calendar.work_hours_per_day, 8.0);
         assert_eq!(read_calendar.work_days.len(), 5);
@@ -735,10 +1176,10 @@ mod tests {
             task_ids: vec![task1_id, task2_id],
         };
 
-        storage.write_baseline(&baseline).unwrap();
+        storage.write(&baseline).unwrap();
         assert!(storage.exists(&EntityType::Baseline, &baseline_id));
 
-        let read_baseline = storage.read_baseline(&baseline_id).unwrap();
+        let read_baseline = storage.read::<Baseline>(&baseline_id).unwrap();
         assert_eq!(read_baseline.name, "Q1 2025 Baseline");
         assert_eq!(read_baseline.task_ids.len(), 2);
         assert!(read_baseline.task_ids.contains(&task1_id));
@@ -761,10 +1202,10 @@ mod tests {
             notes: Some("Initial design".to_string()),
         };
 
-        storage.write_assembly(&assembly).unwrap();
+        storage.write(&assembly).unwrap();
         assert!(storage.exists(&EntityType::Assembly, &assembly_id));
 
-        let read_assembly = storage.read_assembly(&assembly_id).unwrap();
+        let read_assembly = storage.read::<Assembly>(&assembly_id).unwrap();
         assert_eq!(read_assembly.name, "Main Assembly");
         assert_eq!(read_assembly.revision, "A");
     }
@@ -789,10 +1230,10 @@ mod tests {
             notes: None,
         };
 
-        storage.write_component(&component).unwrap();
+        storage.write(&component).unwrap();
         assert!(storage.exists(&EntityType::Component, &component_id));
 
-        let read_component = storage.read_component(&component_id).unwrap();
+        let read_component = storage.read::<Component>(&component_id).unwrap();
         assert_eq!(read_component.name, "Bracket");
         assert_eq!(read_component.part_number, Some("BKT-001".to_string()));
     }
@@ -821,10 +1262,10 @@ mod tests {
             drawing_location: Some("Detail A".to_string()),
         };
 
-        storage.write_feature(&feature).unwrap();
+        storage.write(&feature).unwrap();
         assert!(storage.exists(&EntityType::Feature, &feature_id));
 
-        let read_feature = storage.read_feature(&feature_id).unwrap();
+        let read_feature = storage.read::<Feature>(&feature_id).unwrap();
         assert_eq!(read_feature.name, "Hole Diameter");
         assert_eq!(read_feature.nominal, 10.0);
     }
@@ -849,10 +1290,10 @@ mod tests {
             analysis_result: Some(MateAnalysisResult::Pass),
         };
 
-        storage.write_mate(&mate).unwrap();
+        storage.write(&mate).unwrap();
         assert!(storage.exists(&EntityType::Mate, &mate_id));
 
-        let read_mate = storage.read_mate(&mate_id).unwrap();
+        let read_mate = storage.read::<Mate>(&mate_id).unwrap();
         assert_eq!(read_mate.name, "Shaft-Bearing Fit");
         assert_eq!(read_mate.mate_type, MateType::InterferenceFit);
     }
@@ -886,10 +1327,10 @@ mod tests {
             monte_carlo_result: None,
         };
 
-        storage.write_stackup(&stackup).unwrap();
+        storage.write(&stackup).unwrap();
         assert!(storage.exists(&EntityType::Stackup, &stackup_id));
 
-        let read_stackup = storage.read_stackup(&stackup_id).unwrap();
+        let read_stackup = storage.read::<Stackup>(&stackup_id).unwrap();
         assert_eq!(read_stackup.name, "Gap Analysis");
         assert_eq!(read_stackup.analysis_types.len(), 2);
     }
@@ -914,10 +1355,10 @@ mod tests {
             notes: None,
         };
 
-        storage.write_supplier(&supplier).unwrap();
+        storage.write(&supplier).unwrap();
         assert!(storage.exists(&EntityType::Supplier, &supplier_id));
 
-        let read_supplier = storage.read_supplier(&supplier_id).unwrap();
+        let read_supplier = storage.read::<Supplier>(&supplier_id).unwrap();
         assert_eq!(read_supplier.name, "Acme Manufacturing");
         assert_eq!(read_supplier.email, Some("john@acme.com".to_string()));
     }
@@ -946,10 +1387,10 @@ mod tests {
             notes: Some("Volume discounts available".to_string()),
         };
 
-        storage.write_quote(&quote).unwrap();
+        storage.write(&quote).unwrap();
         assert!(storage.exists(&EntityType::Quote, &quote_id));
 
-        let read_quote = storage.read_quote(&quote_id).unwrap();
+        let read_quote = storage.read::<Quote>(&quote_id).unwrap();
         assert_eq!(read_quote.quote_number, "Q-2025-001");
         assert_eq!(read_quote.quantity_price_pairs.len(), 3);
     }
@@ -990,10 +1431,10 @@ mod tests {
             defects_found: vec![],
         };
 
-        storage.write_verification(&verification).unwrap();
+        storage.write(&verification).unwrap();
         assert!(storage.exists(&EntityType::Verification, &verification_id));
 
-        let read_verification = storage.read_verification(&verification_id).unwrap();
+        let read_verification = storage.read::<Verification>(&verification_id).unwrap();
         assert_eq!(read_verification.name, "VER-001: Voltage Test");
         assert_eq!(read_verification.status, TestStatus::NotStarted);
         assert_eq!(read_verification.test_steps.len(), 1);
@@ -1030,10 +1471,10 @@ mod tests {
             approved_at: None,
         };
 
-        storage.write_validation(&validation).unwrap();
+        storage.write(&validation).unwrap();
         assert!(storage.exists(&EntityType::Validation, &validation_id));
 
-        let read_validation = storage.read_validation(&validation_id).unwrap();
+        let read_validation = storage.read::<Validation>(&validation_id).unwrap();
         assert_eq!(read_validation.name, "VAL-001: User Acceptance Test");
         assert_eq!(read_validation.status, TestStatus::InProgress);
         assert_eq!(read_validation.participants.len(), 2);
@@ -1086,10 +1527,10 @@ mod tests {
             nonconformances: vec![],
         };
 
-        storage.write_manufacturing(&manufacturing).unwrap();
+        storage.write(&manufacturing).unwrap();
         assert!(storage.exists(&EntityType::Manufacturing, &manufacturing_id));
 
-        let read_manufacturing = storage.read_manufacturing(&manufacturing_id).unwrap();
+        let read_manufacturing = storage.read::<Manufacturing>(&manufacturing_id).unwrap();
         assert_eq!(read_manufacturing.name, "MFG-001: Bracket Assembly");
         assert_eq!(read_manufacturing.status, ProcessStatus::Planned);
         assert_eq!(read_manufacturing.work_instructions.len(), 1);
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::core::{EdtResult, EdtError};
+use crate::storage::journal::atomic_write;
+
+/// Location of the per-user read state, relative to the project root
+pub const SEEN_COMMENTS_PATH: &str = ".edt/seen_comments.ron";
+
+/// User name to the comments they have read, with when they last read each
+type SeenByUser = HashMap<String, HashMap<Uuid, DateTime<Utc>>>;
+
+/// Which comments each user has read
+///
+/// This is local state: it lives next to the project's other `.edt` files, is not
+/// committed, and unlike the query cache is never rebuilt from the entity files, so
+/// clearing the cache does not mark every comment unread again.
+pub struct SeenComments {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl SeenComments {
+    pub fn new(project_root: &Path) -> Self {
+        Self {
+            path: project_root.join(SEEN_COMMENTS_PATH),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Record that a user has read these comments, as of now
+    pub fn mark_seen(&self, user: &str, comment_ids: &[Uuid]) -> EdtResult<()> {
+        let _guard = self.lock()?;
+        let mut all = self.load()?;
+        let now = Utc::now();
+        let seen = all.entry(user.to_string()).or_default();
+        for id in comment_ids {
+            seen.insert(*id, now);
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let serialized = ron::ser::to_string_pretty(&all, ron::ser::PrettyConfig::default())?;
+        atomic_write(&self.path, serialized.as_bytes())
+    }
+
+    /// Comments the user has read, with when they last read each
+    pub fn seen_by(&self, user: &str) -> EdtResult<HashMap<Uuid, DateTime<Utc>>> {
+        let _guard = self.lock()?;
+        Ok(self.load()?.remove(user).unwrap_or_default())
+    }
+
+    fn load(&self) -> EdtResult<SeenByUser> {
+        if !self.path.exists() {
+            return Ok(HashMap::new());
+        }
+        let content = fs::read_to_string(&self.path)?;
+        Ok(ron::from_str(&content)?)
+    }
+
+    fn lock(&self) -> EdtResult<MutexGuard<'_, ()>> {
+        self.lock
+            .lock()
+            .map_err(|e| EdtError::ValidationError(format!("Failed to lock seen comments: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::SqliteCache;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_seen_comments_per_user() {
+        let temp = TempDir::new().unwrap();
+        let seen = SeenComments::new(temp.path());
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+
+        seen.mark_seen("Dana", &[first, second]).unwrap();
+        seen.mark_seen("Sam", &[second]).unwrap();
+
+        let dana = seen.seen_by("Dana").unwrap();
+        assert_eq!(dana.len(), 2);
+        assert!(dana.contains_key(&first));
+        let sam = seen.seen_by("Sam").unwrap();
+        assert_eq!(sam.keys().collect::<Vec<_>>(), vec![&second]);
+        assert!(seen.seen_by("Alex").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_seen_comments_survive_cache_removal() {
+        let temp = TempDir::new().unwrap();
+        let id = Uuid::new_v4();
+        SeenComments::new(temp.path()).mark_seen("Dana", &[id]).unwrap();
+
+        // The cache is disposable; deleting it must not lose read state
+        drop(SqliteCache::open(temp.path()).unwrap());
+        fs::remove_dir_all(temp.path().join(".edt/.cache")).unwrap();
+
+        let reopened = SeenComments::new(temp.path());
+        assert!(reopened.seen_by("Dana").unwrap().contains_key(&id));
+    }
+}
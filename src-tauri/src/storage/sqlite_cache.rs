@@ -0,0 +1,778 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use chrono::Utc;
+use rusqlite::{params, params_from_iter, Connection, Transaction};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::core::{EdtResult, EdtError};
+use crate::models::{EntityMetadata, EntityStatus, EntityType, Link};
+use crate::storage::RonStorage;
+
+/// Location of the query cache database, relative to the project root
+pub const CACHE_DB_PATH: &str = ".edt/.cache/query.db";
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS entities (
+    id TEXT PRIMARY KEY,
+    entity_type TEXT NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT,
+    status TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    file_path TEXT NOT NULL,
+    file_hash TEXT NOT NULL,
+    schema_version TEXT NOT NULL,
+    entity_data TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_entity_type ON entities(entity_type);
+CREATE INDEX IF NOT EXISTS idx_status ON entities(status);
+CREATE INDEX IF NOT EXISTS idx_updated_at ON entities(updated_at);
+CREATE INDEX IF NOT EXISTS idx_entity_file ON entities(file_path);
+
+CREATE TABLE IF NOT EXISTS links (
+    id TEXT PRIMARY KEY,
+    from_entity_id TEXT NOT NULL,
+    from_entity_type TEXT NOT NULL,
+    to_entity_id TEXT NOT NULL,
+    to_entity_type TEXT NOT NULL,
+    link_type TEXT NOT NULL,
+    metadata TEXT,
+    created_at INTEGER NOT NULL,
+    file_path TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_links_from ON links(from_entity_id);
+CREATE INDEX IF NOT EXISTS idx_links_to ON links(to_entity_id);
+CREATE INDEX IF NOT EXISTS idx_link_type ON links(link_type);
+
+CREATE TABLE IF NOT EXISTS file_hashes (
+    file_path TEXT PRIMARY KEY,
+    hash TEXT NOT NULL,
+    last_checked INTEGER NOT NULL
+);
+
+-- Full-text index; `body` holds the type-specific text fields
+CREATE VIRTUAL TABLE IF NOT EXISTS entities_fts USING fts5(
+    entity_id UNINDEXED,
+    name,
+    description,
+    notes,
+    body
+);
+";
+
+/// Fields indexed in their own FTS column (or not at all), rather than in `body`
+const NON_BODY_FIELDS: &[&str] = &["metadata", "name", "description", "notes"];
+
+/// Column to sort entity queries by
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum SortField {
+    #[default]
+    Name,
+    EntityType,
+    Status,
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl SortField {
+    fn column(&self) -> &'static str {
+        match self {
+            SortField::Name => "name",
+            SortField::EntityType => "entity_type",
+            SortField::Status => "status",
+            SortField::CreatedAt => "created_at",
+            SortField::UpdatedAt => "updated_at",
+        }
+    }
+}
+
+/// Filter, sort and pagination options for listing entities
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EntityQuery {
+    pub entity_type: Option<EntityType>,
+    pub status: Option<EntityStatus>,
+    pub name_contains: Option<String>,
+    /// Only entities linked to or from this entity
+    pub linked_to: Option<Uuid>,
+    pub sort_by: SortField,
+    pub descending: bool,
+    pub limit: Option<u32>,
+    pub offset: u32,
+}
+
+/// One page of query results
+#[derive(Debug, Clone, Serialize)]
+pub struct EntityPage {
+    /// Full entity data as JSON
+    pub entities: Vec<serde_json::Value>,
+    /// Number of matching entities across all pages
+    pub total: usize,
+}
+
+/// Full-text search options
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchQuery {
+    pub text: String,
+    pub entity_type: Option<EntityType>,
+    pub status: Option<EntityStatus>,
+    pub limit: Option<u32>,
+}
+
+/// A ranked full-text search hit
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub entity_id: Uuid,
+    pub entity_type: EntityType,
+    pub name: String,
+    pub status: EntityStatus,
+    /// Matching text with terms wrapped in `<mark>` tags
+    pub snippet: String,
+    /// bm25 relevance; lower is better
+    pub rank: f64,
+}
+
+/// Summary of an incremental cache refresh
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RefreshStats {
+    pub added: usize,
+    pub updated: usize,
+    pub removed: usize,
+    pub unchanged: usize,
+    /// Files that could not be parsed, with the error
+    pub failed: Vec<String>,
+}
+
+/// SQLite index over the RON files, rebuilt incrementally from blake3 file hashes
+///
+/// The RON files remain the source of truth; the cache can be deleted at any time
+/// without losing anything.
+pub struct SqliteCache {
+    conn: Mutex<Connection>,
+    project_root: PathBuf,
+}
+
+impl SqliteCache {
+    /// Open (or create) the cache database for a project
+    pub fn open<P: AsRef<Path>>(project_root: P) -> EdtResult<Self> {
+        let project_root = project_root.as_ref().to_path_buf();
+        let db_path = project_root.join(CACHE_DB_PATH);
+        if let Some(parent) = db_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(db_path)?;
+        let has_fts: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE name = 'entities_fts')",
+            [],
+            |row| row.get(0),
+        )?;
+        conn.execute_batch(SCHEMA)?;
+
+        // Caches created before the search index existed need a full re-index
+        if !has_fts {
+            conn.execute("DELETE FROM file_hashes", [])?;
+        }
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+            project_root,
+        })
+    }
+
+    fn lock(&self) -> EdtResult<MutexGuard<'_, Connection>> {
+        self.conn
+            .lock()
+            .map_err(|e| EdtError::DatabaseError(format!("Failed to lock cache: {}", e)))
+    }
+
+    /// Re-index every entity and link file whose hash changed since the last refresh
+    pub fn refresh(&self) -> EdtResult<RefreshStats> {
+        let mut conn = self.lock()?;
+        let tx = conn.transaction()?;
+
+        let known: HashMap<String, String> = {
+            let mut stmt = tx.prepare("SELECT file_path, hash FROM file_hashes")?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<Result<_, _>>()?
+        };
+
+        // (relative path, entity type); links have no entity type
+        let mut files: Vec<(String, Option<EntityType>)> = Vec::new();
+        for entity_type in EntityType::all() {
+            let dir = format!("entities/{}", entity_type.folder_name());
+            for path in self.ron_files(&dir)? {
+                files.push((path, Some(entity_type.clone())));
+            }
+        }
+        for path in self.ron_files("links")? {
+            files.push((path, None));
+        }
+
+        let now = Utc::now().timestamp_millis();
+        let mut stats = RefreshStats::default();
+        let mut seen = HashSet::new();
+
+        for (path, entity_type) in files {
+            let content = fs::read_to_string(self.project_root.join(&path))?;
+            let hash = blake3::hash(content.as_bytes()).to_hex().to_string();
+            let previous = known.get(&path);
+            seen.insert(path.clone());
+
+            if previous == Some(&hash) {
+                stats.unchanged += 1;
+                continue;
+            }
+
+            let indexed = match &entity_type {
+                Some(entity_type) => Self::index_entity(&tx, entity_type, &path, &content, &hash),
+                None => Self::index_link(&tx, &path, &content),
+            };
+            if let Err(e) = indexed {
+                // Keep the last good row; the file is retried on the next refresh
+                stats.failed.push(format!("{}: {}", path, e));
+                continue;
+            }
+
+            tx.execute(
+                "INSERT OR REPLACE INTO file_hashes (file_path, hash, last_checked) VALUES (?1, ?2, ?3)",
+                params![path, hash, now],
+            )?;
+
+            if previous.is_some() {
+                stats.updated += 1;
+            } else {
+                stats.added += 1;
+            }
+        }
+
+        for path in known.keys().filter(|path| !seen.contains(*path)) {
+            tx.execute(
+                "DELETE FROM entities_fts WHERE entity_id IN (SELECT id FROM entities WHERE file_path = ?1)",
+                params![path],
+            )?;
+            tx.execute("DELETE FROM entities WHERE file_path = ?1", params![path])?;
+            tx.execute("DELETE FROM links WHERE file_path = ?1", params![path])?;
+            tx.execute("DELETE FROM file_hashes WHERE file_path = ?1", params![path])?;
+            stats.removed += 1;
+        }
+
+        tx.commit()?;
+        Ok(stats)
+    }
+
+    /// List entities matching a query
+    pub fn query_entities(&self, query: &EntityQuery) -> EdtResult<EntityPage> {
+        let mut conditions: Vec<&str> = Vec::new();
+        let mut values: Vec<String> = Vec::new();
+
+        if let Some(entity_type) = &query.entity_type {
+            conditions.push("entity_type = ?");
+            values.push(format!("{:?}", entity_type));
+        }
+        if let Some(status) = &query.status {
+            conditions.push("status = ?");
+            values.push(format!("{:?}", status));
+        }
+        if let Some(text) = &query.name_contains {
+            conditions.push("name LIKE ? ESCAPE '\\'");
+            let escaped = text
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            values.push(format!("%{}%", escaped));
+        }
+        if let Some(linked_to) = &query.linked_to {
+            conditions.push(
+                "id IN (SELECT to_entity_id FROM links WHERE from_entity_id = ? \
+                 UNION SELECT from_entity_id FROM links WHERE to_entity_id = ?)",
+            );
+            values.push(linked_to.to_string());
+            values.push(linked_to.to_string());
+        }
+
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", conditions.join(" AND "))
+        };
+
+        let conn = self.lock()?;
+
+        let total: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM entities{}", where_clause),
+            params_from_iter(values.iter()),
+            |row| row.get(0),
+        )?;
+
+        let sql = format!(
+            "SELECT entity_data FROM entities{} ORDER BY {} {}, id LIMIT {} OFFSET {}",
+            where_clause,
+            query.sort_by.column(),
+            if query.descending { "DESC" } else { "ASC" },
+            query.limit.map(i64::from).unwrap_or(-1),
+            query.offset,
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params_from_iter(values.iter()), |row| row.get::<_, String>(0))?;
+
+        let mut entities = Vec::new();
+        for data in rows {
+            entities.push(serde_json::from_str(&data?)?);
+        }
+
+        Ok(EntityPage {
+            entities,
+            total: total as usize,
+        })
+    }
+
+    /// Full-text search over entity names, descriptions, notes and type-specific text
+    pub fn search(&self, query: &SearchQuery) -> EdtResult<Vec<SearchHit>> {
+        let match_expr = Self::fts_match_expression(&query.text);
+        if match_expr.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut sql = String::from(
+            "SELECT e.id, e.entity_type, e.name, e.status,
+                    snippet(entities_fts, -1, '<mark>', '</mark>', '...', 12),
+                    bm25(entities_fts) AS rank
+             FROM entities_fts JOIN entities e ON e.id = entities_fts.entity_id
+             WHERE entities_fts MATCH ?",
+        );
+        let mut values = vec![match_expr];
+        if let Some(entity_type) = &query.entity_type {
+            sql.push_str(" AND e.entity_type = ?");
+            values.push(format!("{:?}", entity_type));
+        }
+        if let Some(status) = &query.status {
+            sql.push_str(" AND e.status = ?");
+            values.push(format!("{:?}", status));
+        }
+        sql.push_str(&format!(
+            " ORDER BY rank LIMIT {}",
+            query.limit.map(i64::from).unwrap_or(-1)
+        ));
+
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params_from_iter(values.iter()), |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, f64>(5)?,
+            ))
+        })?;
+
+        let mut hits = Vec::new();
+        for row in rows {
+            let (id, entity_type, name, status, snippet, rank) = row?;
+            hits.push(SearchHit {
+                entity_id: Uuid::parse_str(&id)?,
+                entity_type: serde_json::from_value(serde_json::Value::String(entity_type))?,
+                name,
+                status: serde_json::from_value(serde_json::Value::String(status))?,
+                snippet,
+                rank,
+            });
+        }
+
+        Ok(hits)
+    }
+
+    /// Turn user input into an FTS5 expression: every word quoted, prefix-matched and ANDed
+    ///
+    /// Quoting keeps FTS5 operators and punctuation in the input from being interpreted.
+    fn fts_match_expression(text: &str) -> String {
+        text.split_whitespace()
+            .map(|token| format!("\"{}\"*", token.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Collect the string values of an entity for the FTS `body` column
+    fn collect_text(value: &serde_json::Value, out: &mut Vec<String>) {
+        match value {
+            // IDs add noise to the index without being searchable text
+            serde_json::Value::String(s) if Uuid::parse_str(s).is_err() => {
+                out.push(s.clone());
+            }
+            serde_json::Value::Array(items) => {
+                for item in items {
+                    Self::collect_text(item, out);
+                }
+            }
+            serde_json::Value::Object(map) => {
+                for item in map.values() {
+                    Self::collect_text(item, out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Relative paths of the `.ron` files in a project directory, sorted
+    fn ron_files(&self, dir: &str) -> EdtResult<Vec<String>> {
+        let full_dir = self.project_root.join(dir);
+        if !full_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut files = Vec::new();
+        for entry in fs::read_dir(full_dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|s| s.to_str()) == Some("ron") {
+                if let Some(name) = path.file_name().and_then(|s| s.to_str()) {
+                    files.push(format!("{}/{}", dir, name));
+                }
+            }
+        }
+        files.sort();
+
+        Ok(files)
+    }
+
+    fn index_entity(
+        tx: &Transaction,
+        entity_type: &EntityType,
+        path: &str,
+        content: &str,
+        hash: &str,
+    ) -> EdtResult<()> {
+        let data = RonStorage::entity_ron_to_json(entity_type, content)?;
+        let metadata: EntityMetadata = serde_json::from_value(data["metadata"].clone())?;
+
+        // Quotes are identified by their quote number and comments by their body
+        let name = data
+            .get("name")
+            .or_else(|| data.get("quote_number"))
+            .or_else(|| data.get("body"))
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        let description = data.get("description").and_then(|v| v.as_str());
+
+        tx.execute(
+            "INSERT OR REPLACE INTO entities (
+                id, entity_type, name, description, status, created_at, updated_at,
+                file_path, file_hash, schema_version, entity_data
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                metadata.id.to_string(),
+                format!("{:?}", entity_type),
+                name,
+                description,
+                format!("{:?}", metadata.status),
+                metadata.created_at.timestamp_millis(),
+                metadata.updated_at.timestamp_millis(),
+                path,
+                hash,
+                metadata.schema_version,
+                data.to_string(),
+            ],
+        )?;
+
+        let mut body = Vec::new();
+        if let Some(fields) = data.as_object() {
+            for (field, value) in fields {
+                if !NON_BODY_FIELDS.contains(&field.as_str()) {
+                    Self::collect_text(value, &mut body);
+                }
+            }
+        }
+        let notes = data.get("notes").and_then(|v| v.as_str());
+
+        tx.execute(
+            "DELETE FROM entities_fts WHERE entity_id = ?1",
+            params![metadata.id.to_string()],
+        )?;
+        tx.execute(
+            "INSERT INTO entities_fts (entity_id, name, description, notes, body)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![metadata.id.to_string(), name, description, notes, body.join("\n")],
+        )?;
+
+        Ok(())
+    }
+
+    fn index_link(tx: &Transaction, path: &str, content: &str) -> EdtResult<()> {
+        let link: Link = ron::from_str(content)?;
+        let metadata = link.metadata.as_ref().map(serde_json::to_string).transpose()?;
+
+        tx.execute(
+            "INSERT OR REPLACE INTO links (
+                id, from_entity_id, from_entity_type, to_entity_id, to_entity_type,
+                link_type, metadata, created_at, file_path
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                link.id.to_string(),
+                link.from_entity_id.to_string(),
+                format!("{:?}", link.from_entity_type),
+                link.to_entity_id.to_string(),
+                format!("{:?}", link.to_entity_type),
+                format!("{:?}", link.link_type),
+                metadata,
+                link.created_at.timestamp_millis(),
+                path,
+            ],
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Requirement, LinkType};
+    use tempfile::TempDir;
+
+    fn create_requirement(storage: &RonStorage, name: &str) -> Requirement {
+        let requirement = Requirement {
+            metadata: EntityMetadata::new(EntityType::Requirement),
+            name: name.to_string(),
+            description: format!("{} description", name),
+            notes: None,
+            requirement_type: "User Requirement".to_string(),
+            rationale: None,
+            source: None,
+            verification_method: None,
+        };
+        storage.write(&requirement).unwrap();
+        requirement
+    }
+
+    fn setup() -> (TempDir, RonStorage, SqliteCache) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = RonStorage::new(temp_dir.path()).unwrap();
+        let cache = SqliteCache::open(temp_dir.path()).unwrap();
+        (temp_dir, storage, cache)
+    }
+
+    #[test]
+    fn test_open_creates_database() {
+        let (temp_dir, _storage, _cache) = setup();
+        assert!(temp_dir.path().join(CACHE_DB_PATH).exists());
+    }
+
+    #[test]
+    fn test_incremental_refresh() {
+        let (_temp, storage, cache) = setup();
+
+        let mut first = create_requirement(&storage, "First");
+        let second = create_requirement(&storage, "Second");
+
+        let stats = cache.refresh().unwrap();
+        assert_eq!(stats.added, 2);
+
+        // Nothing changed
+        let stats = cache.refresh().unwrap();
+        assert_eq!(stats.unchanged, 2);
+        assert_eq!(stats.added + stats.updated + stats.removed, 0);
+
+        // One modified, one removed
+        first.name = "First (renamed)".to_string();
+        storage.write(&first).unwrap();
+        storage.delete(&EntityType::Requirement, &second.metadata.id).unwrap();
+
+        let stats = cache.refresh().unwrap();
+        assert_eq!(stats.updated, 1);
+        assert_eq!(stats.removed, 1);
+
+        let page = cache.query_entities(&EntityQuery::default()).unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.entities[0]["name"], "First (renamed)");
+    }
+
+    #[test]
+    fn test_unparseable_file_reported() {
+        let (temp_dir, storage, cache) = setup();
+        create_requirement(&storage, "Good");
+        fs::write(
+            temp_dir.path().join("entities/requirements").join(format!("{}.ron", Uuid::new_v4())),
+            "not ron",
+        )
+        .unwrap();
+
+        let stats = cache.refresh().unwrap();
+        assert_eq!(stats.added, 1);
+        assert_eq!(stats.failed.len(), 1);
+    }
+
+    #[test]
+    fn test_query_filter_sort_and_paginate() {
+        let (_temp, storage, cache) = setup();
+        for name in ["Charlie", "Alpha", "Bravo", "Delta"] {
+            create_requirement(&storage, name);
+        }
+        cache.refresh().unwrap();
+
+        let page = cache
+            .query_entities(&EntityQuery {
+                entity_type: Some(EntityType::Requirement),
+                limit: Some(2),
+                offset: 1,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(page.total, 4);
+        let names: Vec<_> = page.entities.iter().map(|e| e["name"].clone()).collect();
+        assert_eq!(names, vec!["Bravo", "Charlie"]);
+
+        let page = cache
+            .query_entities(&EntityQuery {
+                descending: true,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(page.entities[0]["name"], "Delta");
+
+        let page = cache
+            .query_entities(&EntityQuery {
+                name_contains: Some("rav".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(page.total, 1);
+
+        let page = cache
+            .query_entities(&EntityQuery {
+                entity_type: Some(EntityType::Task),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(page.total, 0);
+    }
+
+    #[test]
+    fn test_query_linked_to() {
+        let (_temp, storage, cache) = setup();
+        let parent = create_requirement(&storage, "Parent");
+        let child = create_requirement(&storage, "Child");
+        create_requirement(&storage, "Unrelated");
+
+        let link = Link::new(
+            parent.metadata.id,
+            EntityType::Requirement,
+            child.metadata.id,
+            EntityType::Requirement,
+            LinkType::Derives,
+            None,
+        );
+        storage.write_link(&link).unwrap();
+        cache.refresh().unwrap();
+
+        let page = cache
+            .query_entities(&EntityQuery {
+                linked_to: Some(parent.metadata.id),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.entities[0]["name"], "Child");
+
+        // Removing the link file drops it from the index
+        storage.delete_link(&link.id).unwrap();
+        let stats = cache.refresh().unwrap();
+        assert_eq!(stats.removed, 1);
+        let page = cache
+            .query_entities(&EntityQuery {
+                linked_to: Some(parent.metadata.id),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(page.total, 0);
+    }
+
+    #[test]
+    fn test_search_ranks_and_highlights() {
+        let (_temp, storage, cache) = setup();
+
+        let mut sealed = create_requirement(&storage, "Enclosure sealing");
+        sealed.description = "The enclosure shall meet IP67 when assembled".to_string();
+        storage.write(&sealed).unwrap();
+
+        let mut mentioned = create_requirement(&storage, "Labeling");
+        mentioned.rationale = Some("Label must survive IP67 testing".to_string());
+        storage.write(&mentioned).unwrap();
+
+        create_requirement(&storage, "Unrelated");
+        cache.refresh().unwrap();
+
+        let hits = cache
+            .search(&SearchQuery {
+                text: "ip67".to_string(),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().any(|h| h.entity_id == sealed.metadata.id));
+        // Type-specific fields (rationale) are indexed too
+        let label_hit = hits.iter().find(|h| h.entity_id == mentioned.metadata.id).unwrap();
+        assert!(label_hit.snippet.contains("<mark>IP67</mark>"));
+        assert_eq!(label_hit.entity_type, EntityType::Requirement);
+    }
+
+    #[test]
+    fn test_search_filters_and_prefix() {
+        let (_temp, storage, cache) = setup();
+
+        let mut approved = create_requirement(&storage, "Waterproof housing");
+        approved.metadata.status = EntityStatus::Approved;
+        storage.write(&approved).unwrap();
+        create_requirement(&storage, "Waterproof connector");
+        cache.refresh().unwrap();
+
+        let search = |text: &str, entity_type, status| {
+            cache
+                .search(&SearchQuery {
+                    text: text.to_string(),
+                    entity_type,
+                    status,
+                    limit: None,
+                })
+                .unwrap()
+        };
+
+        assert_eq!(search("water", None, None).len(), 2);
+        assert_eq!(search("water", None, Some(EntityStatus::Approved)).len(), 1);
+        assert_eq!(search("water", Some(EntityType::Task), None).len(), 0);
+        assert_eq!(search("waterproof housing", None, None).len(), 1);
+        // FTS syntax in user input is treated literally
+        assert!(search("\"housing OR", None, None).is_empty());
+        assert!(search("   ", None, None).is_empty());
+    }
+
+    #[test]
+    fn test_search_index_follows_changes() {
+        let (_temp, storage, cache) = setup();
+
+        let mut requirement = create_requirement(&storage, "Gasket");
+        cache.refresh().unwrap();
+
+        requirement.name = "O-ring".to_string();
+        requirement.description = "Static seal".to_string();
+        storage.write(&requirement).unwrap();
+        cache.refresh().unwrap();
+
+        let query = |text: &str| SearchQuery {
+            text: text.to_string(),
+            ..Default::default()
+        };
+        assert!(cache.search(&query("gasket")).unwrap().is_empty());
+        assert_eq!(cache.search(&query("ring")).unwrap().len(), 1);
+
+        storage.delete(&EntityType::Requirement, &requirement.metadata.id).unwrap();
+        cache.refresh().unwrap();
+        assert!(cache.search(&query("ring")).unwrap().is_empty());
+    }
+}
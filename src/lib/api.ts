@@ -4,6 +4,7 @@
  */
 
 import { invoke } from '@tauri-apps/api/core';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 import type {
   Task, Milestone, Resource, Calendar, Baseline,
   Requirement, Hazard, Risk, RiskControl,
@@ -11,7 +12,18 @@ import type {
   Verification, Validation, Manufacturing,
   CriticalPathResult, EvmMetrics, BomResult,
   StackupResult, MonteCarloResult,
-  AnalysisType
+  AnalysisType,
+  Link, CreateLinkRequest,
+  EntityType, DeleteResponse,
+  ProjectConfig, TypeValidationIssue, HealthReport,
+  EntityQuery, EntityPage, RefreshStats, SearchQuery, SearchHit,
+  MigrationReport, CommitInfo, EntityRevisionDiff, FieldChange,
+  EntityConflict, ConflictResolution, EntityChange, EntityRef,
+  HistoryEntry, UndoHistory, ProjectInfo,
+  EntityStatus, StatusChange, AvailableTransition,
+  Signature, SignatureMeaning, SignatureRecord,
+  AuditRecord, AuditQuery, AuditVerification,
+  Comment, CommentThread
 } from '@/types';
 
 // Task API
@@ -19,7 +31,7 @@ export const taskApi = {
   create: (data: Partial<Task>) => invoke<Task>('create_task', { data }),
   get: (id: string) => invoke<Task>('get_task', { id }),
   update: (id: string, data: Partial<Task>) => invoke<Task>('update_task', { id, data }),
-  delete: (id: string) => invoke<void>('delete_task', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_task', { id }),
   list: () => invoke<Task[]>('list_tasks'),
 };
 
@@ -28,7 +40,7 @@ export const milestoneApi = {
   create: (data: Partial<Milestone>) => invoke<Milestone>('create_milestone', { data }),
   get: (id: string) => invoke<Milestone>('get_milestone', { id }),
   update: (id: string, data: Partial<Milestone>) => invoke<Milestone>('update_milestone', { id, data }),
-  delete: (id: string) => invoke<void>('delete_milestone', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_milestone', { id }),
   list: () => invoke<Milestone[]>('list_milestones'),
 };
 
@@ -37,7 +49,7 @@ export const resourceApi = {
   create: (data: Partial<Resource>) => invoke<Resource>('create_resource', { data }),
   get: (id: string) => invoke<Resource>('get_resource', { id }),
   update: (id: string, data: Partial<Resource>) => invoke<Resource>('update_resource', { id, data }),
-  delete: (id: string) => invoke<void>('delete_resource', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_resource', { id }),
   list: () => invoke<Resource[]>('list_resources'),
 };
 
@@ -46,7 +58,7 @@ export const calendarApi = {
   create: (data: Partial<Calendar>) => invoke<Calendar>('create_calendar', { data }),
   get: (id: string) => invoke<Calendar>('get_calendar', { id }),
   update: (id: string, data: Partial<Calendar>) => invoke<Calendar>('update_calendar', { id, data }),
-  delete: (id: string) => invoke<void>('delete_calendar', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_calendar', { id }),
   list: () => invoke<Calendar[]>('list_calendars'),
 };
 
@@ -55,8 +67,9 @@ export const baselineApi = {
   create: (data: Partial<Baseline>) => invoke<Baseline>('create_baseline', { data }),
   get: (id: string) => invoke<Baseline>('get_baseline', { id }),
   update: (id: string, data: Partial<Baseline>) => invoke<Baseline>('update_baseline', { id, data }),
-  delete: (id: string) => invoke<void>('delete_baseline', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_baseline', { id }),
   list: () => invoke<Baseline[]>('list_baselines'),
+  diffTask: (taskId: string) => invoke<{ changes: FieldChange[] }>('diff_task_baseline', { taskId }),
 };
 
 // Requirement API
@@ -64,7 +77,7 @@ export const requirementApi = {
   create: (data: Partial<Requirement>) => invoke<Requirement>('create_requirement', { data }),
   get: (id: string) => invoke<Requirement>('get_requirement', { id }),
   update: (id: string, data: Partial<Requirement>) => invoke<Requirement>('update_requirement', { id, data }),
-  delete: (id: string) => invoke<void>('delete_requirement', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_requirement', { id }),
   list: () => invoke<Requirement[]>('list_requirements'),
 };
 
@@ -73,7 +86,7 @@ export const hazardApi = {
   create: (data: Partial<Hazard>) => invoke<Hazard>('create_hazard', { data }),
   get: (id: string) => invoke<Hazard>('get_hazard', { id }),
   update: (id: string, data: Partial<Hazard>) => invoke<Hazard>('update_hazard', { id, data }),
-  delete: (id: string) => invoke<void>('delete_hazard', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_hazard', { id }),
   list: () => invoke<Hazard[]>('list_hazards'),
 };
 
@@ -82,7 +95,7 @@ export const riskApi = {
   create: (data: Partial<Risk>) => invoke<Risk>('create_risk', { data }),
   get: (id: string) => invoke<Risk>('get_risk', { id }),
   update: (id: string, data: Partial<Risk>) => invoke<Risk>('update_risk', { id, data }),
-  delete: (id: string) => invoke<void>('delete_risk', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_risk', { id }),
   list: () => invoke<Risk[]>('list_risks'),
 };
 
@@ -91,7 +104,7 @@ export const riskControlApi = {
   create: (data: Partial<RiskControl>) => invoke<RiskControl>('create_risk_control', { data }),
   get: (id: string) => invoke<RiskControl>('get_risk_control', { id }),
   update: (id: string, data: Partial<RiskControl>) => invoke<RiskControl>('update_risk_control', { id, data }),
-  delete: (id: string) => invoke<void>('delete_risk_control', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_risk_control', { id }),
   list: () => invoke<RiskControl[]>('list_risk_controls'),
 };
 
@@ -100,7 +113,7 @@ export const assemblyApi = {
   create: (data: Partial<Assembly>) => invoke<Assembly>('create_assembly', { data }),
   get: (id: string) => invoke<Assembly>('get_assembly', { id }),
   update: (id: string, data: Partial<Assembly>) => invoke<Assembly>('update_assembly', { id, data }),
-  delete: (id: string) => invoke<void>('delete_assembly', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_assembly', { id }),
   list: () => invoke<Assembly[]>('list_assemblies'),
 };
 
@@ -109,7 +122,7 @@ export const componentApi = {
   create: (data: Partial<Component>) => invoke<Component>('create_component', { data }),
   get: (id: string) => invoke<Component>('get_component', { id }),
   update: (id: string, data: Partial<Component>) => invoke<Component>('update_component', { id, data }),
-  delete: (id: string) => invoke<void>('delete_component', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_component', { id }),
   list: () => invoke<Component[]>('list_components'),
 };
 
@@ -118,7 +131,7 @@ export const featureApi = {
   create: (data: Partial<Feature>) => invoke<Feature>('create_feature', { data }),
   get: (id: string) => invoke<Feature>('get_feature', { id }),
   update: (id: string, data: Partial<Feature>) => invoke<Feature>('update_feature', { id, data }),
-  delete: (id: string) => invoke<void>('delete_feature', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_feature', { id }),
   list: () => invoke<Feature[]>('list_features'),
 };
 
@@ -127,7 +140,7 @@ export const mateApi = {
   create: (data: Partial<Mate>) => invoke<Mate>('create_mate', { data }),
   get: (id: string) => invoke<Mate>('get_mate', { id }),
   update: (id: string, data: Partial<Mate>) => invoke<Mate>('update_mate', { id, data }),
-  delete: (id: string) => invoke<void>('delete_mate', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_mate', { id }),
   list: () => invoke<Mate[]>('list_mates'),
 };
 
@@ -136,7 +149,7 @@ export const stackupApi = {
   create: (data: Partial<Stackup>) => invoke<Stackup>('create_stackup', { data }),
   get: (id: string) => invoke<Stackup>('get_stackup', { id }),
   update: (id: string, data: Partial<Stackup>) => invoke<Stackup>('update_stackup', { id, data }),
-  delete: (id: string) => invoke<void>('delete_stackup', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_stackup', { id }),
   list: () => invoke<Stackup[]>('list_stackups'),
 };
 
@@ -145,7 +158,7 @@ export const supplierApi = {
   create: (data: Partial<Supplier>) => invoke<Supplier>('create_supplier', { data }),
   get: (id: string) => invoke<Supplier>('get_supplier', { id }),
   update: (id: string, data: Partial<Supplier>) => invoke<Supplier>('update_supplier', { id, data }),
-  delete: (id: string) => invoke<void>('delete_supplier', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_supplier', { id }),
   list: () => invoke<Supplier[]>('list_suppliers'),
 };
 
@@ -154,7 +167,7 @@ export const quoteApi = {
   create: (data: Partial<Quote>) => invoke<Quote>('create_quote', { data }),
   get: (id: string) => invoke<Quote>('get_quote', { id }),
   update: (id: string, data: Partial<Quote>) => invoke<Quote>('update_quote', { id, data }),
-  delete: (id: string) => invoke<void>('delete_quote', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_quote', { id }),
   list: () => invoke<Quote[]>('list_quotes'),
 };
 
@@ -163,7 +176,7 @@ export const verificationApi = {
   create: (data: Partial<Verification>) => invoke<Verification>('create_verification', { data }),
   get: (id: string) => invoke<Verification>('get_verification', { id }),
   update: (id: string, data: Partial<Verification>) => invoke<Verification>('update_verification', { id, data }),
-  delete: (id: string) => invoke<void>('delete_verification', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_verification', { id }),
   list: () => invoke<Verification[]>('list_verifications'),
 };
 
@@ -172,7 +185,7 @@ export const validationApi = {
   create: (data: Partial<Validation>) => invoke<Validation>('create_validation', { data }),
   get: (id: string) => invoke<Validation>('get_validation', { id }),
   update: (id: string, data: Partial<Validation>) => invoke<Validation>('update_validation', { id, data }),
-  delete: (id: string) => invoke<void>('delete_validation', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_validation', { id }),
   list: () => invoke<Validation[]>('list_validations'),
 };
 
@@ -181,10 +194,151 @@ export const manufacturingApi = {
   create: (data: Partial<Manufacturing>) => invoke<Manufacturing>('create_manufacturing', { data }),
   get: (id: string) => invoke<Manufacturing>('get_manufacturing', { id }),
   update: (id: string, data: Partial<Manufacturing>) => invoke<Manufacturing>('update_manufacturing', { id, data }),
-  delete: (id: string) => invoke<void>('delete_manufacturing', { id }),
+  delete: (id: string) => invoke<DeleteResponse>('delete_manufacturing', { id }),
   list: () => invoke<Manufacturing[]>('list_manufacturing'),
 };
 
+// Link API
+export const linkApi = {
+  create: (request: CreateLinkRequest) => invoke<{ link: Link }>('create_link', { request }),
+  delete: (linkId: string) => invoke<void>('delete_link', { linkId }),
+  from: (entityId: string) => invoke<{ links: Link[] }>('get_links_from', { entityId }),
+  to: (entityId: string) => invoke<{ links: Link[] }>('get_links_to', { entityId }),
+  all: (entityId: string) => invoke<{ links: Link[] }>('get_all_links', { entityId }),
+  impacted: (entityId: string) =>
+    invoke<{ entity_ids: string[] }>('get_impacted_entities', { entityId }),
+};
+
+// Delete API
+export const deleteApi = {
+  preview: (entityType: EntityType, entityId: string) =>
+    invoke<DeleteResponse>('preview_delete', { entityType, entityId }),
+};
+
+// Config API
+export const configApi = {
+  get: () => invoke<{ config: ProjectConfig }>('get_config'),
+  update: (config: ProjectConfig) => invoke<{ config: ProjectConfig }>('update_config', { config }),
+  revalidate: () => invoke<{ issues: TypeValidationIssue[] }>('revalidate_entities'),
+};
+
+// Health Check API
+export const healthApi = {
+  check: () => invoke<{ report: HealthReport }>('run_health_check'),
+};
+
+// Query API
+export const queryApi = {
+  entities: (query: EntityQuery) => invoke<{ page: EntityPage }>('query_entities', { query }),
+  search: (query: SearchQuery) => invoke<{ hits: SearchHit[] }>('search_entities', { query }),
+  refresh: () => invoke<{ stats: RefreshStats }>('refresh_cache'),
+  /** Accepts a UUID or a human-readable ID such as REQ-0001 */
+  resolveId: (id: string) => invoke<{ entity: EntityRef }>('resolve_entity_id', { id }),
+};
+
+// Migration API
+export const migrationApi = {
+  migrateProject: () => invoke<{ report: MigrationReport }>('migrate_project'),
+};
+
+// Version Control API
+export const versionControlApi = {
+  init: () => invoke<{ created: boolean }>('init_repository'),
+  commit: (message: string) => invoke<{ commit: CommitInfo }>('commit_changes', { message }),
+  history: (entityType: EntityType, entityId: string) =>
+    invoke<{ commits: CommitInfo[] }>('get_entity_history', { entityType, entityId }),
+  diff: (entityType: EntityType, entityId: string, fromRevision: string, toRevision?: string) =>
+    invoke<{ diff: EntityRevisionDiff }>('diff_entity_revisions', {
+      entityType,
+      entityId,
+      fromRevision,
+      toRevision,
+    }),
+  restore: (entityType: EntityType, entityId: string, revision: string) =>
+    invoke<{ entity: unknown }>('restore_entity_revision', { entityType, entityId, revision }),
+  mergeConflicts: () => invoke<{ conflicts: EntityConflict[] }>('get_merge_conflicts'),
+  resolveConflict: (entityType: EntityType, entityId: string, resolutions: ConflictResolution[]) =>
+    invoke<{ entity: unknown }>('resolve_merge_conflict', { entityType, entityId, resolutions }),
+};
+
+// Undo/Redo API
+export const historyApi = {
+  undo: () => invoke<{ step: HistoryEntry | null }>('undo'),
+  redo: () => invoke<{ step: HistoryEntry | null }>('redo'),
+  list: () => invoke<{ history: UndoHistory }>('get_undo_history'),
+  /** Group the commands issued until `endGroup` is called with the returned token into one undo step */
+  beginGroup: (label: string) => invoke<{ token: string }>('begin_undo_group', { label }),
+  endGroup: (token: string) => invoke<void>('end_undo_group', { token }),
+  /** Close a group left open, e.g. after an error between `beginGroup` and `endGroup` */
+  resetGroup: () => invoke<void>('reset_undo_group'),
+};
+
+// Project API
+export const workspaceApi = {
+  /** Create a project in a folder and open it; fails if the folder already holds one */
+  create: (path: string) => invoke<{ project: ProjectInfo }>('create_project', { path }),
+  open: (path: string) => invoke<{ project: ProjectInfo }>('open_project', { path }),
+  close: () => invoke<boolean>('close_project'),
+  current: () => invoke<{ path: string | null }>('get_current_project'),
+  recent: () => invoke<{ projects: ProjectInfo[] }>('get_recent_projects'),
+};
+
+// Workflow API
+export const workflowApi = {
+  /** Move an entity to a new status; `reason` is required when rejecting or revising */
+  transition: (entityId: string, status: EntityStatus, changedBy: string, reason?: string) =>
+    invoke<{ change: StatusChange }>('transition_entity_status', { entityId, status, changedBy, reason }),
+  available: (entityId: string) =>
+    invoke<{ status: EntityStatus; transitions: AvailableTransition[] }>('get_available_transitions', { entityId }),
+};
+
+// Signature API
+export const signatureApi = {
+  sign: (entityId: string, signer: string, role: string, meaning: SignatureMeaning) =>
+    invoke<{ signature: Signature }>('sign_entity', { entityId, signer, role, meaning }),
+  list: (entityId: string) =>
+    invoke<{ content_hash: string; signatures: SignatureRecord[] }>('get_entity_signatures', { entityId }),
+};
+
+// Audit API
+export const auditApi = {
+  query: (query: AuditQuery) =>
+    invoke<{ records: AuditRecord[] }>('query_audit_log', { query }),
+  entityHistory: (entityId: string) =>
+    invoke<{ records: AuditRecord[] }>('get_entity_audit_history', { entityId }),
+  verify: () => invoke<{ verification: AuditVerification }>('verify_audit_log'),
+  getUser: () => invoke<string>('get_audit_user'),
+  setUser: (user: string) => invoke<void>('set_audit_user', { user }),
+};
+
+// Comment API
+export const commentApi = {
+  create: (entityId: string, author: string, body: string) =>
+    invoke<{ comment: Comment }>('create_comment', { entityId, author, body }),
+  reply: (commentId: string, author: string, body: string) =>
+    invoke<{ comment: Comment }>('reply_to_comment', { commentId, author, body }),
+  get: (commentId: string) => invoke<{ comment: Comment }>('get_comment', { commentId }),
+  edit: (commentId: string, body: string) =>
+    invoke<{ comment: Comment }>('edit_comment', { commentId, body }),
+  resolve: (commentId: string, resolved: boolean) =>
+    invoke<{ comment: Comment }>('resolve_comment', { commentId, resolved }),
+  delete: (commentId: string) => invoke<DeleteResponse>('delete_comment', { commentId }),
+  threads: (entityId: string) =>
+    invoke<{ threads: CommentThread[] }>('get_comment_threads', { entityId }),
+  unresolvedCount: (entityId: string) =>
+    invoke<{ count: number }>('get_unresolved_comment_count', { entityId }),
+  markSeen: (user: string, commentIds: string[]) =>
+    invoke<void>('mark_comments_seen', { user, commentIds }),
+  unseen: (user: string) => invoke<{ comments: Comment[] }>('get_unseen_comments', { user }),
+};
+
+// File change events
+export const fileEvents = {
+  /** Called when an entity file is created, modified or deleted on disk */
+  onEntityChanged: (handler: (change: EntityChange) => void): Promise<UnlistenFn> =>
+    listen<EntityChange>('entity-changed', (event) => handler(event.payload)),
+};
+
 // Calculation API
 export const calculationApi = {
   criticalPath: () => invoke<CriticalPathResult>('calculate_critical_path'),
@@ -218,5 +372,19 @@ export const api = {
   verification: verificationApi,
   validation: validationApi,
   manufacturing: manufacturingApi,
+  link: linkApi,
+  delete: deleteApi,
+  config: configApi,
+  health: healthApi,
+  query: queryApi,
+  migration: migrationApi,
+  versionControl: versionControlApi,
+  history: historyApi,
+  workspace: workspaceApi,
+  workflow: workflowApi,
+  signature: signatureApi,
+  audit: auditApi,
+  comment: commentApi,
+  fileEvents,
   calculation: calculationApi,
 };
@@ -27,11 +27,21 @@ export type EntityStatus = 'Draft' | 'PendingApproval' | 'Approved' | 'Released'
 
 export interface EntityMetadata {
   id: string;
+  human_id?: string;
   entity_type: EntityType;
   schema_version: string;
   created_at: string;
   updated_at: string;
   status: EntityStatus;
+  status_history: StatusChange[];
+}
+
+export interface StatusChange {
+  from: EntityStatus;
+  to: EntityStatus;
+  changed_by: string;
+  changed_at: string;
+  reason?: string;
 }
 
 // Project Management Types
@@ -150,9 +160,11 @@ export interface Risk {
   probability: number;
   severity: number;
   risk_score: number;
+  is_acceptable: boolean;
   residual_probability?: number;
   residual_severity?: number;
   residual_risk_score?: number;
+  residual_is_acceptable?: boolean;
 }
 
 export interface RiskControl {
@@ -354,6 +366,318 @@ export interface Link {
   created_at: string;
 }
 
+export interface CreateLinkRequest {
+  from_entity_id: string;
+  from_entity_type: EntityType;
+  to_entity_id: string;
+  to_entity_type: EntityType;
+  link_type: LinkType;
+  metadata?: LinkMetadata;
+}
+
+// Delete Types
+export type DeletePolicy = 'Block' | 'CascadeLinks' | 'CascadeChildren' | 'Orphan';
+
+export interface EntityRef {
+  entity_id: string;
+  entity_type: EntityType;
+}
+
+export interface DeleteImpact {
+  entity_id: string;
+  entity_type: EntityType;
+  policy: DeletePolicy;
+  children: EntityRef[];
+  links: Link[];
+  blocked: boolean;
+}
+
+export interface DeleteResponse {
+  impact: DeleteImpact;
+}
+
+// Project Configuration
+export interface LinkRuleConfig {
+  from_entity_type: EntityType;
+  link_type: LinkType;
+  to_entity_type: EntityType;
+}
+
+export interface ProjectConfig {
+  schema_version: string;
+  critical_path_milestone_id?: string;
+  requirement_types: string[];
+  risk_types: string[];
+  risk_control_types: string[];
+  severity_levels: number[];
+  probability_levels: number[];
+  risk_matrix: Record<string, number>;
+  acceptable_risk_threshold: number;
+  warn_hazard_without_risk: boolean;
+  warn_risk_without_control: boolean;
+  warn_risk_without_verification: boolean;
+  warn_requirement_without_verification: boolean;
+  warn_requirement_without_design: boolean;
+  general_entity_types: string[];
+  signature_roles: string[];
+  link_rules: LinkRuleConfig[];
+  delete_policies: Partial<Record<EntityType, DeletePolicy>>;
+  id_prefixes: Partial<Record<EntityType, string>>;
+  workflow: WorkflowConfig;
+}
+
+// Workflow
+export interface StatusTransition {
+  from: EntityStatus;
+  to: EntityStatus;
+  requires_reason: boolean;
+}
+
+export type TransitionCondition = { LinkedEntity: EntityType } | { Signature: SignatureMeaning };
+
+export interface TransitionGuard {
+  entity_type: EntityType;
+  to: EntityStatus;
+  condition: TransitionCondition;
+}
+
+export interface WorkflowConfig {
+  transitions: StatusTransition[];
+  guards: TransitionGuard[];
+  locked_statuses: EntityStatus[];
+}
+
+export interface AvailableTransition {
+  to: EntityStatus;
+  requires_reason: boolean;
+  blocked_by: string[];
+}
+
+// Electronic Signatures
+export type SignatureMeaning = 'Authored' | 'Reviewed' | 'Approved';
+
+export interface Signature {
+  id: string;
+  entity_id: string;
+  entity_type: EntityType;
+  signer: string;
+  role: string;
+  meaning: SignatureMeaning;
+  content_hash: string;
+  signed_at: string;
+}
+
+export interface SignatureRecord {
+  signature: Signature;
+  /** False once the entity's content has changed since it was signed */
+  valid: boolean;
+}
+
+// Audit Trail
+export type AuditAction = 'Create' | 'Update' | 'Delete';
+
+export interface AuditRecord {
+  sequence: number;
+  timestamp: string;
+  user: string;
+  action: AuditAction;
+  /** The changed entity, or the source entity of a changed link */
+  entity_id: string;
+  entity_type: EntityType;
+  link_id?: string;
+  changes: FieldChange[];
+  previous_hash: string;
+  hash: string;
+}
+
+export interface AuditQuery {
+  entity_id?: string;
+  user?: string;
+  from?: string;
+  to?: string;
+}
+
+export interface AuditVerification {
+  record_count: number;
+  /** Sequence number of the first record that fails the check */
+  broken_at?: number;
+}
+
+// Comments
+export interface Comment {
+  metadata: EntityMetadata;
+  author: string;
+  body: string;
+  edited_at?: string;
+  resolved: boolean;
+}
+
+export interface CommentThread {
+  comment: Comment;
+  replies: CommentThread[];
+}
+
+export interface TypeValidationIssue {
+  entity_id: string;
+  entity_type: EntityType;
+  name: string;
+  field: string;
+  message: string;
+}
+
+// Health Check
+export type FindingKind =
+  | 'HazardWithoutRisk'
+  | 'RiskWithoutControl'
+  | 'RiskWithoutVerification'
+  | 'RequirementWithoutVerification'
+  | 'RequirementWithoutDesign';
+
+export interface HealthFinding {
+  kind: FindingKind;
+  entity_id: string;
+  entity_type: EntityType;
+  entity_name: string;
+  message: string;
+}
+
+export interface HealthReport {
+  findings: HealthFinding[];
+  entities_checked: number;
+}
+
+// Query Cache
+export type SortField = 'Name' | 'EntityType' | 'Status' | 'CreatedAt' | 'UpdatedAt';
+
+export interface EntityQuery {
+  entity_type?: EntityType;
+  status?: EntityStatus;
+  name_contains?: string;
+  linked_to?: string;
+  sort_by?: SortField;
+  descending?: boolean;
+  limit?: number;
+  offset?: number;
+}
+
+export interface EntityPage {
+  entities: unknown[];
+  total: number;
+}
+
+export interface SearchQuery {
+  text: string;
+  entity_type?: EntityType;
+  status?: EntityStatus;
+  limit?: number;
+}
+
+export interface SearchHit {
+  entity_id: string;
+  entity_type: EntityType;
+  name: string;
+  status: EntityStatus;
+  snippet: string;
+  rank: number;
+}
+
+export interface RefreshStats {
+  added: number;
+  updated: number;
+  removed: number;
+  unchanged: number;
+  failed: string[];
+}
+
+// Schema Migration
+export interface MigratedEntity {
+  entity_id: string;
+  entity_type: EntityType;
+  from_version: string;
+}
+
+export interface MigrationFailure {
+  entity_id: string;
+  entity_type: EntityType;
+  message: string;
+}
+
+export interface MigrationReport {
+  target_version: string;
+  migrated: MigratedEntity[];
+  up_to_date: number;
+  failed: MigrationFailure[];
+}
+
+// Version Control
+export interface CommitInfo {
+  id: string;
+  message: string;
+  author: string;
+  timestamp: string;
+}
+
+export interface FieldChange {
+  path: string;
+  old_value?: unknown;
+  new_value?: unknown;
+}
+
+export interface EntityRevisionDiff {
+  entity_id: string;
+  entity_type: EntityType;
+  from_revision: string;
+  to_revision?: string;
+  changes: FieldChange[];
+}
+
+export interface MergeConflict {
+  path: string;
+  base?: unknown;
+  ours?: unknown;
+  theirs?: unknown;
+}
+
+export interface EntityConflict {
+  entity_id: string;
+  entity_type: EntityType;
+  conflicts: MergeConflict[];
+}
+
+export interface ConflictResolution {
+  path: string;
+  value?: unknown;
+}
+
+// Undo / Redo
+export interface HistoryEntry {
+  label: string;
+  timestamp: string;
+  entities: EntityRef[];
+  links_changed: boolean;
+}
+
+export interface UndoHistory {
+  undo: HistoryEntry[];
+  redo: HistoryEntry[];
+}
+
+// Projects
+export interface ProjectInfo {
+  path: string;
+  name: string;
+  last_opened: string;
+}
+
+// File Watching
+export type FileChangeKind = 'Created' | 'Modified' | 'Deleted';
+
+export interface EntityChange {
+  entity_type: EntityType;
+  entity_id: string;
+  kind: FileChangeKind;
+}
+
 // Calculation Results
 export interface CriticalPathResult {
   project_duration: number;